@@ -0,0 +1,111 @@
+//! Activation functions and other building blocks for machine learning inference.
+
+/// Beyond this magnitude, the rational approximation of `tanh` is clamped.  The approximation
+/// reaches 1 very close to this point, which keeps the result within `[-1, 1]`.
+const TANH_LIMIT: f64 = 4.97;
+
+macro_rules! impl_activation {
+    { $name:ident, $type:ty, $bits_ty:ident, $mask_ty:ident, $mask_impl_ty:ident } => {
+        impl<const LANES: usize> crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            /// Approximates the hyperbolic tangent of each lane.
+            ///
+            /// This uses a low-degree rational approximation that is several times faster than an
+            /// accurate `tanh`, with a maximum absolute error of 1e-4.  `NaN` lanes remain `NaN`.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::<4>::from_array([0., 0.5, -2., ", stringify!($type), "::INFINITY]);")]
+            /// let y = x.tanh_fast().to_array();
+            /// assert_eq!(y[0], 0.);
+            /// assert!((y[1] - 0.4621172).abs() < 1e-4);
+            /// assert!((y[2] + 0.9640276).abs() < 1e-4);
+            /// assert!((y[3] - 1.).abs() < 1e-4);
+            /// ```
+            #[inline]
+            pub fn tanh_fast(self) -> Self {
+                // clamp without `clamp`, which asserts on its bounds
+                let limit = Self::splat(TANH_LIMIT as $type);
+                let x = self.lanes_gt(limit).select(limit, self);
+                let x = x.lanes_lt(-limit).select(-limit, x);
+
+                // Padé approximant of degree 7/6
+                let x2 = x * x;
+                let p = ((x2 + Self::splat(378.)) * x2 + Self::splat(17325.)) * x2
+                    + Self::splat(135135.);
+                let q = ((Self::splat(28.) * x2 + Self::splat(3150.)) * x2 + Self::splat(62370.))
+                    * x2
+                    + Self::splat(135135.);
+                x * p / q
+            }
+
+            /// Approximates the logistic sigmoid `1 / (1 + exp(-x))` of each lane.
+            ///
+            /// This is computed with [`Self::tanh_fast`], with a maximum absolute error of 5e-5.
+            /// `NaN` lanes remain `NaN`.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::<4>::from_array([0., 1., -4., ", stringify!($type), "::NEG_INFINITY]);")]
+            /// let y = x.sigmoid_fast().to_array();
+            /// assert_eq!(y[0], 0.5);
+            /// assert!((y[1] - 0.7310586).abs() < 5e-5);
+            /// assert!((y[2] - 0.0179862).abs() < 5e-5);
+            /// assert!(y[3].abs() < 5e-5);
+            /// ```
+            #[inline]
+            pub fn sigmoid_fast(self) -> Self {
+                let half = Self::splat(0.5);
+                half * (self * half).tanh_fast() + half
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<const LANES: usize> crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            /// Computes `exp(x - max)` for each lane, where `max` is the maximum lane, along with
+            /// the sum of the results.
+            ///
+            /// These are the numerically stable building blocks of softmax: subtracting the maximum
+            /// keeps every exponential in `(0, 1]`, so the sum cannot overflow.  All lanes must be
+            /// finite.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::<4>::from_array([1000., 1000., 1000., 1000.]);")]
+            /// let (exp, sum) = x.exp_sub_max();
+            #[doc = concat!("assert_eq!(exp, ", stringify!($name), "::splat(1.));")]
+            /// assert_eq!(sum, 4.);
+            /// ```
+            #[inline]
+            pub fn exp_sub_max(self) -> (Self, $type) {
+                let shifted = self - Self::splat(self.horizontal_max());
+                let exp = unsafe { crate::intrinsics::simd_fexp(shifted) };
+                (exp, exp.horizontal_sum())
+            }
+
+            /// Computes the softmax of the lanes, `exp(x) / sum(exp(x))`.
+            ///
+            /// This is computed with [`Self::exp_sub_max`], so it does not overflow for large lanes.
+            /// All lanes must be finite.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::<4>::from_array([0., 0., 0., 0.]);")]
+            #[doc = concat!("assert_eq!(x.softmax(), ", stringify!($name), "::splat(0.25));")]
+            /// ```
+            #[inline]
+            pub fn softmax(self) -> Self {
+                let (exp, sum) = self.exp_sub_max();
+                exp / Self::splat(sum)
+            }
+        }
+    }
+}
+
+impl_activation! { SimdF32, f32, SimdU32, Mask32, SimdI32 }
+impl_activation! { SimdF64, f64, SimdU64, Mask64, SimdI64 }
@@ -0,0 +1,112 @@
+//! Heap-allocated scalar buffers aligned for vector access.
+//!
+//! The global allocator only guarantees the alignment of the scalar type for a `Vec<f32>` or
+//! `Box<[f32]>`, so vector loads from such buffers may be unaligned.  The buffers here are
+//! allocated as vectors and viewed as scalars, so they always start vector-aligned.
+
+use crate::Vector;
+use alloc::boxed::Box;
+use alloc::vec;
+
+/// A heap-allocated slice of scalars, aligned to and padded out to a whole number of vectors of
+/// type `V`.
+///
+/// The buffer dereferences to a slice of `len` scalars, and can also be accessed as a slice of
+/// vectors with [`as_vectors`](Self::as_vectors).
+///
+/// ```
+/// # use core_simd::{aligned::AlignedBox, SimdF32};
+/// let mut buffer = AlignedBox::<SimdF32<4>>::new(6, 1.0);
+/// buffer[5] = 2.0;
+/// assert_eq!(buffer.len(), 6);
+///
+/// // the padding lanes of the final vector have the fill value
+/// let sum = buffer.as_vectors().iter().fold(SimdF32::splat(0.), |acc, v| acc + *v);
+/// assert_eq!(sum.to_array(), [2., 3., 2., 2.]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlignedBox<V: Vector> {
+    vectors: Box<[V]>,
+    len: usize,
+}
+
+impl<V: Vector> AlignedBox<V> {
+    /// Allocates a buffer of `len` scalars, each set to `value`.  The padding lanes of the final
+    /// vector are also set to `value`.
+    ///
+    /// # Panics
+    /// Panics if the number of lanes in `V` isn't a power of two, since such vectors are padded
+    /// and can't be viewed as scalars.
+    #[track_caller]
+    pub fn new(len: usize, value: V::Scalar) -> Self {
+        assert!(
+            V::LANES.is_power_of_two(),
+            "vector must have a power-of-two number of lanes"
+        );
+        let vectors = vec![V::splat(value); (len + V::LANES - 1) / V::LANES].into_boxed_slice();
+        Self { vectors, len }
+    }
+
+    /// Allocates a buffer containing a copy of `slice`.  The padding lanes of the final vector
+    /// are set to the default value of the scalar type (zero).
+    pub fn from_slice(slice: &[V::Scalar]) -> Self
+    where
+        V::Scalar: Default,
+    {
+        let mut buffer = Self::new(slice.len(), V::Scalar::default());
+        buffer.copy_from_slice(slice);
+        buffer
+    }
+
+    /// Returns the buffer as a slice of vectors, including the padding lanes of the final vector.
+    #[inline]
+    pub fn as_vectors(&self) -> &[V] {
+        &self.vectors
+    }
+
+    /// Returns the buffer as a mutable slice of vectors, including the padding lanes of the final
+    /// vector.
+    #[inline]
+    pub fn as_vectors_mut(&mut self) -> &mut [V] {
+        &mut self.vectors
+    }
+
+    /// Converts the buffer into a boxed slice of vectors, including the padding lanes of the
+    /// final vector.
+    #[inline]
+    pub fn into_vectors(self) -> Box<[V]> {
+        self.vectors
+    }
+}
+
+impl<V: Vector> core::ops::Deref for AlignedBox<V> {
+    type Target = [V::Scalar];
+
+    #[inline]
+    fn deref(&self) -> &[V::Scalar] {
+        // SAFETY: a vector with a power-of-two number of lanes has the layout of an array of
+        // `V::LANES` scalars
+        unsafe { core::slice::from_raw_parts(self.vectors.as_ptr() as *const V::Scalar, self.len) }
+    }
+}
+
+impl<V: Vector> core::ops::DerefMut for AlignedBox<V> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [V::Scalar] {
+        // SAFETY: a vector with a power-of-two number of lanes has the layout of an array of
+        // `V::LANES` scalars
+        unsafe {
+            core::slice::from_raw_parts_mut(self.vectors.as_mut_ptr() as *mut V::Scalar, self.len)
+        }
+    }
+}
+
+impl<V: Vector> From<&[V::Scalar]> for AlignedBox<V>
+where
+    V::Scalar: Default,
+{
+    #[inline]
+    fn from(slice: &[V::Scalar]) -> Self {
+        Self::from_slice(slice)
+    }
+}
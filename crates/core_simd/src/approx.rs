@@ -0,0 +1,54 @@
+//! Approximate equality of float vectors, for testing numerical kernels.
+
+macro_rules! impl_approx_eq {
+    { $name:ident, $type:ty, $bits_ty:ident, $bits_scalar:ty, $mask_ty:ident, $mask_impl_ty:ident } => {
+        impl<const LANES: usize> crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            /// Test if each lane is approximately equal to the corresponding lane in `other`.
+            ///
+            /// Lanes are approximately equal if they differ by at most `epsilon`, or if they have
+            /// the same sign and are at most `max_ulps` representable values apart.  The absolute
+            /// `epsilon` handles values near zero, where ULPs are very small, while `max_ulps`
+            /// scales with the magnitude of the values.  `NaN` is never approximately equal to
+            /// anything.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = ", stringify!($name), "::<4>::from_array([1.0, 0.1 + 0.2, 1e-20, 1.0]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::<4>::from_array([1.0, 0.3, 0.0, 1.1]);")]
+            /// assert_eq!(a.lanes_approx_eq(b, 4, 1e-10).to_array(), [true, true, true, false]);
+            /// ```
+            #[inline]
+            pub fn lanes_approx_eq(
+                self,
+                other: Self,
+                max_ulps: $bits_scalar,
+                epsilon: $type,
+            ) -> crate::$mask_ty<LANES> {
+                let within_epsilon = (self - other).abs().lanes_le(Self::splat(epsilon));
+
+                // For floats with the same sign, the distance between the bit patterns is the
+                // number of representable values between them.
+                let (a, b) = (self.to_bits(), other.to_bits());
+                let sign_bit = crate::$bits_ty::splat(!(<$bits_scalar>::MAX >> 1));
+                let same_sign = (a ^ b).lanes_lt(sign_bit);
+                let ulps = a.lanes_gt(b).select(a - b, b - a);
+                let within_ulps = same_sign & ulps.lanes_le(crate::$bits_ty::splat(max_ulps));
+
+                within_epsilon | (within_ulps & !self.is_nan() & !other.is_nan())
+            }
+
+            /// Returns true if every lane is approximately equal to the corresponding lane in
+            /// `other`, as defined by [`Self::lanes_approx_eq`].
+            #[inline]
+            pub fn all_approx_eq(self, other: Self, max_ulps: $bits_scalar, epsilon: $type) -> bool {
+                self.lanes_approx_eq(other, max_ulps, epsilon).all()
+            }
+        }
+    }
+}
+
+impl_approx_eq! { SimdF32, f32, SimdU32, u32, Mask32, SimdI32 }
+impl_approx_eq! { SimdF64, f64, SimdU64, u64, Mask64, SimdI64 }
@@ -6,11 +6,9 @@ use crate::vector::*;
 /// A representation of a vector as an "array" with indices, implementing
 /// operations applicable to any vector type based solely on "having lanes",
 /// and describing relationships between vector and scalar types.
-pub trait SimdArray<const LANES: usize>: crate::LanesAtMost32
+pub trait SimdArray<const LANES: usize>
 where
-    SimdUsize<LANES>: crate::LanesAtMost32,
-    SimdIsize<LANES>: crate::LanesAtMost32,
-    MaskSize<LANES>: crate::Mask,
+    crate::LaneCount<LANES>: crate::SupportedLaneCount,
     Self: Sized,
 {
     /// The scalar type in every lane of this vector type.
@@ -58,6 +56,33 @@ where
         Self::gather_or(slice, idxs, Self::splat(Self::Scalar::default()))
     }
 
+    /// SIMD gather: construct a SIMD vector by reading from a slice, using potentially discontiguous indices.
+    /// Out-of-bounds indices are clamped to the last element of the slice.
+    ///
+    /// # Panics
+    /// Panics if `slice` is empty.
+    /// ```
+    /// # use core_simd::*;
+    /// let vec: Vec<i32> = vec![10, 11, 12, 13, 14, 15, 16, 17, 18];
+    /// let idxs = SimdUsize::<4>::from_array([9, 3, 0, usize::MAX]);
+    ///
+    /// let result = SimdI32::<4>::gather_clamped(&vec, idxs); // Note the lanes that are out-of-bounds.
+    /// assert_eq!(result, SimdI32::from_array([18, 13, 10, 18]));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn gather_clamped(slice: &[Self::Scalar], idxs: SimdUsize<LANES>) -> Self {
+        assert!(!slice.is_empty(), "cannot gather from an empty slice");
+        let last = SimdUsize::splat(slice.len() - 1);
+        let idxs = idxs.lanes_gt(last).select(last, idxs);
+        let base_ptr = SimdConstPtr::splat(slice.as_ptr());
+        let ptrs = base_ptr.wrapping_add(idxs);
+        // SAFETY: every index has been clamped into bounds, so every lane may be read
+        unsafe {
+            intrinsics::simd_gather(Self::splat(slice[0]), ptrs, MaskSize::splat(true).to_int())
+        }
+    }
+
     /// SIMD gather: construct a SIMD vector by reading from a slice, using potentially discontiguous indices.
     /// Out-of-bounds or masked indices instead select the value from the "or" vector.
     /// ```
@@ -86,6 +111,98 @@ where
         unsafe { intrinsics::simd_gather(or, ptrs, mask) }
     }
 
+    /// SIMD gather from a 2D array: construct a SIMD vector by reading from a slice of rows of
+    /// `stride` elements, at the element in column `x` of row `y` for each lane.
+    /// Lanes with `x` outside of the row, or a row outside of the slice, instead select the value
+    /// from the "or" vector.  The index `y * stride + x` is never computed with overflow, so large
+    /// coordinates can't wrap around to a valid index.
+    ///
+    /// # Panics
+    /// Panics if `stride` is zero.
+    /// ```
+    /// # use core_simd::*;
+    /// // a 3x3 image, stored with a stride of 4
+    /// let image: Vec<i32> = vec![10, 11, 12, 0, 20, 21, 22, 0, 30, 31, 32];
+    /// let x = SimdUsize::<4>::from_array([0, 2, 4, 1]);
+    /// let y = SimdUsize::<4>::from_array([0, 2, 0, usize::MAX]);
+    /// let alt = SimdI32::splat(-1);
+    ///
+    /// let result = SimdI32::<4>::gather_2d_or(&image, 4, x, y, alt); // Note the lanes outside of the image.
+    /// assert_eq!(result, SimdI32::from_array([10, 32, -1, -1]));
+    /// ```
+    #[must_use]
+    #[inline]
+    #[track_caller]
+    fn gather_2d_or(
+        slice: &[Self::Scalar],
+        stride: usize,
+        x: SimdUsize<LANES>,
+        y: SimdUsize<LANES>,
+        or: Self,
+    ) -> Self {
+        assert!(stride != 0, "stride must be nonzero");
+        let (mask, idxs) = index_2d(slice.len(), stride, x, y);
+        Self::gather_select(slice, mask, idxs, or)
+    }
+
+    /// SIMD gather from a 2D array: construct a SIMD vector by reading from a slice of rows of
+    /// `stride` elements, at the element in column `x` of row `y` for each lane.
+    /// Lanes outside of the array instead use the default value for that lane (0).
+    /// See [`SimdArray::gather_2d_or`].
+    ///
+    /// # Panics
+    /// Panics if `stride` is zero.
+    #[must_use]
+    #[inline]
+    #[track_caller]
+    fn gather_2d_or_default(
+        slice: &[Self::Scalar],
+        stride: usize,
+        x: SimdUsize<LANES>,
+        y: SimdUsize<LANES>,
+    ) -> Self
+    where
+        Self::Scalar: Default,
+    {
+        Self::gather_2d_or(slice, stride, x, y, Self::splat(Self::Scalar::default()))
+    }
+
+    /// SIMD scatter of a transposed tile to a 2D array: write lane `i` of `vectors[k]` into a
+    /// slice of rows of `stride` elements, at the element in column `k` of row `i`.
+    /// The `K` vectors are stored as `LANES` rows of `K` elements, which is the layout of a tile of
+    /// a matrix product accumulated one column per vector.  Elements outside of the row, or rows
+    /// outside of the slice, are not written.
+    ///
+    /// # Panics
+    /// Panics if `stride` is zero.
+    /// ```
+    /// # use core_simd::*;
+    /// // a 4x3 matrix, stored with a stride of 3
+    /// let mut matrix = vec![0; 12];
+    /// let columns = [SimdI32::<4>::from_array([1, 2, 3, 4]), SimdI32::from_array([5, 6, 7, 8])];
+    ///
+    /// SimdI32::scatter_2d_transposed(columns, &mut matrix[1..], 3); // Note the partial last row.
+    /// assert_eq!(matrix, vec![0, 1, 5, 0, 2, 6, 0, 3, 7, 0, 4, 8]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn scatter_2d_transposed<const K: usize>(
+        vectors: [Self; K],
+        slice: &mut [Self::Scalar],
+        stride: usize,
+    ) {
+        assert!(stride != 0, "stride must be nonzero");
+        let mut rows = [0; LANES];
+        for (i, row) in rows.iter_mut().enumerate() {
+            *row = i;
+        }
+        let y = SimdUsize::from_array(rows);
+        for (x, vector) in IntoIterator::into_iter(vectors).enumerate() {
+            let (mask, idxs) = index_2d(slice.len(), stride, SimdUsize::splat(x), y);
+            vector.scatter_select(slice, mask, idxs);
+        }
+    }
+
     /// SIMD scatter: write a SIMD vector's values into a slice, using potentially discontiguous indices.
     /// Out-of-bounds indices are not written.
     /// `scatter` writes "in order", so if an index receives two writes, only the last is guaranteed.
@@ -147,13 +264,34 @@ where
     }
 }
 
+/// Returns the indices of the elements in column `x` of row `y` of a slice of `len` elements,
+/// stored in rows of `stride` elements, and a mask of the lanes within the slice.  Lanes outside
+/// of the slice have index 0, so the index `y * stride + x` is never computed with overflow.
+#[inline]
+fn index_2d<const LANES: usize>(
+    len: usize,
+    stride: usize,
+    x: SimdUsize<LANES>,
+    y: SimdUsize<LANES>,
+) -> (MaskSize<LANES>, SimdUsize<LANES>)
+where
+    crate::LaneCount<LANES>: crate::SupportedLaneCount,
+{
+    // compare the row against the number of rows rather than computing the index, which may
+    // overflow: the last row may be partial
+    let rows = SimdUsize::splat(len / stride);
+    let partial = SimdUsize::splat(len % stride);
+    let in_row = x.lanes_lt(SimdUsize::splat(stride));
+    let in_slice = y.lanes_lt(rows) | (y.lanes_eq(rows) & x.lanes_lt(partial));
+    let mask = in_row & in_slice;
+    let idxs = mask.select(y * SimdUsize::splat(stride) + x, SimdUsize::splat(0));
+    (mask, idxs)
+}
+
 macro_rules! impl_simdarray_for {
     ($simd:ident {type Scalar = $scalar:ident;}) => {
         impl<const LANES: usize> SimdArray<LANES> for $simd<LANES>
-            where SimdUsize<LANES>: crate::LanesAtMost32,
-            SimdIsize<LANES>: crate::LanesAtMost32,
-            MaskSize<LANES>: crate::Mask,
-            Self: crate::LanesAtMost32,
+            where crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
             type Scalar = $scalar;
 
@@ -167,10 +305,7 @@ macro_rules! impl_simdarray_for {
 
     ($simd:ident $impl:tt) => {
         impl<const LANES: usize> SimdArray<LANES> for $simd<LANES>
-            where SimdUsize<LANES>: crate::LanesAtMost32,
-            SimdIsize<LANES>: crate::LanesAtMost32,
-            MaskSize<LANES>: crate::Mask,
-            Self: crate::LanesAtMost32,
+            where crate::LaneCount<LANES>: crate::SupportedLaneCount,
         $impl
     }
 }
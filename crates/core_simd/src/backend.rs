@@ -0,0 +1,118 @@
+//! Compile-time introspection of how vectors map onto the target's registers.
+//!
+//! Vectors wider than the target's native registers still work, but LLVM splits (or, with no
+//! vector unit at all, scalarizes) every operation on them.  This only reflects target features
+//! enabled at compile time, not features detected at runtime.
+
+/// Returns the width, in bits, of the widest native vector register that supports lanes of
+/// `elem_bits` bits, or 0 if operations on such lanes are scalarized.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const fn native_register_bits(elem_bits: usize, float: bool) -> usize {
+    let avx512 = (elem_bits < 32 && cfg!(target_feature = "avx512bw"))
+        || (elem_bits >= 32 && cfg!(target_feature = "avx512f"));
+    let avx = (float && cfg!(target_feature = "avx")) || cfg!(target_feature = "avx2");
+    let sse =
+        (float && elem_bits == 32 && cfg!(target_feature = "sse")) || cfg!(target_feature = "sse2");
+    if avx512 {
+        512
+    } else if avx {
+        256
+    } else if sse {
+        128
+    } else {
+        0
+    }
+}
+
+#[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+const fn native_register_bits(elem_bits: usize, float: bool) -> usize {
+    // 32-bit NEON has no double-precision lanes
+    let f64_lanes = !(float && elem_bits == 64) || cfg!(target_arch = "aarch64");
+    if cfg!(target_feature = "neon") && f64_lanes {
+        128
+    } else {
+        0
+    }
+}
+
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+const fn native_register_bits(elem_bits: usize, float: bool) -> usize {
+    let altivec = cfg!(target_feature = "altivec") && !(float && elem_bits == 64);
+    if altivec || cfg!(target_feature = "vsx") {
+        128
+    } else {
+        0
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+const fn native_register_bits(_elem_bits: usize, _float: bool) -> usize {
+    if cfg!(target_feature = "simd128") {
+        128
+    } else {
+        0
+    }
+}
+
+#[cfg(not(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "wasm32",
+)))]
+const fn native_register_bits(_elem_bits: usize, _float: bool) -> usize {
+    0
+}
+
+macro_rules! impl_backend_introspection {
+    { $($name:ident, $scalar:ty, $float:literal;)* } => {
+        $(
+            impl<const LANES: usize> crate::$name<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                /// Returns the number of lanes that fit in a single native vector register on
+                /// the current build target, or 0 if the target has no vector registers for
+                /// this lane type.
+                #[inline]
+                pub const fn native_lanes() -> usize {
+                    native_register_bits(core::mem::size_of::<$scalar>() * 8, $float)
+                        / (core::mem::size_of::<$scalar>() * 8)
+                }
+
+                /// Returns true if this vector fits in a single native vector register on the
+                /// current build target, so operations on it are neither split nor scalarized.
+                ///
+                /// ```
+                /// # use core_simd::*;
+                /// // ensure wide vectors aren't silently emulated
+                #[doc = concat!("if ", stringify!($name), "::<32>::is_hardware_accelerated() {")]
+                #[doc = concat!("    assert!(", stringify!($name), "::<32>::native_lanes() >= 32);")]
+                /// }
+                /// ```
+                #[inline]
+                pub const fn is_hardware_accelerated() -> bool {
+                    LANES <= Self::native_lanes()
+                }
+            }
+        )*
+    }
+}
+
+impl_backend_introspection! {
+    SimdU8, u8, false;
+    SimdU16, u16, false;
+    SimdU32, u32, false;
+    SimdU64, u64, false;
+    SimdUsize, usize, false;
+    SimdI8, i8, false;
+    SimdI16, i16, false;
+    SimdI32, i32, false;
+    SimdI64, i64, false;
+    SimdIsize, isize, false;
+    SimdF32, f32, true;
+    SimdF64, f64, true;
+}
@@ -0,0 +1,274 @@
+//! Base64 encoding and decoding with the standard alphabet of RFC 4648.
+//!
+//! The slice functions [`encode`] and [`decode`] are built from lower-level kernels which operate
+//! on [`SimdU8`] vectors, and can be used to build codecs for other layouts:
+//! * [`split_6bit`] and [`pack_6bit`] convert between 12 bytes and 16 6-bit values
+//! * [`encode_6bit`] and [`decode_6bit`] translate between 6-bit values and ASCII characters
+//!
+//! ```
+//! # use core_simd::base64;
+//! let mut encoded = [0; 8];
+//! assert_eq!(base64::encode(b"hello", &mut encoded), 8);
+//! assert_eq!(&encoded, b"aGVsbG8=");
+//!
+//! let mut decoded = [0; 6];
+//! assert_eq!(base64::decode(&encoded, &mut decoded), Ok(5));
+//! assert_eq!(&decoded[..5], b"hello");
+//! ```
+
+use crate::{LaneCount, Mask8, SimdU16, SimdU8, SupportedLaneCount};
+
+/// The number of input bytes encoded at a time.
+const BYTES: usize = 12;
+
+/// The number of characters produced from `BYTES` bytes.
+const CHARS: usize = 16;
+
+/// An error which can be returned when decoding base64.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DecodeError {
+    /// The input contained a byte which is not in the base64 alphabet, at this index.
+    InvalidByte(usize),
+
+    /// The input length was not a multiple of 4.
+    InvalidLength,
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidByte(index) => write!(f, "invalid base64 byte at index {}", index),
+            Self::InvalidLength => f.write_str("base64 length must be a multiple of 4"),
+        }
+    }
+}
+
+/// Returns the number of characters needed to encode `len` bytes, including padding.
+///
+/// ```
+/// # use core_simd::base64;
+/// assert_eq!(base64::encoded_len(5), 8);
+/// assert_eq!(base64::encoded_len(6), 8);
+/// ```
+#[inline]
+pub const fn encoded_len(len: usize) -> usize {
+    (len + 2) / 3 * 4
+}
+
+/// Returns the most bytes that can be decoded from `len` characters.
+///
+/// The actual length may be up to 2 bytes shorter, depending on the padding.
+#[inline]
+pub const fn decoded_len(len: usize) -> usize {
+    len / 4 * 3
+}
+
+/// Combines pairs of bytes into 16-bit lanes, with lane `i` of `hi` in the high byte.
+#[inline]
+fn combine<const HI: [u32; 16], const LO: [u32; 16]>(bytes: SimdU8<16>, shift: u16) -> SimdU16<16> {
+    let hi: SimdU16<16> =
+        unsafe { crate::intrinsics::simd_cast(bytes.shuffle::<HI>(SimdU8::splat(0))) };
+    let lo: SimdU16<16> =
+        unsafe { crate::intrinsics::simd_cast(bytes.shuffle::<LO>(SimdU8::splat(0))) };
+    (hi << shift) | lo
+}
+
+/// Splits the first 12 bytes of `bytes` into 16 6-bit values, most significant bits first.
+///
+/// The last 4 lanes of `bytes` are ignored.
+///
+/// ```
+/// # use core_simd::*;
+/// let mut bytes = [0; 16];
+/// bytes[..3].copy_from_slice(&[0b000001_00, 0b0010_0000, 0b11_000100]);
+/// let values = base64::split_6bit(SimdU8::from_array(bytes));
+/// assert_eq!(values.to_array()[..4], [1, 2, 3, 4]);
+/// ```
+#[inline]
+pub fn split_6bit(bytes: SimdU8<16>) -> SimdU8<16> {
+    // each value spans at most two bytes: take the bytes containing its high and low bits
+    const HI: [u32; 16] = [0, 0, 1, 1, 3, 3, 4, 4, 6, 6, 7, 7, 9, 9, 10, 10];
+    const LO: [u32; 16] = [1, 1, 2, 2, 4, 4, 5, 5, 7, 7, 8, 8, 10, 10, 11, 11];
+    const SHIFTS: SimdU16<16> =
+        SimdU16::from_array([10, 4, 6, 0, 10, 4, 6, 0, 10, 4, 6, 0, 10, 4, 6, 0]);
+    let words = combine::<HI, LO>(bytes, 8);
+    unsafe { crate::intrinsics::simd_cast((words >> SHIFTS) & SimdU16::splat(0x3f)) }
+}
+
+/// Packs 16 6-bit values into 12 bytes, most significant bits first.
+///
+/// This is the inverse of [`split_6bit`].  Bits of each value above the low 6 bits must be zero.
+/// The last 4 lanes of the result are zero.
+///
+/// ```
+/// # use core_simd::*;
+/// let mut values = [0; 16];
+/// values[..4].copy_from_slice(&[1, 2, 3, 4]);
+/// let bytes = base64::pack_6bit(SimdU8::from_array(values));
+/// assert_eq!(bytes.to_array()[..3], [0b000001_00, 0b0010_0000, 0b11_000100]);
+/// ```
+#[inline]
+pub fn pack_6bit(values: SimdU8<16>) -> SimdU8<16> {
+    // each byte spans at most two values: take the values containing its high and low bits,
+    // and select the zero vector for the unused lanes
+    const HI: [u32; 16] = [0, 1, 2, 4, 5, 6, 8, 9, 10, 12, 13, 14, 16, 16, 16, 16];
+    const LO: [u32; 16] = [1, 2, 3, 5, 6, 7, 9, 10, 11, 13, 14, 15, 16, 16, 16, 16];
+    const SHIFTS: SimdU16<16> =
+        SimdU16::from_array([4, 2, 0, 4, 2, 0, 4, 2, 0, 4, 2, 0, 0, 0, 0, 0]);
+    let words = combine::<HI, LO>(values, 6);
+    unsafe { crate::intrinsics::simd_cast(words >> SHIFTS) }
+}
+
+/// Translates 6-bit values to characters of the base64 alphabet.
+///
+/// Bits of each value above the low 6 bits must be zero.
+///
+/// ```
+/// # use core_simd::*;
+/// let values = SimdU8::from_array([0, 25, 26, 51, 52, 61, 62, 63]);
+/// assert_eq!(&base64::encode_6bit(values).to_array(), b"AZaz09+/");
+/// ```
+#[inline]
+pub fn encode_6bit<const LANES: usize>(values: SimdU8<LANES>) -> SimdU8<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // the alphabet is made of contiguous ranges, so add the offset of each range
+    let mut offset = SimdU8::splat(b'A');
+    offset = values
+        .lanes_ge(SimdU8::splat(26))
+        .select(SimdU8::splat(b'a' - 26), offset);
+    offset = values
+        .lanes_ge(SimdU8::splat(52))
+        .select(SimdU8::splat(b'0'.wrapping_sub(52)), offset);
+    offset = values
+        .lanes_eq(SimdU8::splat(62))
+        .select(SimdU8::splat(b'+'.wrapping_sub(62)), offset);
+    offset = values
+        .lanes_eq(SimdU8::splat(63))
+        .select(SimdU8::splat(b'/'.wrapping_sub(63)), offset);
+    values + offset
+}
+
+/// Translates characters of the base64 alphabet to 6-bit values.
+///
+/// Returns the values, and a mask of the lanes which contained valid characters.  Invalid lanes
+/// have unspecified values.
+///
+/// ```
+/// # use core_simd::*;
+/// let chars = SimdU8::from_array(*b"AZaz09+=");
+/// let (values, valid) = base64::decode_6bit(chars);
+/// assert_eq!(values.to_array()[..7], [0, 25, 26, 51, 52, 61, 62]);
+/// assert_eq!(valid.to_array(), [true, true, true, true, true, true, true, false]);
+/// ```
+#[inline]
+pub fn decode_6bit<const LANES: usize>(chars: SimdU8<LANES>) -> (SimdU8<LANES>, Mask8<LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let upper = chars.lanes_in_range_inclusive(SimdU8::splat(b'A'), SimdU8::splat(b'Z'));
+    let lower = chars.lanes_in_range_inclusive(SimdU8::splat(b'a'), SimdU8::splat(b'z'));
+    let digit = chars.lanes_in_range_inclusive(SimdU8::splat(b'0'), SimdU8::splat(b'9'));
+    let plus = chars.lanes_eq(SimdU8::splat(b'+'));
+    let slash = chars.lanes_eq(SimdU8::splat(b'/'));
+
+    let mut offset = SimdU8::splat(0);
+    offset = upper.select(SimdU8::splat(b'A'), offset);
+    offset = lower.select(SimdU8::splat(b'a' - 26), offset);
+    offset = digit.select(SimdU8::splat(b'0'.wrapping_sub(52)), offset);
+    offset = plus.select(SimdU8::splat(b'+'.wrapping_sub(62)), offset);
+    offset = slash.select(SimdU8::splat(b'/'.wrapping_sub(63)), offset);
+    (chars - offset, upper | lower | digit | plus | slash)
+}
+
+/// Encodes `input` as base64 into `output`, returning the number of characters written.
+///
+/// The output is padded with `=` to a multiple of 4 characters.
+///
+/// # Panics
+/// Panics if `output` is shorter than [`encoded_len`] of the input length.
+#[track_caller]
+pub fn encode(input: &[u8], output: &mut [u8]) -> usize {
+    let len = encoded_len(input.len());
+    assert!(output.len() >= len, "output too short for encoded data");
+
+    for (i, (chunk, output)) in input
+        .chunks(BYTES)
+        .zip(output.chunks_mut(CHARS))
+        .enumerate()
+    {
+        let bytes = if input.len() - i * BYTES >= 16 {
+            // load a whole vector when it fits, ignoring the last 4 bytes
+            SimdU8::from_slice(&input[i * BYTES..])
+        } else {
+            let mut padded = [0; 16];
+            padded[..chunk.len()].copy_from_slice(chunk);
+            SimdU8::from_array(padded)
+        };
+        let chars = encode_6bit(split_6bit(bytes));
+
+        let written = encoded_len(chunk.len());
+        if written == CHARS {
+            chars.copy_to_slice(output);
+        } else {
+            output[..written].copy_from_slice(&chars.to_array()[..written]);
+        }
+
+        // replace the characters encoding only padding bits
+        let used = (chunk.len() * 8 + 5) / 6;
+        for c in &mut output[used..written] {
+            *c = b'=';
+        }
+    }
+    len
+}
+
+/// Decodes base64 `input` into `output`, returning the number of bytes written.
+///
+/// The input must be padded with `=` to a multiple of 4 characters.  Unused bits in the last
+/// character before the padding are ignored.
+///
+/// # Panics
+/// Panics if `output` is shorter than [`decoded_len`] of the input length.
+#[track_caller]
+pub fn decode(input: &[u8], output: &mut [u8]) -> Result<usize, DecodeError> {
+    if input.len() % 4 != 0 {
+        return Err(DecodeError::InvalidLength);
+    }
+    assert!(
+        output.len() >= decoded_len(input.len()),
+        "output too short for decoded data"
+    );
+
+    let padding = input
+        .iter()
+        .rev()
+        .take(2)
+        .take_while(|c| **c == b'=')
+        .count();
+    let input = &input[..input.len() - padding];
+
+    let mut written = 0;
+    for (i, chunk) in input.chunks(CHARS).enumerate() {
+        let chars = if chunk.len() == CHARS {
+            SimdU8::from_slice(chunk)
+        } else {
+            // pad with the character for zero
+            let mut padded = [b'A'; 16];
+            padded[..chunk.len()].copy_from_slice(chunk);
+            SimdU8::from_array(padded)
+        };
+
+        let (values, valid) = decode_6bit(chars);
+        if !valid.all() {
+            let lane = valid.to_array().iter().position(|v| !v).unwrap();
+            return Err(DecodeError::InvalidByte(i * CHARS + lane));
+        }
+
+        let len = chunk.len() * 6 / 8;
+        output[written..written + len].copy_from_slice(&pack_6bit(values).to_array()[..len]);
+        written += len;
+    }
+    Ok(written)
+}
@@ -0,0 +1,203 @@
+//! Packing of small integers into dense bit fields, as used by columnar encodings.
+//!
+//! Values are packed least significant bit first: value `i`, packed with a width of `bits`,
+//! occupies bits `i * bits .. (i + 1) * bits` of the output, counting from the least significant
+//! bit of the first byte.  This is the bit-packed layout used by Parquet.
+//!
+//! Bits of a value above the field width are discarded when packing.
+
+use crate::{LaneCount, SimdArray, SimdU32, SimdU64, SimdU8, SimdUsize, SupportedLaneCount};
+use core::convert::TryInto;
+
+/// The most bytes a single field can span: up to 7 bits of offset into its first byte, plus
+/// 32 bits of value.
+const MAX_SPAN: usize = 5;
+
+/// The number of values packed at a time by the slice functions.  A group of 8 values always
+/// packs into a whole number of bytes.
+const GROUP: usize = 8;
+
+/// Returns the number of bytes needed to pack `count` values into fields of `bits` bits.
+///
+/// ```
+/// # use core_simd::bitpack;
+/// assert_eq!(bitpack::packed_len(8, 3), 3);
+/// assert_eq!(bitpack::packed_len(5, 3), 2);
+/// ```
+#[inline]
+pub const fn packed_len(count: usize, bits: u32) -> usize {
+    (count * bits as usize + 7) / 8
+}
+
+#[inline]
+#[track_caller]
+fn assert_width(bits: u32) {
+    assert!(bits <= 32, "bit width must be at most 32");
+}
+
+/// Returns the index of the first byte of the field of each lane, and the offset of the field
+/// within that byte.
+#[inline]
+fn field_positions<const LANES: usize>(bits: u32) -> (SimdUsize<LANES>, SimdU64<LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut lanes = [0; LANES];
+    for (i, lane) in lanes.iter_mut().enumerate() {
+        *lane = i as u64;
+    }
+    let offsets = SimdU64::from_array(lanes) * u64::from(bits);
+
+    let mut starts = [0; LANES];
+    for (start, offset) in starts.iter_mut().zip(offsets.as_slice()) {
+        *start = (offset >> 3) as usize;
+    }
+    (SimdUsize::from_array(starts), offsets & 7)
+}
+
+/// Packs each lane of `values` into a field of `bits` bits at the start of `out`.
+///
+/// Exactly [`packed_len(LANES, bits)`](packed_len) bytes of `out` are overwritten.  If
+/// `LANES * bits` isn't a multiple of 8, the unused high bits of the final byte are cleared.
+///
+/// ```
+/// # use core_simd::{bitpack, SimdU32};
+/// let mut out = [0; 3];
+/// bitpack::pack(SimdU32::from_array([0, 1, 2, 3, 4, 5, 6, 7]), 3, &mut out);
+/// assert_eq!(out, [0b10_001_000, 0b1_100_011_0, 0b111_110_10]);
+/// ```
+///
+/// # Panics
+/// Panics if `bits` is greater than 32, or if `out` is shorter than `packed_len(LANES, bits)`.
+#[inline]
+pub fn pack<const LANES: usize>(values: SimdU32<LANES>, bits: u32, out: &mut [u8])
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    assert_width(bits);
+    let len = packed_len(LANES, bits);
+    assert!(out.len() >= len, "output buffer is too small");
+
+    let (starts, shifts) = field_positions::<LANES>(bits);
+    let values: SimdU64<LANES> = unsafe { crate::intrinsics::simd_cast(values) };
+    let fields = (values & ((1 << bits) - 1)) << shifts;
+
+    // Neighbouring fields may share a byte, so merge them one at a time
+    let out = &mut out[..len];
+    out.fill(0);
+    for (field, start) in fields.as_slice().iter().zip(starts.as_slice()) {
+        for (k, byte) in out[*start..].iter_mut().take(MAX_SPAN).enumerate() {
+            *byte |= (field >> (8 * k)) as u8;
+        }
+    }
+}
+
+/// Unpacks `LANES` fields of `bits` bits from the start of `bytes`.
+///
+/// ```
+/// # use core_simd::{bitpack, SimdU32};
+/// let bytes = [0b10_001_000, 0b1_100_011_0, 0b111_110_10];
+/// assert_eq!(bitpack::unpack(&bytes, 3), SimdU32::<8>::from_array([0, 1, 2, 3, 4, 5, 6, 7]));
+/// ```
+///
+/// # Panics
+/// Panics if `bits` is greater than 32, or if `bytes` is shorter than `packed_len(LANES, bits)`.
+#[inline]
+pub fn unpack<const LANES: usize>(bytes: &[u8], bits: u32) -> SimdU32<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    assert_width(bits);
+    assert!(
+        bytes.len() >= packed_len(LANES, bits),
+        "input buffer is too small"
+    );
+
+    // Gather the bytes spanned by each field into a little-endian window
+    let (starts, shifts) = field_positions::<LANES>(bits);
+    let mut window = SimdU64::splat(0);
+    for k in 0..MAX_SPAN {
+        let byte = SimdU8::gather_or_default(bytes, starts + k);
+        let byte: SimdU64<LANES> = unsafe { crate::intrinsics::simd_cast(byte) };
+        window |= byte << (8 * k as u64);
+    }
+
+    let fields = (window >> shifts) & ((1 << bits) - 1);
+    unsafe { crate::intrinsics::simd_cast(fields) }
+}
+
+/// Packs every value in `values` into fields of `bits` bits at the start of `out`.
+///
+/// Exactly [`packed_len(values.len(), bits)`](packed_len) bytes of `out` are overwritten.
+///
+/// ```
+/// # use core_simd::bitpack;
+/// let values: Vec<u32> = (0..100).map(|x| x % 32).collect();
+/// let mut packed = vec![0; bitpack::packed_len(values.len(), 5)];
+/// bitpack::pack_slice(&values, 5, &mut packed);
+///
+/// let mut unpacked = vec![0; values.len()];
+/// bitpack::unpack_slice(&packed, 5, &mut unpacked);
+/// assert_eq!(unpacked, values);
+/// ```
+///
+/// # Panics
+/// Panics if `bits` is greater than 32, or if `out` is shorter than
+/// `packed_len(values.len(), bits)`.
+pub fn pack_slice(values: &[u32], bits: u32, out: &mut [u8]) {
+    assert_width(bits);
+    assert!(
+        out.len() >= packed_len(values.len(), bits),
+        "output buffer is too small"
+    );
+
+    let group_len = packed_len(GROUP, bits);
+    let mut chunks = values.chunks_exact(GROUP);
+    for (i, chunk) in (&mut chunks).enumerate() {
+        let values = SimdU32::<GROUP>::from_array(chunk.try_into().unwrap());
+        pack(values, bits, &mut out[i * group_len..]);
+    }
+
+    let remainder = chunks.remainder();
+    if !remainder.is_empty() {
+        let remainder_start = values.len() / GROUP * group_len;
+        let mut padded = [0; GROUP];
+        padded[..remainder.len()].copy_from_slice(remainder);
+        let mut packed = [0; packed_len(GROUP, 32)];
+        pack(SimdU32::from_array(padded), bits, &mut packed);
+        let len = packed_len(remainder.len(), bits);
+        out[remainder_start..remainder_start + len].copy_from_slice(&packed[..len]);
+    }
+}
+
+/// Unpacks fields of `bits` bits from the start of `bytes`, filling `out`.
+///
+/// See [`pack_slice`] for an example.
+///
+/// # Panics
+/// Panics if `bits` is greater than 32, or if `bytes` is shorter than
+/// `packed_len(out.len(), bits)`.
+pub fn unpack_slice(bytes: &[u8], bits: u32, out: &mut [u32]) {
+    assert_width(bits);
+    assert!(
+        bytes.len() >= packed_len(out.len(), bits),
+        "input buffer is too small"
+    );
+
+    let group_len = packed_len(GROUP, bits);
+    let remainder_start = out.len() / GROUP * group_len;
+    let mut chunks = out.chunks_exact_mut(GROUP);
+    for (i, chunk) in (&mut chunks).enumerate() {
+        let values = unpack::<GROUP>(&bytes[i * group_len..], bits);
+        chunk.copy_from_slice(values.as_slice());
+    }
+
+    let remainder = chunks.into_remainder();
+    if !remainder.is_empty() {
+        let len = packed_len(remainder.len(), bits);
+        let mut padded = [0; packed_len(GROUP, 32)];
+        padded[..len].copy_from_slice(&bytes[remainder_start..remainder_start + len]);
+        let values = unpack::<GROUP>(&padded, bits);
+        remainder.copy_from_slice(&values.as_slice()[..remainder.len()]);
+    }
+}
@@ -0,0 +1,272 @@
+//! Fixed-size bitsets stored in vectors.
+
+use crate::{LaneCount, SimdU64, SupportedLaneCount};
+
+/// A fixed-size set of `64 * LANES` bits, stored in a vector of `u64`.
+///
+/// Bit `i` is bit `i % 64` of lane `i / 64`.  Set operations act on the whole vector at once.
+///
+/// ```
+/// # use core_simd::SimdBitSet;
+/// let mut a = SimdBitSet::<4>::new();
+/// a.set(3);
+/// a.set(200);
+/// let mut b = SimdBitSet::<4>::new();
+/// b.set(200);
+/// b.set(255);
+///
+/// assert_eq!(a.intersection(b).iter().collect::<Vec<_>>(), [200]);
+/// assert_eq!(a.union(b).count_ones(), 3);
+/// assert_eq!(a.difference(b).iter().collect::<Vec<_>>(), [3]);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[repr(transparent)]
+pub struct SimdBitSet<const LANES: usize>(SimdU64<LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount;
+
+impl<const LANES: usize> SimdBitSet<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// The number of bits in the set.
+    pub const BITS: usize = 64 * LANES;
+
+    /// Creates a set with no bits set.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(SimdU64::splat(0))
+    }
+
+    /// Creates a set with every bit set.
+    #[inline]
+    pub const fn full() -> Self {
+        Self(SimdU64::splat(u64::MAX))
+    }
+
+    /// Creates a set from its vector representation.
+    #[inline]
+    pub const fn from_vector(bits: SimdU64<LANES>) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the vector representation of the set.
+    #[inline]
+    pub const fn to_vector(self) -> SimdU64<LANES> {
+        self.0
+    }
+
+    /// Returns the lane and the mask of the bit within it.
+    #[inline]
+    #[track_caller]
+    fn locate(bit: usize) -> (usize, u64) {
+        assert!(bit < Self::BITS, "bit index out of range");
+        (bit / 64, 1 << (bit % 64))
+    }
+
+    /// Tests if `bit` is set.
+    ///
+    /// # Panics
+    /// Panics if `bit` is greater than or equal to `Self::BITS`.
+    #[inline]
+    pub fn test(&self, bit: usize) -> bool {
+        let (lane, mask) = Self::locate(bit);
+        self.0[lane] & mask != 0
+    }
+
+    /// Sets `bit`.
+    ///
+    /// # Panics
+    /// Panics if `bit` is greater than or equal to `Self::BITS`.
+    #[inline]
+    pub fn set(&mut self, bit: usize) {
+        let (lane, mask) = Self::locate(bit);
+        self.0[lane] |= mask;
+    }
+
+    /// Clears `bit`.
+    ///
+    /// # Panics
+    /// Panics if `bit` is greater than or equal to `Self::BITS`.
+    #[inline]
+    pub fn clear(&mut self, bit: usize) {
+        let (lane, mask) = Self::locate(bit);
+        self.0[lane] &= !mask;
+    }
+
+    /// Returns the set of bits set in either `self` or `other`.
+    #[inline]
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the set of bits set in both `self` and `other`.
+    #[inline]
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the set of bits set in `self` but not in `other`.
+    #[inline]
+    pub fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Returns the set of bits set in exactly one of `self` and `other`.
+    #[inline]
+    pub fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    /// Returns the set of bits not set in `self`.
+    #[inline]
+    pub fn complement(self) -> Self {
+        Self(!self.0)
+    }
+
+    /// Returns the number of bits set.
+    #[inline]
+    pub fn count_ones(self) -> u32 {
+        // count the bits of each lane in parallel, by summing adjacent fields of increasing width
+        let x = self.0;
+        let x = x - ((x >> 1) & 0x5555_5555_5555_5555);
+        let x = (x & 0x3333_3333_3333_3333) + ((x >> 2) & 0x3333_3333_3333_3333);
+        let x = (x + (x >> 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+        let x = (x * 0x0101_0101_0101_0101) >> 56;
+        x.horizontal_sum() as u32
+    }
+
+    /// Returns true if no bits are set.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.0.horizontal_or() == 0
+    }
+
+    /// Returns true if every bit set in `self` is also set in `other`.
+    #[inline]
+    pub fn is_subset(self, other: Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Returns an iterator over the indices of the set bits, in ascending order.
+    #[inline]
+    pub fn iter(&self) -> Iter<LANES> {
+        Iter {
+            bits: self.0,
+            lane: 0,
+        }
+    }
+}
+
+impl<const LANES: usize> core::fmt::Debug for SimdBitSet<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<const LANES: usize> IntoIterator for SimdBitSet<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Item = usize;
+    type IntoIter = Iter<LANES>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<LANES> {
+        self.iter()
+    }
+}
+
+impl<const LANES: usize> core::iter::FromIterator<usize> for SimdBitSet<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Collects bit indices into a set.
+    ///
+    /// # Panics
+    /// Panics if any index is greater than or equal to `Self::BITS`.
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for bit in iter {
+            set.set(bit);
+        }
+        set
+    }
+}
+
+macro_rules! impl_bitset_op {
+    { $trait:ident :: $fn:ident, $assign_trait:ident :: $assign_fn:ident, $method:ident } => {
+        impl<const LANES: usize> core::ops::$trait for SimdBitSet<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self {
+                self.$method(rhs)
+            }
+        }
+
+        impl<const LANES: usize> core::ops::$assign_trait for SimdBitSet<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = self.$method(rhs);
+            }
+        }
+    };
+}
+
+impl_bitset_op! { BitOr::bitor, BitOrAssign::bitor_assign, union }
+impl_bitset_op! { BitAnd::bitand, BitAndAssign::bitand_assign, intersection }
+impl_bitset_op! { BitXor::bitxor, BitXorAssign::bitxor_assign, symmetric_difference }
+impl_bitset_op! { Sub::sub, SubAssign::sub_assign, difference }
+
+impl<const LANES: usize> core::ops::Not for SimdBitSet<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+
+/// An iterator over the indices of the set bits of a [`SimdBitSet`], in ascending order.
+#[derive(Clone, Debug)]
+pub struct Iter<const LANES: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    bits: SimdU64<LANES>,
+    lane: usize,
+}
+
+impl<const LANES: usize> Iterator for Iter<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        while self.lane < LANES {
+            let word = &mut self.bits[self.lane];
+            if *word != 0 {
+                let bit = word.trailing_zeros() as usize;
+                *word &= *word - 1;
+                return Some(self.lane * 64 + bit);
+            }
+            self.lane += 1;
+        }
+        None
+    }
+}
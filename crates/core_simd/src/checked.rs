@@ -0,0 +1,99 @@
+use crate::{LaneCount, SupportedLaneCount};
+
+/// Provides arithmetic on integer vectors that is checked for overflow in debug builds.
+///
+/// Operations like `+` on `Checked<SimdI32<LANES>>` panic if any lane overflows when debug
+/// assertions are enabled, mirroring the overflow checks on scalar integers.  When debug
+/// assertions are disabled, the operations wrap like plain vector arithmetic.
+///
+/// This is useful for catching silent wraparound in code ported from scalar arithmetic.
+///
+/// ```
+/// # use core_simd::*;
+/// let a = Checked(SimdU8::from_array([1, 100, 200, 254]));
+/// let b = Checked(SimdU8::splat(1));
+/// assert_eq!((a + b).0.to_array(), [2, 101, 201, 255]);
+/// // `a + Checked(SimdU8::splat(100))` would panic in debug builds, since 200 + 100 overflows
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default, Hash, Debug)]
+#[repr(transparent)]
+pub struct Checked<T>(pub T);
+
+macro_rules! impl_checked_op {
+    { $vector:ident, $trait:ident :: $fn:ident, $assign_trait:ident :: $assign_fn:ident, $overflows:expr, $msg:literal } => {
+        impl<const LANES: usize> core::ops::$trait for Checked<crate::$vector<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn $fn(self, rhs: Self) -> Self::Output {
+                let overflows: fn(crate::$vector<LANES>, crate::$vector<LANES>) -> bool = $overflows;
+                debug_assert!(!overflows(self.0, rhs.0), $msg);
+                Checked(core::ops::$trait::$fn(self.0, rhs.0))
+            }
+        }
+
+        impl<const LANES: usize> core::ops::$assign_trait for Checked<crate::$vector<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            #[track_caller]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = core::ops::$trait::$fn(*self, rhs);
+            }
+        }
+    }
+}
+
+macro_rules! impl_unsigned_checked {
+    { $($vector:ident),* } => {
+        $(
+            impl_checked_op! {
+                $vector, Add::add, AddAssign::add_assign,
+                |a, b| a.saturating_add(b) != a + b,
+                "attempt to add with overflow"
+            }
+            impl_checked_op! {
+                $vector, Sub::sub, SubAssign::sub_assign,
+                |a, b| a.saturating_sub(b) != a - b,
+                "attempt to subtract with overflow"
+            }
+            impl_checked_op! {
+                $vector, Mul::mul, MulAssign::mul_assign,
+                |a, b| a.as_slice().iter().zip(b.as_slice()).any(|(a, b)| a.checked_mul(*b).is_none()),
+                "attempt to multiply with overflow"
+            }
+        )*
+    }
+}
+
+macro_rules! impl_signed_checked {
+    { $($vector:ident),* } => {
+        impl_unsigned_checked! { $($vector),* }
+        $(
+            impl<const LANES: usize> core::ops::Neg for Checked<crate::$vector<LANES>>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                type Output = Self;
+
+                #[inline]
+                #[track_caller]
+                fn neg(self) -> Self::Output {
+                    debug_assert!(
+                        self.0.saturating_neg() == -self.0,
+                        "attempt to negate with overflow"
+                    );
+                    Checked(-self.0)
+                }
+            }
+        )*
+    }
+}
+
+impl_unsigned_checked! { SimdU8, SimdU16, SimdU32, SimdU64, SimdUsize }
+impl_signed_checked! { SimdI8, SimdI16, SimdI32, SimdI64, SimdIsize }
@@ -1,13 +1,11 @@
-use crate::LanesAtMost32;
+use crate::{LaneCount, SupportedLaneCount};
 
 macro_rules! implement_mask_ops {
     { $($vector:ident => $mask:ident ($inner_ty:ident),)* } => {
         $(
             impl<const LANES: usize> crate::$vector<LANES>
             where
-                crate::$vector<LANES>: LanesAtMost32,
-                crate::$inner_ty<LANES>: LanesAtMost32,
-                crate::$mask<LANES>: crate::Mask,
+                LaneCount<LANES>: SupportedLaneCount,
             {
                 /// Test if each lane is equal to the corresponding lane in `other`.
                 #[inline]
@@ -56,6 +54,51 @@ macro_rules! implement_mask_ops {
                         crate::$mask::from_int_unchecked(crate::intrinsics::simd_ge(self, other))
                     }
                 }
+
+                /// Three-way comparison of each lane with the corresponding lane in `other`,
+                /// returning -1 if the lane is less, 0 if equal, and 1 if greater.
+                ///
+                /// For floats, 0 is also returned if either lane is NaN.
+                ///
+                /// ```
+                /// # use core_simd::*;
+                #[doc = concat!("let a = ", stringify!($vector), "::from_array([1 as _, 2 as _, 3 as _, 4 as _]);")]
+                #[doc = concat!("let b = ", stringify!($vector), "::from_array([2 as _, 2 as _, 2 as _, 2 as _]);")]
+                /// assert_eq!(a.lanes_cmp(b).to_array(), [-1, 0, 1, 1]);
+                /// ```
+                #[inline]
+                pub fn lanes_cmp(self, other: Self) -> crate::$inner_ty<LANES> {
+                    // true lanes are -1
+                    self.lanes_lt(other).to_int() - self.lanes_gt(other).to_int()
+                }
+
+                /// Test if each lane is within the half-open range `lo..hi` given by the
+                /// corresponding lanes in `lo` and `hi`.
+                ///
+                /// ```
+                /// # use core_simd::*;
+                #[doc = concat!("let x = ", stringify!($vector), "::from_array([0 as _, 1 as _, 2 as _, 3 as _]);")]
+                #[doc = concat!("let mask = x.lanes_in_range(", stringify!($vector), "::splat(1 as _), ", stringify!($vector), "::splat(3 as _));")]
+                /// assert_eq!(mask.to_array(), [false, true, true, false]);
+                /// ```
+                #[inline]
+                pub fn lanes_in_range(self, lo: Self, hi: Self) -> crate::$mask<LANES> {
+                    self.lanes_ge(lo) & self.lanes_lt(hi)
+                }
+
+                /// Test if each lane is within the closed range `lo..=hi` given by the
+                /// corresponding lanes in `lo` and `hi`.
+                ///
+                /// ```
+                /// # use core_simd::*;
+                #[doc = concat!("let x = ", stringify!($vector), "::from_array([0 as _, 1 as _, 2 as _, 3 as _]);")]
+                #[doc = concat!("let mask = x.lanes_in_range_inclusive(", stringify!($vector), "::splat(1 as _), ", stringify!($vector), "::splat(3 as _));")]
+                /// assert_eq!(mask.to_array(), [false, true, true, true]);
+                /// ```
+                #[inline]
+                pub fn lanes_in_range_inclusive(self, lo: Self, hi: Self) -> crate::$mask<LANES> {
+                    self.lanes_ge(lo) & self.lanes_le(hi)
+                }
             }
         )*
     }
@@ -0,0 +1,75 @@
+//! Arithmetic on complex numbers stored as interleaved real and imaginary parts.
+
+macro_rules! impl_complex_lanes {
+    { $name:ident, $type:ty, $n:literal } => {
+        impl crate::$name<$n> {
+            /// Multiplies complex numbers and adds the products to an accumulator, computing
+            /// `self * other + acc`.
+            ///
+            /// Each pair of lanes holds the real and imaginary parts of a complex number, such as
+            /// a slice of interleaved `[re, im]` samples.  The products are computed with two
+            /// fused multiply-adds per lane, which makes this the core of FFT butterflies and
+            /// mixing a signal with a complex oscillator.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            /// // (1 + 2i) * (3 + 4i) + (1 + 1i), and i * i + 0
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([1., 2., 0., 1.]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([3., 4., 0., 1.]);")]
+            #[doc = concat!("let acc = ", stringify!($name), "::from_array([1., 1., 0., 0.]);")]
+            /// assert_eq!(a.cmul_add(b, acc).to_array(), [-4., 11., -1., 0.]);
+            /// ```
+            #[inline]
+            pub fn cmul_add(self, other: Self, acc: Self) -> Self {
+                // copies the real or imaginary part of each number to both of its lanes
+                const fn duplicate(part: usize) -> [u32; $n] {
+                    let mut idx = [0u32; $n];
+                    let mut i = 0;
+                    while i < $n {
+                        idx[i] = (i - i % 2 + part) as u32;
+                        i += 1;
+                    }
+                    idx
+                }
+                const fn swap() -> [u32; $n] {
+                    let mut idx = [0u32; $n];
+                    let mut i = 0;
+                    while i < $n {
+                        idx[i] = (i ^ 1) as u32;
+                        i += 1;
+                    }
+                    idx
+                }
+                const fn negate_real() -> [$type; $n] {
+                    let mut signs = [1.; $n];
+                    let mut i = 0;
+                    while i < $n {
+                        signs[i] = -1.;
+                        i += 2;
+                    }
+                    signs
+                }
+
+                // (a + bi)(c + di) = (ac - bd) + (ad + bc)i
+                let re = self.shuffle::<{ duplicate(0) }>(self);
+                let im = self.shuffle::<{ duplicate(1) }>(self) * Self::from_array(negate_real());
+                let swapped = other.shuffle::<{ swap() }>(other);
+                im.mul_add(swapped, re.mul_add(other, acc))
+            }
+        }
+    }
+}
+
+macro_rules! impl_complex {
+    { $name:ident, $type:ty } => {
+        impl_complex_lanes! { $name, $type, 2 }
+        impl_complex_lanes! { $name, $type, 4 }
+        impl_complex_lanes! { $name, $type, 8 }
+        impl_complex_lanes! { $name, $type, 16 }
+        impl_complex_lanes! { $name, $type, 32 }
+        impl_complex_lanes! { $name, $type, 64 }
+    }
+}
+
+impl_complex! { SimdF32, f32 }
+impl_complex! { SimdF64, f64 }
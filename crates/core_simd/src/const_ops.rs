@@ -0,0 +1,134 @@
+//! Lanewise arithmetic usable in constant expressions.
+//!
+//! Trait methods can't be called in const contexts, so the operators are mirrored by `const fn`
+//! methods.  These operate a lane at a time; outside of const contexts, prefer the operators.
+
+macro_rules! impl_const_lanewise {
+    { $(#[$attr:meta])* fn $fn:ident(self, $rhs:ident: Self) => |$a:ident, $b:ident| $op:expr } => {
+        $(#[$attr])*
+        #[inline]
+        pub const fn $fn(self, $rhs: Self) -> Self {
+            let mut lanes = self.to_array();
+            let rhs = $rhs.to_array();
+            let mut i = 0;
+            while i < LANES {
+                let ($a, $b) = (lanes[i], rhs[i]);
+                lanes[i] = $op;
+                i += 1;
+            }
+            Self::from_array(lanes)
+        }
+    };
+    { $(#[$attr:meta])* fn $fn:ident(self) => |$a:ident| $op:expr } => {
+        $(#[$attr])*
+        #[inline]
+        pub const fn $fn(self) -> Self {
+            let mut lanes = self.to_array();
+            let mut i = 0;
+            while i < LANES {
+                let $a = lanes[i];
+                lanes[i] = $op;
+                i += 1;
+            }
+            Self::from_array(lanes)
+        }
+    };
+}
+
+macro_rules! impl_const_int_ops {
+    { $($name:ident),* } => {
+        $(
+            impl<const LANES: usize> crate::$name<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                impl_const_lanewise! {
+                    /// Lanewise wrapping addition, usable in const contexts.
+                    ///
+                    /// ```
+                    /// # use core_simd::*;
+                    #[doc = concat!("const X: ", stringify!($name), "<4> = ", stringify!($name), "::from_array([1, 2, 3, 4]).const_add(", stringify!($name), "::splat(1));")]
+                    #[doc = concat!("assert_eq!(X, ", stringify!($name), "::from_array([2, 3, 4, 5]));")]
+                    /// ```
+                    fn const_add(self, rhs: Self) => |a, b| a.wrapping_add(b)
+                }
+
+                impl_const_lanewise! {
+                    /// Lanewise wrapping subtraction, usable in const contexts.
+                    fn const_sub(self, rhs: Self) => |a, b| a.wrapping_sub(b)
+                }
+
+                impl_const_lanewise! {
+                    /// Lanewise wrapping multiplication, usable in const contexts.
+                    fn const_mul(self, rhs: Self) => |a, b| a.wrapping_mul(b)
+                }
+
+                impl_const_lanewise! {
+                    /// Lanewise bitwise AND, usable in const contexts.
+                    fn const_bitand(self, rhs: Self) => |a, b| a & b
+                }
+
+                impl_const_lanewise! {
+                    /// Lanewise bitwise OR, usable in const contexts.
+                    fn const_bitor(self, rhs: Self) => |a, b| a | b
+                }
+
+                impl_const_lanewise! {
+                    /// Lanewise bitwise XOR, usable in const contexts.
+                    fn const_bitxor(self, rhs: Self) => |a, b| a ^ b
+                }
+
+                impl_const_lanewise! {
+                    /// Lanewise bitwise NOT, usable in const contexts.
+                    fn const_not(self) => |a| !a
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! impl_const_float_ops {
+    { $($name:ident),* } => {
+        $(
+            impl<const LANES: usize> crate::$name<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                impl_const_lanewise! {
+                    /// Lanewise addition, usable in const contexts.
+                    ///
+                    /// ```
+                    /// # use core_simd::*;
+                    #[doc = concat!("const X: ", stringify!($name), "<4> = ", stringify!($name), "::from_array([1., 2., 3., 4.]).const_add(", stringify!($name), "::splat(0.5));")]
+                    #[doc = concat!("assert_eq!(X, ", stringify!($name), "::from_array([1.5, 2.5, 3.5, 4.5]));")]
+                    /// ```
+                    fn const_add(self, rhs: Self) => |a, b| a + b
+                }
+
+                impl_const_lanewise! {
+                    /// Lanewise subtraction, usable in const contexts.
+                    fn const_sub(self, rhs: Self) => |a, b| a - b
+                }
+
+                impl_const_lanewise! {
+                    /// Lanewise multiplication, usable in const contexts.
+                    fn const_mul(self, rhs: Self) => |a, b| a * b
+                }
+
+                impl_const_lanewise! {
+                    /// Lanewise division, usable in const contexts.
+                    fn const_div(self, rhs: Self) => |a, b| a / b
+                }
+
+                impl_const_lanewise! {
+                    /// Lanewise negation, usable in const contexts.
+                    fn const_neg(self) => |a| -a
+                }
+            }
+        )*
+    }
+}
+
+impl_const_int_ops! { SimdU8, SimdU16, SimdU32, SimdU64, SimdUsize }
+impl_const_int_ops! { SimdI8, SimdI16, SimdI32, SimdI64, SimdIsize }
+impl_const_float_ops! { SimdF32, SimdF64 }
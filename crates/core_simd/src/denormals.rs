@@ -0,0 +1,120 @@
+//! Scoped control of denormal (subnormal) floating-point handling.
+//!
+//! Arithmetic on denormal values is very slow on many processors, which can be a problem for
+//! signal processing code where values decay towards zero.  Flushing denormals to zero trades the
+//! tiny values for consistent performance: denormal results become zero ("flush to zero") and
+//! denormal inputs are treated as zero ("denormals are zero").
+//!
+//! This is currently supported on x86-64, and x86 with SSE2.  On other targets the closure is run
+//! with the default handling.
+//!
+//! The compiler assumes the default floating-point environment, so it may evaluate operations
+//! with the default handling even while denormals are flushed, which makes a safe interface
+//! impossible: see the safety section of [`flush_denormals`].
+
+#[cfg(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse2")
+))]
+mod arch {
+    /// The flush-to-zero and denormals-are-zero bits of MXCSR.
+    const FTZ_DAZ: u32 = 0x8040;
+
+    pub(super) const SUPPORTED: bool = true;
+
+    /// Reads MXCSR.  This uses `stmxcsr` directly, since `_mm_getcsr` is deprecated.
+    #[inline]
+    fn getcsr() -> u32 {
+        let mut csr = 0u32;
+        unsafe {
+            asm!("stmxcsr [{}]", in(reg) &mut csr, options(nostack, preserves_flags));
+        }
+        csr
+    }
+
+    /// Writes MXCSR.  This uses `ldmxcsr` directly, since `_mm_setcsr` is deprecated.
+    #[inline]
+    unsafe fn setcsr(csr: u32) {
+        asm!("ldmxcsr [{}]", in(reg) &csr, options(nostack, preserves_flags, readonly));
+    }
+
+    /// Enables flushing, returning the previous state.
+    #[inline]
+    pub(super) unsafe fn enable() -> u32 {
+        let csr = getcsr();
+        setcsr(csr | FTZ_DAZ);
+        csr & FTZ_DAZ
+    }
+
+    /// Restores the state returned by `enable`, without touching the rest of MXCSR, such as the
+    /// exception flags raised in the meantime.
+    #[inline]
+    pub(super) unsafe fn restore(state: u32) {
+        setcsr((getcsr() & !FTZ_DAZ) | state)
+    }
+}
+
+#[cfg(not(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse2")
+)))]
+mod arch {
+    pub(super) const SUPPORTED: bool = false;
+
+    #[inline]
+    pub(super) unsafe fn enable() -> u32 {
+        0
+    }
+
+    #[inline]
+    pub(super) unsafe fn restore(_state: u32) {}
+}
+
+/// Returns whether [`flush_denormals`] is supported on this target.
+#[inline]
+pub const fn is_supported() -> bool {
+    arch::SUPPORTED
+}
+
+/// Runs `f` with denormals flushed to zero on the current thread, returning its result.
+///
+/// The previous setting is restored when `f` returns or panics, so calls may be nested.  Threads
+/// spawned by `f` are not affected.  `f` is called through a function that is never inlined, to
+/// keep its operations from being moved across the change of setting.
+///
+/// # Safety
+/// The compiler assumes the default floating-point environment.  Floating-point operations in `f`
+/// may still be evaluated with the default handling, for example when they are constant-folded,
+/// and operations outside of `f` may be evaluated with denormals flushed.  The caller must ensure
+/// that no code relies on the exact results of these operations for soundness, such as by using a
+/// result to index without a bounds check.
+///
+/// ```
+/// # use core_simd::{denormals, SimdF32};
+/// let x = SimdF32::<4>::splat(1e-30);
+/// let y = unsafe { core::ptr::read_volatile(&x) };
+/// // SAFETY: the product is only compared
+/// let product = unsafe { denormals::flush_denormals(|| y * y) };
+/// if denormals::is_supported() {
+///     assert_eq!(product.to_array(), [0.; 4]);
+/// }
+/// ```
+#[inline]
+pub unsafe fn flush_denormals<R>(f: impl FnOnce() -> R) -> R {
+    struct Restore(u32);
+
+    impl Drop for Restore {
+        #[inline]
+        fn drop(&mut self) {
+            unsafe { arch::restore(self.0) }
+        }
+    }
+
+    #[inline(never)]
+    fn call<R>(f: impl FnOnce() -> R) -> R {
+        f()
+    }
+
+    let _restore = Restore(arch::enable());
+    call(f)
+}
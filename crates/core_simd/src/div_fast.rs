@@ -0,0 +1,86 @@
+//! Approximate division of float vectors.
+
+macro_rules! impl_div_fast {
+    {
+        $name:ident, $type:ty, $bits_ty:ident, $mask_ty:ident, $mask_impl_ty:ident,
+        magic: $magic:literal,
+        scale: $scale:literal,
+        min: $min:literal,
+        max: $max:literal,
+        steps: $steps:literal,
+        error: $error:literal,
+    } => {
+        impl<const LANES: usize> crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            /// Divides each lane by the corresponding lane in `other`, approximately.
+            ///
+            /// The reciprocal of `other` is estimated from its bit pattern and refined with
+            /// Newton's method, which is usually faster than division.
+            ///
+            /// Lanes of `other` which are zero, infinite, NaN, or so small or large that their
+            /// reciprocal is subnormal fall back to exact division, as do lanes where multiplying
+            /// by the reciprocal overflows.  This is slower when any lane needs it.
+            ///
+            /// # Precision
+            #[doc = concat!("The relative error is at most ", $error, " when the exact quotient is a normal number.")]
+            /// Quotients that are subnormal are computed as a product with the reciprocal, so they
+            /// lose precision like any other subnormal product, and may be flushed to zero.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([1., 10., -3., 0.5]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([3., 4., 7., 1e-3]);")]
+            #[doc = concat!("let error = (a.div_fast(b) / (a / b) - ", stringify!($name), "::splat(1.)).abs();")]
+            /// assert!(error.horizontal_max() < 1e-5);
+            /// ```
+            #[inline]
+            pub fn div_fast(self, other: Self) -> Self {
+                // the estimate is within 5.1% of the reciprocal, and a Newton step scaled to
+                // center its error brings it within 0.13%
+                let estimate = Self::from_bits(crate::$bits_ty::splat($magic) - other.to_bits());
+                let two = Self::splat(2.);
+                let mut recip = Self::splat($scale) * estimate * (two - other * estimate);
+
+                // each Newton step squares the error
+                for _ in 0..$steps {
+                    recip = recip * (two - other * recip);
+                }
+                let quotient = self * recip;
+
+                // the reciprocal may be slightly too large, which overflows quotients close to the
+                // largest finite value
+                let bits = other.abs().to_bits();
+                let exact = bits.lanes_lt(crate::$bits_ty::splat($min))
+                    | bits.lanes_ge(crate::$bits_ty::splat($max))
+                    | (quotient.is_infinite() & self.is_finite());
+                if exact.any() {
+                    exact.select(self / other, quotient)
+                } else {
+                    quotient
+                }
+            }
+        }
+    }
+}
+
+impl_div_fast! {
+    SimdF32, f32, SimdU32, Mask32, SimdI32,
+    magic: 0x7ef3_11c0,
+    scale: 1.001_277_3,
+    min: 0x0080_0000,
+    max: 0x7e00_0000,
+    steps: 1,
+    error: "2<sup>-18</sup>",
+}
+
+impl_div_fast! {
+    SimdF64, f64, SimdU64, Mask64, SimdI64,
+    magic: 0x7fde_6238_22fc_16e6,
+    scale: 1.001_277_276_645_129,
+    min: 0x0010_0000_0000_0000,
+    max: 0x7fc0_0000_0000_0000,
+    steps: 2,
+    error: "2<sup>-37</sup>",
+}
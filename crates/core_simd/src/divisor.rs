@@ -0,0 +1,121 @@
+//! Division of unsigned integer vectors by a runtime-invariant divisor.
+//!
+//! Hardware has no integer vector division, so dividing a vector lowers to one scalar division
+//! per lane.  When the same divisor is used many times, it's much faster to precompute a
+//! "magic" multiplier once and replace each division with a multiply and shifts.
+//!
+//! This uses the round-up method from Granlund and Montgomery, "Division by Invariant Integers
+//! using Multiplication", which is exact for every dividend and every nonzero divisor.
+
+macro_rules! impl_divisor {
+    { $name:ident, $vector:ident, $scalar:ty, $wide:ident, $wide_scalar:ty } => {
+        #[doc = concat!("A precomputed `", stringify!($scalar), "` divisor, for quickly dividing [`", stringify!($vector), "`](crate::", stringify!($vector), ") vectors by the same value.")]
+        ///
+        /// Vectors can be divided by the divisor with `/`, and the remainder taken with `%`.  The
+        /// results are identical to dividing each lane by the original divisor.
+        ///
+        /// ```
+        /// # use core_simd::*;
+        #[doc = concat!("let d = ", stringify!($name), "::new(7);")]
+        #[doc = concat!("let x = ", stringify!($vector), "::<4>::from_array([0, 6, 7, 100]);")]
+        /// assert_eq!((x / d).to_array(), [0, 0, 1, 14]);
+        /// assert_eq!((x % d).to_array(), [0, 6, 0, 2]);
+        /// ```
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $name {
+            divisor: $scalar,
+            multiplier: $scalar,
+            shift1: $scalar,
+            shift2: $scalar,
+        }
+
+        impl $name {
+            /// Precomputes the multiplier for dividing by `divisor`.
+            ///
+            /// # Panics
+            /// Panics if `divisor` is zero.
+            #[inline]
+            #[track_caller]
+            pub fn new(divisor: $scalar) -> Self {
+                assert!(divisor != 0, "attempt to divide by zero");
+
+                // `l` is the number of bits needed to represent `divisor - 1`, i.e. `ceil(log2(divisor))`
+                let l = <$scalar>::BITS - (divisor - 1).leading_zeros();
+                let divisor_wide = divisor as u64;
+                let multiplier = (((1u64 << l) - divisor_wide) << <$scalar>::BITS) / divisor_wide + 1;
+                Self {
+                    divisor,
+                    multiplier: multiplier as $scalar,
+                    shift1: l.min(1) as $scalar,
+                    shift2: l.saturating_sub(1) as $scalar,
+                }
+            }
+
+            /// Returns the divisor.
+            #[inline]
+            pub const fn divisor(&self) -> $scalar {
+                self.divisor
+            }
+
+            /// Returns the high half of the product of each lane with the multiplier.
+            #[inline]
+            fn mul_high<const LANES: usize>(self, x: crate::$vector<LANES>) -> crate::$vector<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                let x: crate::$wide<LANES> = unsafe { crate::intrinsics::simd_cast(x) };
+                let product = x * (self.multiplier as $wide_scalar);
+                unsafe { crate::intrinsics::simd_cast(product >> (<$scalar>::BITS as $wide_scalar)) }
+            }
+        }
+
+        impl<const LANES: usize> core::ops::Div<$name> for crate::$vector<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: $name) -> Self {
+                let t = rhs.mul_high(self);
+                (t + ((self - t) >> rhs.shift1)) >> rhs.shift2
+            }
+        }
+
+        impl<const LANES: usize> core::ops::Rem<$name> for crate::$vector<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn rem(self, rhs: $name) -> Self {
+                self - (self / rhs) * rhs.divisor
+            }
+        }
+
+        impl<const LANES: usize> core::ops::DivAssign<$name> for crate::$vector<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            #[inline]
+            fn div_assign(&mut self, rhs: $name) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<const LANES: usize> core::ops::RemAssign<$name> for crate::$vector<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            #[inline]
+            fn rem_assign(&mut self, rhs: $name) {
+                *self = *self % rhs;
+            }
+        }
+    }
+}
+
+impl_divisor! { DivisorU8, SimdU8, u8, SimdU16, u16 }
+impl_divisor! { DivisorU16, SimdU16, u16, SimdU32, u32 }
+impl_divisor! { DivisorU32, SimdU32, u32, SimdU64, u64 }
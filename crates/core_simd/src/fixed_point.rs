@@ -0,0 +1,78 @@
+//! Fixed-point arithmetic on signed integer vectors.
+
+macro_rules! impl_fixed_point {
+    { $name:ident, $n:ident, $mask:ident, $wide:ident, $wide_n:ident, $format:literal, $bits:literal, aarch64: $neon_mulh:ident $(, x86: $x86_mulhrs:ident)? } => {
+        impl<const LANES: usize> crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            /// Lanewise saturating rounding doubling multiply, returning the high half.
+            ///
+            #[doc = concat!("Computes `(2 * self * rhs + (1 << ", $bits, " - 1)) >> ", $bits, "` for each lane without intermediate overflow.")]
+            #[doc = concat!("This is the rounded product of two ", $format, " fixed-point numbers.")]
+            /// The only case that overflows is MIN times MIN, which saturates to MAX.
+            ///
+            /// This is equivalent to the `VQRDMULH` instruction on ARM.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
+            #[doc = concat!("let half = 1 << (", stringify!($n), "::BITS - 2);")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([half, half, -half, MIN]);")]
+            #[doc = concat!("let y = ", stringify!($name), "::from_array([half, MAX, half, MIN]);")]
+            /// let product = x.saturating_rounding_doubling_mul_high(y);
+            #[doc = concat!("assert_eq!(product, ", stringify!($name), "::from_array([half / 2, half, -half / 2, MAX]));")]
+            /// ```
+            #[inline]
+            pub fn saturating_rounding_doubling_mul_high(self, rhs: Self) -> Self {
+                #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+                if LANES * $bits == 128 {
+                    use core::arch::aarch64::$neon_mulh;
+                    // SAFETY: the vector is exactly one 128-bit register
+                    return unsafe {
+                        core::mem::transmute_copy(&$neon_mulh(
+                            core::mem::transmute_copy(&self),
+                            core::mem::transmute_copy(&rhs),
+                        ))
+                    };
+                }
+
+                let overflow = self.lanes_eq(Self::splat(<$n>::MIN)) & rhs.lanes_eq(Self::splat(<$n>::MIN));
+                overflow.select(Self::splat(<$n>::MAX), self.wrapping_rounding_doubling_mul_high(rhs))
+            }
+
+            /// As `saturating_rounding_doubling_mul_high`, except MIN times MIN wraps to MIN.
+            #[inline]
+            fn wrapping_rounding_doubling_mul_high(self, rhs: Self) -> Self {
+                $(
+                    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "ssse3"))]
+                    if LANES == 8 {
+                        #[cfg(target_arch = "x86")]
+                        use core::arch::x86::$x86_mulhrs;
+                        #[cfg(target_arch = "x86_64")]
+                        use core::arch::x86_64::$x86_mulhrs;
+                        // SAFETY: the vector is exactly one 128-bit register
+                        return unsafe {
+                            core::mem::transmute_copy(&$x86_mulhrs(
+                                core::mem::transmute_copy(&self),
+                                core::mem::transmute_copy(&rhs),
+                            ))
+                        };
+                    }
+                )?
+
+                const BITS: u32 = <$n>::BITS;
+                let a: crate::$wide<LANES> = unsafe { crate::intrinsics::simd_cast(self) };
+                let b: crate::$wide<LANES> = unsafe { crate::intrinsics::simd_cast(rhs) };
+
+                // (2ab + 2^(BITS - 1)) >> BITS, without overflowing the doubling
+                let rounded = (a * b + crate::$wide::splat(1 << (BITS - 2))) >> (BITS - 1) as $wide_n;
+                unsafe { crate::intrinsics::simd_cast(rounded) }
+            }
+        }
+    }
+}
+
+impl_fixed_point! { SimdI16, i16, Mask16, SimdI32, i32, "Q15", 16, aarch64: vqrdmulhq_s16, x86: _mm_mulhrs_epi16 }
+impl_fixed_point! { SimdI32, i32, Mask32, SimdI64, i64, "Q31", 32, aarch64: vqrdmulhq_s32 }
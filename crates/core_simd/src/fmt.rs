@@ -29,13 +29,28 @@ debug_wrapper! {
     UpperHex => format_upper_hex,
 }
 
+/// Formats the lanes separated by commas, without the surrounding brackets of `Debug`.  The
+/// formatter's flags (width, precision, etc.) apply to each lane individually.
+pub(crate) fn format_display<T: core::fmt::Display>(
+    slice: &[T],
+    f: &mut core::fmt::Formatter,
+) -> core::fmt::Result {
+    for (i, x) in slice.iter().enumerate() {
+        if i != 0 {
+            f.write_str(", ")?;
+        }
+        x.fmt(f)?;
+    }
+    Ok(())
+}
+
 macro_rules! impl_fmt_trait {
     { $($type:ident => $(($trait:ident, $format:ident)),*;)* } => {
         $( // repeat type
             $( // repeat trait
                 impl<const LANES: usize> core::fmt::$trait for crate::$type<LANES>
                 where
-                    Self: crate::LanesAtMost32,
+                    crate::LaneCount<LANES>: crate::SupportedLaneCount,
                 {
                     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
                         $format(self.as_ref(), f)
@@ -48,6 +63,7 @@ macro_rules! impl_fmt_trait {
         impl_fmt_trait! {
             $($type =>
               (Debug, format),
+              (Display, format_display),
               (Binary, format_binary),
               (LowerExp, format_lower_exp),
               (UpperExp, format_upper_exp),
@@ -61,6 +77,7 @@ macro_rules! impl_fmt_trait {
         impl_fmt_trait! {
             $($type =>
               (Debug, format),
+              (Display, format_display),
               (LowerExp, format_lower_exp),
               (UpperExp, format_upper_exp);
             )*
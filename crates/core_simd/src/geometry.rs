@@ -0,0 +1,165 @@
+//! Geometry helpers for 3-lane and 4-lane float vectors representing 3D points and directions.
+//!
+//! Lanes 0, 1, and 2 hold the `x`, `y`, and `z` components.  In 4-lane vectors, lane 3 holds `w`,
+//! which these helpers ignore.
+
+macro_rules! impl_geometry {
+    { $name:ident, $type:ty } => {
+        impl crate::$name<4> {
+            /// Returns the dot product of the first three lanes of `self` and `other`, ignoring the
+            /// fourth lane.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = ", stringify!($name), "::<4>::from_array([1., 2., 3., 100.]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::<4>::from_array([4., 5., 6., 100.]);")]
+            /// assert_eq!(a.dot3(b), 32.);
+            /// ```
+            #[inline]
+            pub fn dot3(self, other: Self) -> $type {
+                let product = (self * other).to_array();
+                product[0] + product[1] + product[2]
+            }
+
+            /// Returns the cross product of the first three lanes of `self` and `other`.
+            ///
+            /// The fourth lane of the result is zero if the fourth lanes of the inputs are finite.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::<4>::from_array([1., 0., 0., 0.]);")]
+            #[doc = concat!("let y = ", stringify!($name), "::<4>::from_array([0., 1., 0., 0.]);")]
+            /// assert_eq!(x.cross3(y).to_array(), [0., 0., 1., 0.]);
+            /// ```
+            #[inline]
+            pub fn cross3(self, other: Self) -> Self {
+                // (y, z, x, w) and (z, x, y, w) permutations of each input
+                let a_yzx = self.shuffle::<{ [1, 2, 0, 3] }>(self);
+                let a_zxy = self.shuffle::<{ [2, 0, 1, 3] }>(self);
+                let b_yzx = other.shuffle::<{ [1, 2, 0, 3] }>(other);
+                let b_zxy = other.shuffle::<{ [2, 0, 1, 3] }>(other);
+                a_yzx * b_zxy - a_zxy * b_yzx
+            }
+
+            /// Returns the squared length of the first three lanes, ignoring the fourth lane.
+            ///
+            /// This avoids the square root in [`Self::length`], which makes it useful for
+            /// comparing lengths.
+            #[inline]
+            pub fn length_squared(self) -> $type {
+                self.dot3(self)
+            }
+
+            /// Returns the length of the first three lanes, ignoring the fourth lane.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let v = ", stringify!($name), "::<4>::from_array([2., 3., 6., 100.]);")]
+            /// assert_eq!(v.length(), 7.);
+            /// ```
+            #[inline]
+            #[cfg(feature = "std")]
+            pub fn length(self) -> $type {
+                Self::splat(self.length_squared()).sqrt().to_array()[0]
+            }
+
+            /// Scales the vector so that the first three lanes have a length of 1.
+            ///
+            /// The fourth lane is scaled by the same factor.  If the length is zero, the result
+            /// contains non-finite values.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let v = ", stringify!($name), "::<4>::from_array([0., 3., 4., 0.]);")]
+            /// assert_eq!(v.normalize().to_array(), [0., 0.6, 0.8, 0.]);
+            /// ```
+            #[inline]
+            #[cfg(feature = "std")]
+            pub fn normalize(self) -> Self {
+                self / Self::splat(self.length_squared()).sqrt()
+            }
+        }
+
+        impl crate::$name<3> {
+            /// Returns the dot product of `self` and `other`.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = ", stringify!($name), "::<3>::from_array([1., 2., 3.]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::<3>::from_array([4., 5., 6.]);")]
+            /// assert_eq!(a.dot3(b), 32.);
+            /// ```
+            #[inline]
+            pub fn dot3(self, other: Self) -> $type {
+                self.to_xyzw().dot3(other.to_xyzw())
+            }
+
+            /// Returns the cross product of `self` and `other`.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::<3>::from_array([1., 0., 0.]);")]
+            #[doc = concat!("let y = ", stringify!($name), "::<3>::from_array([0., 1., 0.]);")]
+            /// assert_eq!(x.cross3(y).to_array(), [0., 0., 1.]);
+            /// ```
+            #[inline]
+            pub fn cross3(self, other: Self) -> Self {
+                Self::from_xyzw(self.to_xyzw().cross3(other.to_xyzw()))
+            }
+
+            /// Returns the squared length of the vector.
+            ///
+            /// This avoids the square root in [`Self::length`], which makes it useful for
+            /// comparing lengths.
+            #[inline]
+            pub fn length_squared(self) -> $type {
+                self.dot3(self)
+            }
+
+            /// Returns the length of the vector.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let v = ", stringify!($name), "::<3>::from_array([2., 3., 6.]);")]
+            /// assert_eq!(v.length(), 7.);
+            /// ```
+            #[inline]
+            #[cfg(feature = "std")]
+            pub fn length(self) -> $type {
+                self.to_xyzw().length()
+            }
+
+            /// Scales the vector to a length of 1.
+            ///
+            /// If the length is zero, the result contains non-finite values.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let v = ", stringify!($name), "::<3>::from_array([0., 3., 4.]);")]
+            /// assert_eq!(v.normalize().to_array(), [0., 0.6, 0.8]);
+            /// ```
+            #[inline]
+            #[cfg(feature = "std")]
+            pub fn normalize(self) -> Self {
+                Self::from_xyzw(self.to_xyzw().normalize())
+            }
+
+            /// Extends the vector to 4 lanes with a `w` of zero.
+            #[inline]
+            fn to_xyzw(self) -> crate::$name<4> {
+                let [x, y, z] = self.to_array();
+                crate::$name::from_array([x, y, z, 0.])
+            }
+
+            /// Drops the `w` lane of a 4-lane vector.
+            #[inline]
+            fn from_xyzw(vector: crate::$name<4>) -> Self {
+                let [x, y, z, _] = vector.to_array();
+                Self::from_array([x, y, z])
+            }
+        }
+    }
+}
+
+impl_geometry! { SimdF32, f32 }
+impl_geometry! { SimdF64, f64 }
@@ -0,0 +1,60 @@
+use crate::{LaneCount, SimdF64, SimdU64, SupportedLaneCount};
+
+/// Computes the integer square root of each lane, rounded down.
+///
+/// A floating-point estimate is refined with Newton's method, then corrected by at most one in
+/// each direction so that the result is exact.
+#[inline]
+fn isqrt_u64<const LANES: usize>(n: SimdU64<LANES>) -> SimdU64<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x: SimdF64<LANES> = unsafe { crate::intrinsics::simd_cast(n) };
+
+    // halving the exponent gives an estimate within 6%, and each Newton step squares the error
+    let mut y = SimdF64::from_bits((x.to_bits() >> 1) + SimdU64::splat(0x1ff8_0000_0000_0000));
+    for _ in 0..4 {
+        y = SimdF64::splat(0.5) * (y + x / y);
+    }
+
+    // the rounding of `x` leaves the estimate off by at most one
+    let max_root = SimdU64::splat(u32::MAX as u64);
+    let r: SimdU64<LANES> = unsafe { crate::intrinsics::simd_cast(y) };
+    let r = r.lanes_gt(max_root).select(max_root, r);
+    let r = (r * r).lanes_gt(n).select(r - SimdU64::splat(1), r);
+    // (r + 1)^2 <= n, rearranged to avoid overflow
+    let grow = (n - r * r).lanes_ge(r + r + SimdU64::splat(1));
+    grow.select(r + SimdU64::splat(1), r)
+}
+
+macro_rules! impl_isqrt {
+    { $($name:ident, $n:ident;)* } => {
+        $(
+            impl<const LANES: usize> crate::$name<LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                /// Returns the square root of each lane, rounded down.
+                ///
+                /// # Examples
+                /// ```
+                /// # use core_simd::*;
+                #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+                #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 15, 16, MAX]);")]
+                #[doc = concat!("let max_root = (1 << (", stringify!($n), "::BITS / 2)) - 1;")]
+                #[doc = concat!("assert_eq!(x.isqrt(), ", stringify!($name), "::from_array([0, 3, 4, max_root]));")]
+                /// ```
+                #[inline]
+                pub fn isqrt(self) -> Self {
+                    let wide: SimdU64<LANES> = unsafe { crate::intrinsics::simd_cast(self) };
+                    unsafe { crate::intrinsics::simd_cast(isqrt_u64(wide)) }
+                }
+            }
+        )*
+    }
+}
+
+impl_isqrt! {
+    SimdU32, u32;
+    SimdU64, u64;
+}
@@ -2,7 +2,7 @@ macro_rules! impl_traits {
     { $type:ident } => {
         impl<const LANES: usize> core::iter::Sum<Self> for crate::$type<LANES>
         where
-            Self: crate::LanesAtMost32,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
             fn sum<I: core::iter::Iterator<Item = Self>>(iter: I) -> Self {
                 iter.fold(Default::default(), core::ops::Add::add)
@@ -11,7 +11,7 @@ macro_rules! impl_traits {
 
         impl<const LANES: usize> core::iter::Product<Self> for crate::$type<LANES>
         where
-            Self: crate::LanesAtMost32,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
             fn product<I: core::iter::Iterator<Item = Self>>(iter: I) -> Self {
                 iter.fold(Default::default(), core::ops::Mul::mul)
@@ -20,7 +20,7 @@ macro_rules! impl_traits {
 
         impl<'a, const LANES: usize> core::iter::Sum<&'a Self> for crate::$type<LANES>
         where
-            Self: crate::LanesAtMost32,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
             fn sum<I: core::iter::Iterator<Item = &'a Self>>(iter: I) -> Self {
                 iter.fold(Default::default(), core::ops::Add::add)
@@ -29,7 +29,7 @@ macro_rules! impl_traits {
 
         impl<'a, const LANES: usize> core::iter::Product<&'a Self> for crate::$type<LANES>
         where
-            Self: crate::LanesAtMost32,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
             fn product<I: core::iter::Iterator<Item = &'a Self>>(iter: I) -> Self {
                 iter.fold(Default::default(), core::ops::Mul::mul)
@@ -38,6 +38,114 @@ macro_rules! impl_traits {
     }
 }
 
+/// Iterates over a slice a vector at a time, including the tail.
+///
+/// Each item is a vector along with a mask of the lanes that came from the slice.  Every vector is
+/// full except possibly the last, which is padded with zeros and has a partial mask.  This allows
+/// a single loop body to handle the entire slice.
+///
+/// Created by the `masked_chunks` method on each vector type.
+///
+/// ```
+/// # use core_simd::*;
+/// let data = [1., 2., 3., 4., 5., 6.];
+/// let mut sum = SimdF32::<4>::splat(0.);
+/// for (v, mask) in SimdF32::<4>::masked_chunks(&data) {
+///     sum += mask.select_or_default(v * v);
+/// }
+/// assert_eq!(sum.horizontal_sum(), 91.);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MaskedChunks<'a, V: MaskedChunk> {
+    chunks: core::slice::Chunks<'a, V::Scalar>,
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// Supporting trait for [`MaskedChunks`], relating a vector type to its mask type.
+pub trait MaskedChunk: Sealed + crate::Vector {
+    /// The mask type of the vector.
+    type Mask;
+
+    #[doc(hidden)]
+    fn from_partial_slice(slice: &[Self::Scalar]) -> (Self, Self::Mask);
+}
+
+impl<'a, V: MaskedChunk> Iterator for MaskedChunks<'a, V> {
+    type Item = (V, V::Mask);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(V::from_partial_slice)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<'a, V: MaskedChunk> ExactSizeIterator for MaskedChunks<'a, V> {}
+
+impl<'a, V: MaskedChunk> core::iter::FusedIterator for MaskedChunks<'a, V> {}
+
+macro_rules! impl_masked_chunks {
+    { $($type:ident => $mask:ident ($bits_ty:ident),)* } => {
+        $(
+            impl<const LANES: usize> Sealed for crate::$type<LANES> where crate::LaneCount<LANES>: crate::SupportedLaneCount {}
+
+            impl<const LANES: usize> MaskedChunk for crate::$type<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                type Mask = crate::$mask<LANES>;
+
+                #[inline]
+                fn from_partial_slice(slice: &[Self::Scalar]) -> (Self, Self::Mask) {
+                    let mut vector = Self::splat(Default::default());
+                    vector.as_mut_slice()[..slice.len()].copy_from_slice(slice);
+                    (vector, crate::$mask::from_len(slice.len()))
+                }
+            }
+
+            impl<const LANES: usize> crate::$type<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                /// Returns an iterator over `slice` a vector at a time, yielding each vector with
+                /// a mask of the lanes that came from the slice.  The final vector is padded with
+                /// zeros.  See [`MaskedChunks`](crate::MaskedChunks).
+                #[inline]
+                pub fn masked_chunks(slice: &[<Self as crate::Vector>::Scalar]) -> MaskedChunks<'_, Self> {
+                    MaskedChunks {
+                        chunks: slice.chunks(LANES),
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_masked_chunks! {
+    SimdI8 => Mask8 (SimdI8),
+    SimdI16 => Mask16 (SimdI16),
+    SimdI32 => Mask32 (SimdI32),
+    SimdI64 => Mask64 (SimdI64),
+    SimdIsize => MaskSize (SimdIsize),
+
+    SimdU8 => Mask8 (SimdI8),
+    SimdU16 => Mask16 (SimdI16),
+    SimdU32 => Mask32 (SimdI32),
+    SimdU64 => Mask64 (SimdI64),
+    SimdUsize => MaskSize (SimdIsize),
+
+    SimdF32 => Mask32 (SimdI32),
+    SimdF64 => Mask64 (SimdI64),
+}
+
 impl_traits! { SimdF32 }
 impl_traits! { SimdF64 }
 impl_traits! { SimdU8 }
@@ -0,0 +1,90 @@
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// A type representing a vector lane count.
+///
+/// Every vector and mask type is supported with the same lane counts, so a single
+/// `LaneCount<LANES>: SupportedLaneCount` bound is sufficient for generic code over any of them:
+/// ```
+/// # use core_simd::*;
+/// fn sum_lanes<const LANES: usize>(v: SimdF32<LANES>, m: Mask32<LANES>) -> f32
+/// where
+///     LaneCount<LANES>: SupportedLaneCount,
+/// {
+///     m.select(v, SimdF32::splat(0.)).horizontal_sum()
+/// }
+///
+/// let v = SimdF32::from_array([1., 2., 3., 4.]);
+/// assert_eq!(sum_lanes(v, v.lanes_gt(SimdF32::splat(2.))), 7.);
+/// ```
+pub struct LaneCount<const LANES: usize>;
+
+/// Helper trait for vector lane counts.
+///
+/// Implemented for [`LaneCount`] with each lane count supported by the implementation: vectors
+/// are supported with any number of lanes from 1 to 8, or with 16, 32, or 64 lanes.  Vectors with
+/// a number of lanes that isn't a power of two are padded, see [`Simd`](crate::Simd) for details.
+/// Some operations, such as shuffles and byte conversions, are only available for power-of-two
+/// lane counts.
+#[rustc_on_unimplemented(
+    message = "`{Self}` is not a supported lane count",
+    label = "unsupported number of lanes",
+    note = "vectors are supported with 1 to 8, 16, 32, or 64 lanes"
+)]
+pub trait SupportedLaneCount: Sealed {
+    /// The bitmask representation of a mask.
+    type BitMask: Copy + Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// The bitmask representation of a mask, as a vector of bytes.
+    type BitMaskVector: Copy + From<Self::BitMask> + Into<Self::BitMask>;
+
+    // TODO remove this when rustc intrinsics are more flexible
+    #[doc(hidden)]
+    type IntBitMask: Copy + PartialEq;
+
+    #[doc(hidden)]
+    const ALL_BITMASK: Self::BitMask;
+
+    #[doc(hidden)]
+    const NONE_BITMASK: Self::BitMask;
+}
+
+impl<const LANES: usize> Sealed for LaneCount<LANES> {}
+
+/// Returns a bitmask with the bits of the first `lanes` lanes set.
+const fn all_bitmask<const BYTES: usize>(lanes: usize) -> [u8; BYTES] {
+    let mut bitmask = [0; BYTES];
+    let mut lane = 0;
+    while lane < lanes {
+        bitmask[lane / 8] |= 1 << (lane % 8);
+        lane += 1;
+    }
+    bitmask
+}
+
+// The bitmask intrinsics take an integer with one bit per lane, except that vectors of at most 8
+// lanes all use a `u8`.  Larger lane counts must therefore be powers of two.
+macro_rules! impl_lane_count {
+    { $([u8; $bytes:literal], $int:ty => $($lanes:literal)*;)* } => {
+        $(
+        $(
+        impl SupportedLaneCount for LaneCount<$lanes> {
+            type BitMask = [u8; $bytes];
+            type BitMaskVector = crate::SimdU8<$bytes>;
+            type IntBitMask = $int;
+            const ALL_BITMASK: Self::BitMask = all_bitmask($lanes);
+            const NONE_BITMASK: Self::BitMask = [0; $bytes];
+        }
+        )*
+        )*
+    }
+}
+
+impl_lane_count! {
+    [u8; 1], u8 => 1 2 3 4 5 6 7 8;
+    [u8; 2], u16 => 16;
+    [u8; 4], u32 => 32;
+    [u8; 8], u64 => 64;
+}
@@ -1,9 +1,23 @@
 #![no_std]
 #![allow(incomplete_features)]
-#![feature(repr_simd, platform_intrinsics, simd_ffi, const_generics)]
+#![feature(
+    repr_simd,
+    platform_intrinsics,
+    simd_ffi,
+    const_generics,
+    const_fn_trait_bound,
+    const_fn_floating_point_arithmetic,
+    core_intrinsics,
+    rustc_attrs
+)]
+#![cfg_attr(any(target_arch = "x86", target_arch = "x86_64"), feature(asm, stdsimd))]
+#![cfg_attr(target_arch = "aarch64", feature(stdsimd))]
 #![warn(missing_docs)]
 //! Portable SIMD module.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[macro_use]
 mod first;
 #[macro_use]
@@ -12,24 +26,57 @@ mod permute;
 mod transmute;
 #[macro_use]
 mod reduction;
+pub use reduction::{SimdReduce, SimdReduceBits};
 
 mod select;
 pub use select::Select;
 
 mod to_bytes;
-pub use to_bytes::ToBytes;
+pub use to_bytes::{ReverseByteGroups, ToBytes};
+
+mod saturating;
+pub use saturating::Saturating;
+
+mod checked;
+pub use checked::Checked;
+
+mod iter;
+pub use iter::{MaskedChunk, MaskedChunks};
 
+mod parse;
+pub use parse::ParseVectorError;
+
+mod activation;
+mod approx;
+mod backend;
 mod comparisons;
+mod complex;
+mod const_ops;
+mod div_fast;
+#[cfg(feature = "std")]
+mod erf;
+mod fixed_point;
 mod fmt;
 mod intrinsics;
-mod iter;
+mod isqrt;
 mod ops;
+mod pairwise;
+mod parity;
+mod resize;
 mod round;
+mod rsqrt;
+mod trig;
 
+mod geometry;
 mod math;
+mod newtype;
+mod swizzle;
+
+#[cfg(feature = "num-traits")]
+mod num;
 
-mod lanes_at_most_32;
-pub use lanes_at_most_32::LanesAtMost32;
+mod lane_count;
+pub use lane_count::{LaneCount, SupportedLaneCount};
 
 mod masks;
 pub use masks::*;
@@ -39,3 +86,28 @@ pub use vector::*;
 
 mod array;
 pub use array::SimdArray;
+
+pub mod bitset;
+pub use bitset::SimdBitSet;
+
+mod divisor;
+pub use divisor::{DivisorU16, DivisorU32, DivisorU8};
+
+#[cfg(feature = "alloc")]
+pub mod aligned;
+
+pub mod base64;
+
+pub mod bitpack;
+
+pub mod denormals;
+
+pub mod slice_ops;
+
+pub mod prefetch;
+
+pub mod rng;
+
+pub mod utf16;
+
+pub mod utf8;
@@ -1,59 +1,70 @@
-use crate::Mask;
+use crate::{LaneCount, MaskElement, Simd, SupportedLaneCount, ToBitMask};
 use core::marker::PhantomData;
 
-/// Helper trait for limiting int conversion types
-pub trait ConvertToInt {}
-impl<const LANES: usize> ConvertToInt for crate::SimdI8<LANES> where Self: crate::LanesAtMost32 {}
-impl<const LANES: usize> ConvertToInt for crate::SimdI16<LANES> where Self: crate::LanesAtMost32 {}
-impl<const LANES: usize> ConvertToInt for crate::SimdI32<LANES> where Self: crate::LanesAtMost32 {}
-impl<const LANES: usize> ConvertToInt for crate::SimdI64<LANES> where Self: crate::LanesAtMost32 {}
-impl<const LANES: usize> ConvertToInt for crate::SimdIsize<LANES> where Self: crate::LanesAtMost32 {}
-
 /// A mask where each lane is represented by a single bit.
 #[repr(transparent)]
-pub struct BitMask<T: Mask, const LANES: usize>(T::BitMask, PhantomData<[(); LANES]>);
+pub struct Mask<T, const LANES: usize>(
+    <LaneCount<LANES> as SupportedLaneCount>::BitMask,
+    PhantomData<T>,
+)
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount;
 
-impl<T: Mask, const LANES: usize> Copy for BitMask<T, LANES> {}
+impl_bitmask_reductions! { Mask }
 
-impl<T: Mask, const LANES: usize> Clone for BitMask<T, LANES> {
+impl<T, const LANES: usize> Copy for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+impl<T, const LANES: usize> Clone for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<T: Mask, const LANES: usize> PartialEq for BitMask<T, LANES> {
+impl<T, const LANES: usize> PartialEq for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
     fn eq(&self, other: &Self) -> bool {
         self.0.as_ref() == other.0.as_ref()
     }
 }
 
-impl<T: Mask, const LANES: usize> PartialOrd for BitMask<T, LANES> {
-    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        self.0.as_ref().partial_cmp(other.0.as_ref())
-    }
-}
-
-impl<T: Mask, const LANES: usize> Eq for BitMask<T, LANES> {}
-
-impl<T: Mask, const LANES: usize> Ord for BitMask<T, LANES> {
-    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        self.0.as_ref().cmp(other.0.as_ref())
-    }
+impl<T, const LANES: usize> Eq for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
 }
 
-impl<T: Mask, const LANES: usize> BitMask<T, LANES> {
+impl<T, const LANES: usize> Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
     #[inline]
-    pub fn splat(value: bool) -> Self {
-        let mut mask = T::BitMask::default();
+    pub const fn splat(value: bool) -> Self {
         if value {
-            mask.as_mut().fill(u8::MAX)
+            Self(
+                <LaneCount<LANES> as SupportedLaneCount>::ALL_BITMASK,
+                PhantomData,
+            )
         } else {
-            mask.as_mut().fill(u8::MIN)
-        }
-        if LANES % 8 > 0 {
-            *mask.as_mut().last_mut().unwrap() &= u8::MAX >> (8 - LANES % 8);
+            Self(
+                <LaneCount<LANES> as SupportedLaneCount>::NONE_BITMASK,
+                PhantomData,
+            )
         }
-        Self(mask, PhantomData)
     }
 
     #[inline]
@@ -67,62 +78,65 @@ impl<T: Mask, const LANES: usize> BitMask<T, LANES> {
     }
 
     #[inline]
-    pub fn to_int<V>(self) -> V
-    where
-        V: ConvertToInt + Default + core::ops::Not<Output = V>,
-    {
+    pub fn to_int(self) -> Simd<T, LANES> {
         unsafe {
-            let mask: T::IntBitMask = core::mem::transmute_copy(&self);
-            crate::intrinsics::simd_select_bitmask(mask, !V::default(), V::default())
+            crate::intrinsics::simd_select_bitmask(
+                self.to_int_bitmask(),
+                Simd::splat(T::TRUE),
+                Simd::splat(T::FALSE),
+            )
         }
     }
 
     #[inline]
-    pub unsafe fn from_int_unchecked<V>(value: V) -> Self
-    where
-        V: crate::LanesAtMost32,
-    {
+    pub unsafe fn from_int_unchecked(value: Simd<T, LANES>) -> Self {
         // TODO remove the transmute when rustc is more flexible
         assert_eq!(
-            core::mem::size_of::<T::IntBitMask>(),
-            core::mem::size_of::<T::BitMask>()
+            core::mem::size_of::<<LaneCount<LANES> as SupportedLaneCount>::IntBitMask>(),
+            core::mem::size_of::<<LaneCount<LANES> as SupportedLaneCount>::BitMask>()
         );
-        let mask: T::IntBitMask = crate::intrinsics::simd_bitmask(value);
+        let mask: <LaneCount<LANES> as SupportedLaneCount>::IntBitMask =
+            crate::intrinsics::simd_bitmask(value);
         Self(core::mem::transmute_copy(&mask), PhantomData)
     }
 
     #[inline]
-    pub fn to_bitmask<U: Mask>(self) -> U::BitMask {
+    pub fn to_bitmask<U: ToBitMask>(self) -> U::BitMask {
         assert_eq!(
-            core::mem::size_of::<T::BitMask>(),
+            core::mem::size_of::<<LaneCount<LANES> as SupportedLaneCount>::BitMask>(),
             core::mem::size_of::<U::BitMask>()
         );
         unsafe { core::mem::transmute_copy(&self.0) }
     }
 
     #[inline]
-    pub fn from_bitmask<U: Mask>(bitmask: U::BitMask) -> Self {
+    pub fn from_bitmask<U: ToBitMask>(mut bitmask: U::BitMask) -> Self {
         assert_eq!(
-            core::mem::size_of::<T::BitMask>(),
+            core::mem::size_of::<<LaneCount<LANES> as SupportedLaneCount>::BitMask>(),
             core::mem::size_of::<U::BitMask>()
         );
+        // clear the bits above the number of lanes, which would otherwise break comparisons
+        for (x, all) in bitmask.as_mut().iter_mut().zip(U::ALL_BITMASK.as_ref()) {
+            *x &= all;
+        }
         unsafe { core::mem::transmute_copy(&bitmask) }
     }
 
     #[inline]
-    pub fn any(self) -> bool {
-        self != Self::splat(false)
-    }
-
-    #[inline]
-    pub fn all(self) -> bool {
-        self == Self::splat(true)
+    fn to_int_bitmask(self) -> <LaneCount<LANES> as SupportedLaneCount>::IntBitMask {
+        // TODO remove the transmute when rustc is more flexible
+        assert_eq!(
+            core::mem::size_of::<<LaneCount<LANES> as SupportedLaneCount>::IntBitMask>(),
+            core::mem::size_of::<<LaneCount<LANES> as SupportedLaneCount>::BitMask>()
+        );
+        unsafe { core::mem::transmute_copy(&self.0) }
     }
 }
 
-impl<T: Mask, const LANES: usize> core::ops::BitAnd for BitMask<T, LANES>
+impl<T, const LANES: usize> core::ops::BitAnd for Mask<T, LANES>
 where
-    T::BitMask: Default + AsRef<[u8]> + AsMut<[u8]>,
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
 {
     type Output = Self;
     #[inline]
@@ -134,9 +148,10 @@ where
     }
 }
 
-impl<T: Mask, const LANES: usize> core::ops::BitOr for BitMask<T, LANES>
+impl<T, const LANES: usize> core::ops::BitOr for Mask<T, LANES>
 where
-    T::BitMask: Default + AsRef<[u8]> + AsMut<[u8]>,
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
 {
     type Output = Self;
     #[inline]
@@ -148,7 +163,11 @@ where
     }
 }
 
-impl<T: Mask, const LANES: usize> core::ops::BitXor for BitMask<T, LANES> {
+impl<T, const LANES: usize> core::ops::BitXor for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
     type Output = Self;
     #[inline]
     fn bitxor(mut self, rhs: Self) -> Self::Output {
@@ -159,45 +178,39 @@ impl<T: Mask, const LANES: usize> core::ops::BitXor for BitMask<T, LANES> {
     }
 }
 
-impl<T: Mask, const LANES: usize> core::ops::Not for BitMask<T, LANES> {
+impl<T, const LANES: usize> core::ops::Not for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
     type Output = Self;
     #[inline]
     fn not(mut self) -> Self::Output {
-        for x in self.0.as_mut() {
-            *x = !*x;
-        }
-        if LANES % 8 > 0 {
-            *self.0.as_mut().last_mut().unwrap() &= u8::MAX >> (8 - LANES % 8);
+        // clear the bits above the number of lanes, which would otherwise break comparisons
+        let all = <LaneCount<LANES> as SupportedLaneCount>::ALL_BITMASK;
+        for (x, all) in self.0.as_mut().iter_mut().zip(all.as_ref()) {
+            *x = !*x & all;
         }
         self
     }
 }
 
-pub type Mask8<T, const LANES: usize> = BitMask<T, LANES>;
-pub type Mask16<T, const LANES: usize> = BitMask<T, LANES>;
-pub type Mask32<T, const LANES: usize> = BitMask<T, LANES>;
-pub type Mask64<T, const LANES: usize> = BitMask<T, LANES>;
-pub type MaskSize<T, const LANES: usize> = BitMask<T, LANES>;
-
 macro_rules! impl_from {
-    { $from:ident ($from_inner:ident) => $($to:ident ($to_inner:ident)),* } => {
+    { $from:ty => $($to:ty),* } => {
         $(
-        impl<const LANES: usize> From<$from<crate::$from<LANES>, LANES>> for $to<crate::$to<LANES>, LANES>
+        impl<const LANES: usize> From<Mask<$from, LANES>> for Mask<$to, LANES>
         where
-            crate::$from_inner<LANES>: crate::LanesAtMost32,
-            crate::$to_inner<LANES>: crate::LanesAtMost32,
-            crate::$from<LANES>: crate::Mask,
-            crate::$to<LANES>: crate::Mask,
+            LaneCount<LANES>: SupportedLaneCount,
         {
-            fn from(value: $from<crate::$from<LANES>, LANES>) -> Self {
+            fn from(value: Mask<$from, LANES>) -> Self {
                 unsafe { core::mem::transmute_copy(&value) }
             }
         }
         )*
     }
 }
-impl_from! { Mask8 (SimdI8) => Mask16 (SimdI16), Mask32 (SimdI32), Mask64 (SimdI64), MaskSize (SimdIsize) }
-impl_from! { Mask16 (SimdI16) => Mask32 (SimdI32), Mask64 (SimdI64), MaskSize (SimdIsize), Mask8 (SimdI8) }
-impl_from! { Mask32 (SimdI32) => Mask64 (SimdI64), MaskSize (SimdIsize), Mask8 (SimdI8), Mask16 (SimdI16) }
-impl_from! { Mask64 (SimdI64) => MaskSize (SimdIsize), Mask8 (SimdI8), Mask16 (SimdI16), Mask32 (SimdI32) }
-impl_from! { MaskSize (SimdIsize) => Mask8 (SimdI8), Mask16 (SimdI16), Mask32 (SimdI32), Mask64 (SimdI64) }
+impl_from! { i8 => i16, i32, i64, isize }
+impl_from! { i16 => i32, i64, isize, i8 }
+impl_from! { i32 => i64, isize, i8, i16 }
+impl_from! { i64 => isize, i8, i16, i32 }
+impl_from! { isize => i8, i16, i32, i64 }
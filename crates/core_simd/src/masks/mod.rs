@@ -12,490 +12,952 @@
 )]
 mod mask_impl;
 
-use crate::{LanesAtMost32, SimdI16, SimdI32, SimdI64, SimdI8, SimdIsize};
+mod repr;
+pub use repr::{BitMask, MaskRepr, VectorMask};
+
+use crate::{LaneCount, Simd, SimdElement, SupportedLaneCount};
 
 mod sealed {
     pub trait Sealed {}
 }
 
-/// Helper trait for mask types.
-pub trait Mask: sealed::Sealed {
-    /// The bitmask representation of a mask.
-    type BitMask: Copy + Default + AsRef<[u8]> + AsMut<[u8]>;
-
-    // TODO remove this when rustc intrinsics are more flexible
+/// A type which can be used as the element type of a [`Mask`].
+///
+/// This trait is sealed, and is implemented for the signed integer types.  Each lane of a mask
+/// with element type `T` has the size of a `T`, so the mask selects lanes of vectors with
+/// elements of that size.
+pub trait MaskElement: SimdElement + PartialEq + sealed::Sealed {
     #[doc(hidden)]
-    type IntBitMask;
-}
-
-macro_rules! define_opaque_mask {
-    {
-        $(#[$attr:meta])*
-        struct $name:ident<const $lanes:ident: usize>($inner_ty:ty);
-        @bits $bits_ty:ident
-    } => {
-        $(#[$attr])*
-        #[allow(non_camel_case_types)]
-        pub struct $name<const LANES: usize>($inner_ty)
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask;
-
-        impl<const LANES: usize> sealed::Sealed for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {}
-        impl Mask for $name<1> {
-            type BitMask = [u8; 1];
-            type IntBitMask = u8;
-        }
-        impl Mask for $name<2> {
-            type BitMask = [u8; 1];
-            type IntBitMask = u8;
-        }
-        impl Mask for $name<4> {
-            type BitMask = [u8; 1];
-            type IntBitMask = u8;
-        }
-        impl Mask for $name<8> {
-            type BitMask = [u8; 1];
-            type IntBitMask = u8;
-        }
-        impl Mask for $name<16> {
-            type BitMask = [u8; 2];
-            type IntBitMask = u16;
-        }
-        impl Mask for $name<32> {
-            type BitMask = [u8; 4];
-            type IntBitMask = u32;
-        }
-
-        impl_opaque_mask_reductions! { $name, $bits_ty }
-
-        impl<const LANES: usize> $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            /// Construct a mask by setting all lanes to the given value.
-            pub fn splat(value: bool) -> Self {
-                Self(<$inner_ty>::splat(value))
-            }
-
-            /// Converts an array to a SIMD vector.
-            pub fn from_array(array: [bool; LANES]) -> Self {
-                let mut vector = Self::splat(false);
-                let mut i = 0;
-                while i < $lanes {
-                    vector.set(i, array[i]);
-                    i += 1;
-                }
-                vector
-            }
+    const TRUE: Self;
 
-            /// Converts a SIMD vector to an array.
-            pub fn to_array(self) -> [bool; LANES] {
-                let mut array = [false; LANES];
-                let mut i = 0;
-                while i < $lanes {
-                    array[i] = self.test(i);
-                    i += 1;
-                }
-                array
-            }
-
-            /// Converts a vector of integers to a mask, where 0 represents `false` and -1
-            /// represents `true`.
-            ///
-            /// # Safety
-            /// All lanes must be either 0 or -1.
-            #[inline]
-            pub unsafe fn from_int_unchecked(value: $bits_ty<LANES>) -> Self {
-                Self(<$inner_ty>::from_int_unchecked(value))
-            }
-
-            /// Converts a vector of integers to a mask, where 0 represents `false` and -1
-            /// represents `true`.
-            ///
-            /// # Panics
-            /// Panics if any lane is not 0 or -1.
-            #[inline]
-            pub fn from_int(value: $bits_ty<LANES>) -> Self {
-                assert!(
-                    (value.lanes_eq($bits_ty::splat(0)) | value.lanes_eq($bits_ty::splat(-1))).all(),
-                    "all values must be either 0 or -1",
-                );
-                unsafe { Self::from_int_unchecked(value) }
-            }
-
-            /// Converts the mask to a vector of integers, where 0 represents `false` and -1
-            /// represents `true`.
-            #[inline]
-            pub fn to_int(self) -> $bits_ty<LANES> {
-                self.0.to_int()
-            }
+    #[doc(hidden)]
+    const FALSE: Self;
+}
 
-            /// Tests the value of the specified lane.
-            ///
-            /// # Safety
-            /// `lane` must be less than `LANES`.
-            #[inline]
-            pub unsafe fn test_unchecked(&self, lane: usize) -> bool {
-                self.0.test_unchecked(lane)
+macro_rules! impl_element {
+    { $($type:ty),* } => {
+        $(
+            impl sealed::Sealed for $type {}
+            impl MaskElement for $type {
+                const TRUE: Self = -1;
+                const FALSE: Self = 0;
             }
+        )*
+    }
+}
 
-            /// Tests the value of the specified lane.
-            ///
-            /// # Panics
-            /// Panics if `lane` is greater than or equal to the number of lanes in the vector.
-            #[inline]
-            pub fn test(&self, lane: usize) -> bool {
-                assert!(lane < LANES, "lane index out of range");
-                unsafe { self.test_unchecked(lane) }
-            }
+impl_element! { i8, i16, i32, i64, isize }
 
-            /// Sets the value of the specified lane.
-            ///
-            /// # Safety
-            /// `lane` must be less than `LANES`.
-            #[inline]
-            pub unsafe fn set_unchecked(&mut self, lane: usize, value: bool) {
-                self.0.set_unchecked(lane, value);
-            }
-
-            /// Sets the value of the specified lane.
-            ///
-            /// # Panics
-            /// Panics if `lane` is greater than or equal to the number of lanes in the vector.
-            #[inline]
-            pub fn set(&mut self, lane: usize, value: bool) {
-                assert!(lane < LANES, "lane index out of range");
-                unsafe { self.set_unchecked(lane, value); }
-            }
+/// Helper trait for mask types, giving the bitmask representation of masks with each supported
+/// number of lanes.
+///
+/// This is implemented for every mask type, forwarding to [`SupportedLaneCount`], so that code
+/// generic over the mask type (such as [`BitMask`]) can name its bitmask representation.
+pub trait ToBitMask: sealed::Sealed {
+    /// The bitmask representation of a mask.
+    type BitMask: Copy + Default + AsRef<[u8]> + AsMut<[u8]>;
 
-            /// Convert this mask to a bitmask, with one bit set per lane.
-            pub fn to_bitmask(self) -> <Self as Mask>::BitMask {
-                self.0.to_bitmask::<Self>()
-            }
+    /// The bitmask representation of a mask, as a vector of bytes.
+    type BitMaskVector: Copy + From<Self::BitMask> + Into<Self::BitMask>;
 
-            /// Convert a bitmask to a mask.
-            pub fn from_bitmask(bitmask: <Self as Mask>::BitMask) -> Self {
-                Self(<$inner_ty>::from_bitmask::<Self>(bitmask))
-            }
-        }
+    // TODO remove this when rustc intrinsics are more flexible
+    #[doc(hidden)]
+    type IntBitMask: Copy + PartialEq;
 
-        // vector/array conversion
-        impl<const LANES: usize> From<[bool; LANES]> for $name<LANES>
-        where
-            $bits_ty<LANES>: crate::LanesAtMost32,
-            Self: Mask,
-        {
-            fn from(array: [bool; LANES]) -> Self {
-                Self::from_array(array)
-            }
-        }
+    #[doc(hidden)]
+    const ALL_BITMASK: Self::BitMask;
 
-        impl <const LANES: usize> From<$name<LANES>> for [bool; LANES]
-        where
-            $bits_ty<LANES>: crate::LanesAtMost32,
-            $name<LANES>: Mask,
-        {
-            fn from(vector: $name<LANES>) -> Self {
-                vector.to_array()
-            }
-        }
+    #[doc(hidden)]
+    const NONE_BITMASK: Self::BitMask;
+}
 
-        impl<const LANES: usize> Copy for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {}
+/// Implements a binary operator over references to masks and `bool`s, in terms of the operator
+/// over values.
+macro_rules! impl_mask_ref_ops {
+    { $trait:ident :: $fn:ident, $assign_trait:ident :: $assign_fn:ident } => {
+        impl_mask_ref_ops! { @binary $trait::$fn, Mask<T, LANES>, Mask<T, LANES> }
+        impl_mask_ref_ops! { @binary $trait::$fn, Mask<T, LANES>, bool }
+        impl_mask_ref_ops! { @binary $trait::$fn, bool, Mask<T, LANES> }
+        impl_mask_ref_ops! { @assign $assign_trait::$assign_fn, Mask<T, LANES> }
+        impl_mask_ref_ops! { @assign $assign_trait::$assign_fn, bool }
+    };
 
-        impl<const LANES: usize> Clone for $name<LANES>
+    { @binary $trait:ident :: $fn:ident, $lhs:ty, $rhs:ty } => {
+        impl<T, const LANES: usize> core::ops::$trait<&'_ $rhs> for $lhs
         where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
+            T: MaskElement,
+            LaneCount<LANES>: SupportedLaneCount,
         {
+            type Output = Mask<T, LANES>;
             #[inline]
-            fn clone(&self) -> Self {
-                *self
+            fn $fn(self, rhs: &$rhs) -> Self::Output {
+                core::ops::$trait::$fn(self, *rhs)
             }
         }
 
-        impl<const LANES: usize> Default for $name<LANES>
+        impl<T, const LANES: usize> core::ops::$trait<$rhs> for &'_ $lhs
         where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
+            T: MaskElement,
+            LaneCount<LANES>: SupportedLaneCount,
         {
+            type Output = Mask<T, LANES>;
             #[inline]
-            fn default() -> Self {
-                Self::splat(false)
+            fn $fn(self, rhs: $rhs) -> Self::Output {
+                core::ops::$trait::$fn(*self, rhs)
             }
         }
 
-        impl<const LANES: usize> PartialEq for $name<LANES>
+        impl<T, const LANES: usize> core::ops::$trait<&'_ $rhs> for &'_ $lhs
         where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
+            T: MaskElement,
+            LaneCount<LANES>: SupportedLaneCount,
         {
+            type Output = Mask<T, LANES>;
             #[inline]
-            fn eq(&self, other: &Self) -> bool {
-                self.0 == other.0
+            fn $fn(self, rhs: &$rhs) -> Self::Output {
+                core::ops::$trait::$fn(*self, *rhs)
             }
         }
+    };
 
-        impl<const LANES: usize> PartialOrd for $name<LANES>
+    { @assign $trait:ident :: $fn:ident, $rhs:ty } => {
+        impl<T, const LANES: usize> core::ops::$trait<&'_ $rhs> for Mask<T, LANES>
         where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
+            T: MaskElement,
+            LaneCount<LANES>: SupportedLaneCount,
         {
             #[inline]
-            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-                self.0.partial_cmp(&other.0)
+            fn $fn(&mut self, rhs: &$rhs) {
+                core::ops::$trait::$fn(self, *rhs)
             }
         }
+    };
+}
 
-        impl<const LANES: usize> core::fmt::Debug for $name<LANES>
-        where
-            $bits_ty<LANES>: crate::LanesAtMost32,
-            Self: Mask,
-        {
-            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-                f.debug_list()
-                    .entries((0..LANES).map(|lane| self.test(lane)))
-                    .finish()
-            }
-        }
+/// The error type returned when converting a vector of integers to a mask fails, because a lane
+/// is not 0 or -1.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InvalidMaskError(());
 
-        impl<const LANES: usize> core::ops::BitAnd for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            type Output = Self;
-            #[inline]
-            fn bitand(self, rhs: Self) -> Self {
-                Self(self.0 & rhs.0)
-            }
-        }
+impl core::fmt::Display for InvalidMaskError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("all values must be either 0 or -1")
+    }
+}
 
-        impl<const LANES: usize> core::ops::BitAnd<bool> for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            type Output = Self;
-            #[inline]
-            fn bitand(self, rhs: bool) -> Self {
-                self & Self::splat(rhs)
-            }
-        }
+/// A SIMD vector mask for `LANES` elements of width specified by `T`.
+///
+/// A mask with element type `T` selects lanes of vectors with elements the size of a `T`: for
+/// example, `Mask<i32, LANES>` (also called [`Mask32`]) is the mask type of [`SimdF32`],
+/// [`SimdI32`], and [`SimdU32`].  This allows writing a single function for masks of every
+/// width:
+/// ```
+/// # use core_simd::*;
+/// fn count_set<T: MaskElement>(mask: Mask<T, 4>) -> usize {
+///     mask.to_array().iter().filter(|x| **x).count()
+/// }
+///
+/// assert_eq!(count_set(Mask8::from_array([true, false, true, true])), 3);
+/// assert_eq!(count_set(SimdF64::from_array([1., 2., 3., 4.]).lanes_gt(SimdF64::splat(2.))), 2);
+/// ```
+///
+/// The layout of this type is unspecified.
+pub struct Mask<T, const LANES: usize>(mask_impl::Mask<T, LANES>)
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount;
+
+impl<T, const LANES: usize> sealed::Sealed for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
 
-        impl<const LANES: usize> core::ops::BitAnd<$name<LANES>> for bool
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            $name<LANES>: Mask,
-        {
-            type Output = $name<LANES>;
-            #[inline]
-            fn bitand(self, rhs: $name<LANES>) -> $name<LANES> {
-                $name::<LANES>::splat(self) & rhs
-            }
-        }
+impl<T, const LANES: usize> ToBitMask for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type BitMask = <LaneCount<LANES> as SupportedLaneCount>::BitMask;
+    type BitMaskVector = <LaneCount<LANES> as SupportedLaneCount>::BitMaskVector;
+    type IntBitMask = <LaneCount<LANES> as SupportedLaneCount>::IntBitMask;
+    const ALL_BITMASK: Self::BitMask = <LaneCount<LANES> as SupportedLaneCount>::ALL_BITMASK;
+    const NONE_BITMASK: Self::BitMask = <LaneCount<LANES> as SupportedLaneCount>::NONE_BITMASK;
+}
 
-        impl<const LANES: usize> core::ops::BitOr for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            type Output = Self;
-            #[inline]
-            fn bitor(self, rhs: Self) -> Self {
-                Self(self.0 | rhs.0)
-            }
-        }
+impl_opaque_mask_reductions! { Mask }
 
-        impl<const LANES: usize> core::ops::BitOr<bool> for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            type Output = Self;
-            #[inline]
-            fn bitor(self, rhs: bool) -> Self {
-                self | Self::splat(rhs)
-            }
-        }
+impl<T, const LANES: usize> Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// A mask with all lanes set.
+    pub const ALL: Self = Self::splat(true);
 
-        impl<const LANES: usize> core::ops::BitOr<$name<LANES>> for bool
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            $name<LANES>: Mask,
-        {
-            type Output = $name<LANES>;
-            #[inline]
-            fn bitor(self, rhs: $name<LANES>) -> $name<LANES> {
-                $name::<LANES>::splat(self) | rhs
-            }
-        }
+    /// A mask with no lanes set.
+    pub const NONE: Self = Self::splat(false);
 
-        impl<const LANES: usize> core::ops::BitXor for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            type Output = Self;
-            #[inline]
-            fn bitxor(self, rhs: Self) -> Self::Output {
-                Self(self.0 ^ rhs.0)
-            }
-        }
+    /// Construct a mask by setting all lanes to the given value.
+    #[inline]
+    pub const fn splat(value: bool) -> Self {
+        Self(mask_impl::Mask::splat(value))
+    }
 
-        impl<const LANES: usize> core::ops::BitXor<bool> for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            type Output = Self;
-            #[inline]
-            fn bitxor(self, rhs: bool) -> Self::Output {
-                self ^ Self::splat(rhs)
-            }
-        }
+    /// Construct a mask with the first `n` lanes set, and the remaining lanes unset.
+    ///
+    /// # Panics
+    /// Panics if `n` is greater than the number of lanes.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// assert_eq!(Mask32::<4>::first_n(3).to_array(), [true, true, true, false]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn first_n(n: usize) -> Self {
+        assert!(
+            n <= LANES,
+            "number of set lanes must not exceed the number of lanes"
+        );
+        Self::from_len(n)
+    }
 
-        impl<const LANES: usize> core::ops::BitXor<$name<LANES>> for bool
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            $name<LANES>: Mask,
-        {
-            type Output = $name<LANES>;
-            #[inline]
-            fn bitxor(self, rhs: $name<LANES>) -> Self::Output {
-                $name::<LANES>::splat(self) ^ rhs
-            }
+    /// Construct a mask with the lanes before `lane` set, and the remaining lanes unset.
+    ///
+    /// This is the complement of [`all_from`](Self::all_from), and is useful for splitting a
+    /// vector at a lane found at runtime.  It is the same as [`first_n`](Self::first_n), named to
+    /// pair with `all_from`.
+    ///
+    /// # Panics
+    /// Panics if `lane` is greater than the number of lanes.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// assert_eq!(Mask32::<4>::all_until(1).to_array(), [true, false, false, false]);
+    /// assert!(Mask32::<4>::all_until(4).all());
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn all_until(lane: usize) -> Self {
+        Self::first_n(lane)
+    }
+
+    /// Construct a mask with the lanes at or after `lane` set, and the lanes before it unset.
+    ///
+    /// This is the complement of [`all_until`](Self::all_until), and is useful for splitting a
+    /// vector at a lane found at runtime.
+    ///
+    /// # Panics
+    /// Panics if `lane` is greater than the number of lanes.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// assert_eq!(Mask32::<4>::all_from(1).to_array(), [false, true, true, true]);
+    /// assert!(!Mask32::<4>::all_from(4).any());
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn all_from(lane: usize) -> Self {
+        !Self::all_until(lane)
+    }
+
+    /// Construct a mask of the lanes that are in bounds when `len` elements remain, such
+    /// as for the last chunk of a slice.  The first `len` lanes are set, or every lane if
+    /// `len` is at least the number of lanes.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// assert_eq!(Mask32::<4>::from_len(2).to_array(), [true, true, false, false]);
+    /// assert!(Mask32::<4>::from_len(100).all());
+    /// ```
+    #[inline]
+    pub fn from_len(len: usize) -> Self {
+        let mut bitmask = <LaneCount<LANES> as SupportedLaneCount>::NONE_BITMASK;
+        for (i, byte) in bitmask.as_mut().iter_mut().enumerate() {
+            let bits = len.saturating_sub(i * 8).min(8);
+            *byte = ((1u16 << bits) - 1) as u8;
         }
+        Self::from_bitmask(bitmask)
+    }
 
-        impl<const LANES: usize> core::ops::Not for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            type Output = $name<LANES>;
-            #[inline]
-            fn not(self) -> Self::Output {
-                Self(!self.0)
-            }
+    /// Shifts the lanes of the mask left by `OFFSET` lanes, so that lane `i` of the result
+    /// is lane `i + OFFSET` of `self`.  Lanes shifted in from past the end are unset.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mask = Mask32::from_array([true, false, true, true]);
+    /// assert_eq!(mask.shift_lanes_left::<1>().to_array(), [false, true, true, false]);
+    /// ```
+    #[inline]
+    pub fn shift_lanes_left<const OFFSET: usize>(self) -> Self {
+        let shifted = self
+            .to_int()
+            .sliding_window(Simd::splat(T::FALSE), OFFSET.min(LANES));
+        unsafe { Self::from_int_unchecked(shifted) }
+    }
+
+    /// Shifts the lanes of the mask right by `OFFSET` lanes, so that lane `i + OFFSET` of
+    /// the result is lane `i` of `self`.  Lanes shifted in from before the start are unset.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mask = Mask32::from_array([true, false, true, true]);
+    /// assert_eq!(mask.shift_lanes_right::<1>().to_array(), [false, true, false, true]);
+    /// ```
+    #[inline]
+    pub fn shift_lanes_right<const OFFSET: usize>(self) -> Self {
+        let offset = LANES - OFFSET.min(LANES);
+        let shifted = Simd::splat(T::FALSE).sliding_window(self.to_int(), offset);
+        unsafe { Self::from_int_unchecked(shifted) }
+    }
+
+    /// Shifts the lanes of the mask right by `OFFSET` lanes, shifting in the last lanes of
+    /// `previous`.
+    ///
+    /// When `previous` and `self` are masks of adjacent vectors, lane `i` of the result is
+    /// the lane `OFFSET` positions before lane `i` of `self`.  This carries state between
+    /// vectors, such as whether the previous byte was an escape character.
+    ///
+    /// # Panics
+    /// Panics if `OFFSET` is greater than the number of lanes.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let previous = Mask32::from_array([false, false, false, true]);
+    /// let mask = Mask32::from_array([false, true, false, false]);
+    /// assert_eq!(mask.shift_lanes_right_from::<1>(previous).to_array(), [true, false, true, false]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn shift_lanes_right_from<const OFFSET: usize>(self, previous: Self) -> Self {
+        assert!(
+            OFFSET <= LANES,
+            "offset must not exceed the number of lanes"
+        );
+        let shifted = previous
+            .to_int()
+            .sliding_window(self.to_int(), LANES - OFFSET);
+        unsafe { Self::from_int_unchecked(shifted) }
+    }
+
+    /// Rotates the lanes of the mask left by `OFFSET` lanes, so that lane `i` of the result
+    /// is lane `(i + OFFSET) % LANES` of `self`.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mask = Mask32::from_array([true, false, true, true]);
+    /// assert_eq!(mask.rotate_lanes_left::<1>().to_array(), [false, true, true, true]);
+    /// ```
+    #[inline]
+    pub fn rotate_lanes_left<const OFFSET: usize>(self) -> Self {
+        let int = self.to_int();
+        unsafe { Self::from_int_unchecked(int.sliding_window(int, OFFSET % LANES)) }
+    }
+
+    /// Rotates the lanes of the mask right by `OFFSET` lanes, so that lane
+    /// `(i + OFFSET) % LANES` of the result is lane `i` of `self`.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mask = Mask32::from_array([true, false, true, false]);
+    /// assert_eq!(mask.rotate_lanes_right::<1>().to_array(), [false, true, false, true]);
+    /// ```
+    #[inline]
+    pub fn rotate_lanes_right<const OFFSET: usize>(self) -> Self {
+        let int = self.to_int();
+        let offset = (LANES - OFFSET % LANES) % LANES;
+        unsafe { Self::from_int_unchecked(int.sliding_window(int, offset)) }
+    }
+
+    /// Converts an array to a SIMD vector.
+    pub fn from_array(array: [bool; LANES]) -> Self {
+        let mut vector = Self::splat(false);
+        let mut i = 0;
+        while i < LANES {
+            vector.set(i, array[i]);
+            i += 1;
         }
+        vector
+    }
 
-        impl<const LANES: usize> core::ops::BitAndAssign for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            #[inline]
-            fn bitand_assign(&mut self, rhs: Self) {
-                self.0 = self.0 & rhs.0;
-            }
+    /// Converts a SIMD vector to an array.
+    pub fn to_array(self) -> [bool; LANES] {
+        let mut array = [false; LANES];
+        let mut i = 0;
+        while i < LANES {
+            array[i] = self.test(i);
+            i += 1;
         }
+        array
+    }
 
-        impl<const LANES: usize> core::ops::BitAndAssign<bool> for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            #[inline]
-            fn bitand_assign(&mut self, rhs: bool) {
-                *self &= Self::splat(rhs);
-            }
+    /// Converts a vector of integers to a mask, where 0 represents `false` and -1
+    /// represents `true`.
+    ///
+    /// # Safety
+    /// All lanes must be either 0 or -1.
+    #[inline]
+    pub unsafe fn from_int_unchecked(value: Simd<T, LANES>) -> Self {
+        Self(mask_impl::Mask::from_int_unchecked(value))
+    }
+
+    /// Converts a vector of integers to a mask, where 0 represents `false` and -1
+    /// represents `true`.
+    ///
+    /// # Panics
+    /// Panics if any lane is not 0 or -1.
+    #[inline]
+    pub fn from_int(value: Simd<T, LANES>) -> Self {
+        match Self::try_from_int(value) {
+            Ok(mask) => mask,
+            Err(_) => panic!("all values must be either 0 or -1"),
         }
+    }
 
-        impl<const LANES: usize> core::ops::BitOrAssign for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            #[inline]
-            fn bitor_assign(&mut self, rhs: Self) {
-                self.0 = self.0 | rhs.0;
-            }
+    /// Converts a vector of integers to a mask, where 0 represents `false` and -1
+    /// represents `true`, or returns an error if any lane is not 0 or -1.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mask = Mask32::try_from_int(SimdI32::from_array([0, -1, -1, 0]));
+    /// assert_eq!(mask, Ok(Mask32::from_array([false, true, true, false])));
+    /// assert!(Mask32::try_from_int(SimdI32::from_array([0, 1, -1, 0])).is_err());
+    /// ```
+    #[inline]
+    pub fn try_from_int(value: Simd<T, LANES>) -> Result<Self, InvalidMaskError> {
+        let valid: Simd<T, LANES> = unsafe {
+            crate::intrinsics::simd_or(
+                crate::intrinsics::simd_eq(value, Simd::splat(T::FALSE)),
+                crate::intrinsics::simd_eq(value, Simd::splat(T::TRUE)),
+            )
+        };
+        if unsafe { crate::intrinsics::simd_reduce_all(valid) } {
+            Ok(unsafe { Self::from_int_unchecked(value) })
+        } else {
+            Err(InvalidMaskError(()))
         }
+    }
 
-        impl<const LANES: usize> core::ops::BitOrAssign<bool> for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            #[inline]
-            fn bitor_assign(&mut self, rhs: bool) {
-                *self |= Self::splat(rhs);
-            }
+    /// Converts the mask to a vector of integers, where 0 represents `false` and -1
+    /// represents `true`.
+    #[inline]
+    pub fn to_int(self) -> Simd<T, LANES> {
+        self.0.to_int()
+    }
+
+    /// Converts the mask to a vector of bytes, where 0 represents `false` and 1 represents
+    /// `true`.
+    ///
+    /// This matches the representation of arrays of C `bool` and of byte-per-lane
+    /// validity buffers.
+    #[inline]
+    pub fn to_bool_int(self) -> crate::SimdU8<LANES> {
+        unsafe { crate::intrinsics::simd_cast(crate::intrinsics::simd_neg(self.to_int())) }
+    }
+
+    /// Converts a vector of bytes to a mask, where 0 represents `false` and any other
+    /// value represents `true`.
+    #[inline]
+    pub fn from_bool_int(value: crate::SimdU8<LANES>) -> Self {
+        unsafe {
+            let value: Simd<T, LANES> = crate::intrinsics::simd_cast(value);
+            Self::from_int_unchecked(crate::intrinsics::simd_ne(value, Simd::splat(T::FALSE)))
         }
+    }
 
-        impl<const LANES: usize> core::ops::BitXorAssign for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            #[inline]
-            fn bitxor_assign(&mut self, rhs: Self) {
-                self.0 = self.0 ^ rhs.0;
-            }
+    /// Tests the value of the specified lane.
+    ///
+    /// # Safety
+    /// `lane` must be less than `LANES`.
+    #[inline]
+    pub unsafe fn test_unchecked(&self, lane: usize) -> bool {
+        self.0.test_unchecked(lane)
+    }
+
+    /// Tests the value of the specified lane.
+    ///
+    /// # Panics
+    /// Panics if `lane` is greater than or equal to the number of lanes in the vector.
+    #[inline]
+    pub fn test(&self, lane: usize) -> bool {
+        assert!(lane < LANES, "lane index out of range");
+        unsafe { self.test_unchecked(lane) }
+    }
+
+    /// Sets the value of the specified lane.
+    ///
+    /// # Safety
+    /// `lane` must be less than `LANES`.
+    #[inline]
+    pub unsafe fn set_unchecked(&mut self, lane: usize, value: bool) {
+        self.0.set_unchecked(lane, value);
+    }
+
+    /// Sets the value of the specified lane.
+    ///
+    /// # Panics
+    /// Panics if `lane` is greater than or equal to the number of lanes in the vector.
+    #[inline]
+    pub fn set(&mut self, lane: usize, value: bool) {
+        assert!(lane < LANES, "lane index out of range");
+        unsafe {
+            self.set_unchecked(lane, value);
         }
+    }
 
-        impl<const LANES: usize> core::ops::BitXorAssign<bool> for $name<LANES>
-        where
-            $bits_ty<LANES>: LanesAtMost32,
-            Self: Mask,
-        {
-            #[inline]
-            fn bitxor_assign(&mut self, rhs: bool) {
-                *self ^= Self::splat(rhs);
-            }
+    /// Convert this mask to a bitmask, with one bit set per lane.
+    pub fn to_bitmask(self) -> <LaneCount<LANES> as SupportedLaneCount>::BitMask {
+        self.0.to_bitmask::<Self>()
+    }
+
+    /// Convert a bitmask to a mask.
+    ///
+    /// Bits above the number of lanes are ignored.  Use [`Self::checked_from_bitmask`] to
+    /// reject them instead.
+    pub fn from_bitmask(bitmask: <LaneCount<LANES> as SupportedLaneCount>::BitMask) -> Self {
+        Self(mask_impl::Mask::from_bitmask::<Self>(bitmask))
+    }
+
+    /// Convert a bitmask to a mask, returning `None` if any bit above the number of lanes
+    /// is set.
+    ///
+    /// This catches corrupted bitmasks, such as those read from external sources.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mask = Mask32::<4>::checked_from_bitmask([0b1001]);
+    /// assert_eq!(mask.map(|mask| mask.to_array()), Some([true, false, false, true]));
+    /// assert_eq!(Mask32::<4>::checked_from_bitmask([0b1_1001]), None);
+    /// ```
+    #[inline]
+    pub fn checked_from_bitmask(
+        bitmask: <LaneCount<LANES> as SupportedLaneCount>::BitMask,
+    ) -> Option<Self> {
+        let all = <LaneCount<LANES> as SupportedLaneCount>::ALL_BITMASK;
+        let stray = bitmask
+            .as_ref()
+            .iter()
+            .zip(all.as_ref())
+            .any(|(bits, all)| bits & !all != 0);
+        if stray {
+            None
+        } else {
+            Some(Self::from_bitmask(bitmask))
         }
-    };
-}
+    }
 
-define_opaque_mask! {
-    /// Mask for vectors with `LANES` 8-bit elements.
+    /// Convert this mask to a vector of bitmask bytes, with one bit set per lane.
     ///
-    /// The layout of this type is unspecified.
-    struct Mask8<const LANES: usize>(mask_impl::Mask8<Self, LANES>);
-    @bits SimdI8
-}
+    /// This has the same layout as [`Self::to_bitmask`], so bitmasks can be shifted and
+    /// combined with vector operations.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mask = Mask32::<16>::from_array([
+    ///     true, false, false, true, false, false, false, false,
+    ///     false, true, true, false, false, false, false, true,
+    /// ]);
+    /// let bytes = mask.to_bitmask_vector();
+    /// assert_eq!(bytes.to_array(), [0b0000_1001, 0b1000_0110]);
+    ///
+    /// // shift every lane down by one, within each byte
+    /// let shifted = Mask32::<16>::from_bitmask_vector(bytes >> 1);
+    /// assert_eq!(shifted.to_array()[..4], [false, false, true, false]);
+    /// ```
+    #[inline]
+    pub fn to_bitmask_vector(self) -> <LaneCount<LANES> as SupportedLaneCount>::BitMaskVector {
+        self.to_bitmask().into()
+    }
+
+    /// Convert a vector of bitmask bytes to a mask.
+    #[inline]
+    pub fn from_bitmask_vector(
+        bitmask: <LaneCount<LANES> as SupportedLaneCount>::BitMaskVector,
+    ) -> Self {
+        Self::from_bitmask(bitmask.into())
+    }
 
-define_opaque_mask! {
-    /// Mask for vectors with `LANES` 16-bit elements.
+    /// Computes the lanewise AND of the mask and a bitmask, with one bit per lane.
     ///
-    /// The layout of this type is unspecified.
-    struct Mask16<const LANES: usize>(mask_impl::Mask16<Self, LANES>);
-    @bits SimdI16
-}
+    /// This applies a precomputed bit pattern to a mask without building a second mask
+    /// from an array of bools.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mask = Mask32::<4>::from_array([true, true, false, false]);
+    /// assert_eq!(mask.and_bitmask([0b1010]).to_array(), [false, true, false, false]);
+    /// ```
+    #[inline]
+    pub fn and_bitmask(self, bitmask: <LaneCount<LANES> as SupportedLaneCount>::BitMask) -> Self {
+        self & Self::from_bitmask(bitmask)
+    }
 
-define_opaque_mask! {
-    /// Mask for vectors with `LANES` 32-bit elements.
+    /// Computes the lanewise OR of the mask and a bitmask, with one bit per lane.
     ///
-    /// The layout of this type is unspecified.
-    struct Mask32<const LANES: usize>(mask_impl::Mask32<Self, LANES>);
-    @bits SimdI32
-}
+    /// ```
+    /// # use core_simd::*;
+    /// let mask = Mask32::<4>::from_array([true, true, false, false]);
+    /// assert_eq!(mask.or_bitmask([0b1010]).to_array(), [true, true, false, true]);
+    /// ```
+    #[inline]
+    pub fn or_bitmask(self, bitmask: <LaneCount<LANES> as SupportedLaneCount>::BitMask) -> Self {
+        self | Self::from_bitmask(bitmask)
+    }
 
-define_opaque_mask! {
-    /// Mask for vectors with `LANES` 64-bit elements.
+    /// Computes the lanewise XOR of the mask and a bitmask, with one bit per lane.
     ///
-    /// The layout of this type is unspecified.
-    struct Mask64<const LANES: usize>(mask_impl::Mask64<Self, LANES>);
-    @bits SimdI64
+    /// ```
+    /// # use core_simd::*;
+    /// let mask = Mask32::<4>::from_array([true, true, false, false]);
+    /// assert_eq!(mask.xor_bitmask([0b1010]).to_array(), [true, false, false, true]);
+    /// ```
+    #[inline]
+    pub fn xor_bitmask(self, bitmask: <LaneCount<LANES> as SupportedLaneCount>::BitMask) -> Self {
+        self ^ Self::from_bitmask(bitmask)
+    }
+
+    /// Test if each lane is equal to the corresponding lane in `other`.
+    #[inline]
+    pub fn lanes_eq(self, other: Self) -> Self {
+        !(self ^ other)
+    }
+
+    /// Test if each lane is not equal to the corresponding lane in `other`.
+    #[inline]
+    pub fn lanes_ne(self, other: Self) -> Self {
+        self ^ other
+    }
 }
 
-define_opaque_mask! {
-    /// Mask for vectors with `LANES` pointer-width elements.
-    ///
-    /// The layout of this type is unspecified.
-    struct MaskSize<const LANES: usize>(mask_impl::MaskSize<Self, LANES>);
-    @bits SimdIsize
+// vector/array conversion
+impl<T, const LANES: usize> From<[bool; LANES]> for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn from(array: [bool; LANES]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+impl<T, const LANES: usize> From<Mask<T, LANES>> for [bool; LANES]
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn from(vector: Mask<T, LANES>) -> Self {
+        vector.to_array()
+    }
 }
 
+impl<T, const LANES: usize> Copy for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+impl<T, const LANES: usize> Clone for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const LANES: usize> Default for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::splat(false)
+    }
+}
+
+impl<T, const LANES: usize> PartialEq for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T, const LANES: usize> Eq for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+/// Masks are ordered lexicographically by lane, starting at lane 0, with `false < true`.
+///
+/// This is the ordering of [`to_array`](Self::to_array), and doesn't depend on the
+/// underlying representation of the mask.
+impl<T, const LANES: usize> PartialOrd for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Masks are ordered lexicographically by lane, starting at lane 0, with `false < true`.
+///
+/// This is the ordering of [`to_array`](Self::to_array), and doesn't depend on the
+/// underlying representation of the mask.
+impl<T, const LANES: usize> Ord for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.to_array().cmp(&other.to_array())
+    }
+}
+
+impl<T, const LANES: usize> core::fmt::Debug for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_list()
+            .entries((0..LANES).map(|lane| self.test(lane)))
+            .finish()
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitAnd for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitAnd<bool> for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: bool) -> Self {
+        self & Self::splat(rhs)
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitAnd<Mask<T, LANES>> for bool
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Mask<T, LANES>;
+    #[inline]
+    fn bitand(self, rhs: Mask<T, LANES>) -> Mask<T, LANES> {
+        Mask::splat(self) & rhs
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitOr for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitOr<bool> for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: bool) -> Self {
+        self | Self::splat(rhs)
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitOr<Mask<T, LANES>> for bool
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Mask<T, LANES>;
+    #[inline]
+    fn bitor(self, rhs: Mask<T, LANES>) -> Mask<T, LANES> {
+        Mask::splat(self) | rhs
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitXor for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitXor<bool> for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: bool) -> Self::Output {
+        self ^ Self::splat(rhs)
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitXor<Mask<T, LANES>> for bool
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Mask<T, LANES>;
+    #[inline]
+    fn bitxor(self, rhs: Mask<T, LANES>) -> Self::Output {
+        Mask::splat(self) ^ rhs
+    }
+}
+
+impl<T, const LANES: usize> core::ops::Not for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Mask<T, LANES>;
+    #[inline]
+    fn not(self) -> Self::Output {
+        Self(!self.0)
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitAndAssign for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 = self.0 & rhs.0;
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitAndAssign<bool> for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn bitand_assign(&mut self, rhs: bool) {
+        *self &= Self::splat(rhs);
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitOrAssign for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 = self.0 | rhs.0;
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitOrAssign<bool> for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn bitor_assign(&mut self, rhs: bool) {
+        *self |= Self::splat(rhs);
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitXorAssign for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.0 = self.0 ^ rhs.0;
+    }
+}
+
+impl<T, const LANES: usize> core::ops::BitXorAssign<bool> for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: bool) {
+        *self ^= Self::splat(rhs);
+    }
+}
+
+impl_mask_ref_ops! { BitAnd::bitand, BitAndAssign::bitand_assign }
+impl_mask_ref_ops! { BitOr::bitor, BitOrAssign::bitor_assign }
+impl_mask_ref_ops! { BitXor::bitxor, BitXorAssign::bitxor_assign }
+
+impl<T, const LANES: usize> core::ops::Not for &'_ Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Mask<T, LANES>;
+    #[inline]
+    fn not(self) -> Self::Output {
+        !*self
+    }
+}
+
+/// Mask for vectors with `LANES` 8-bit elements.
+pub type Mask8<const LANES: usize> = Mask<i8, LANES>;
+
+/// Mask for vectors with `LANES` 16-bit elements.
+pub type Mask16<const LANES: usize> = Mask<i16, LANES>;
+
+/// Mask for vectors with `LANES` 32-bit elements.
+pub type Mask32<const LANES: usize> = Mask<i32, LANES>;
+
+/// Mask for vectors with `LANES` 64-bit elements.
+pub type Mask64<const LANES: usize> = Mask<i64, LANES>;
+
+/// Mask for vectors with `LANES` pointer-width elements.
+pub type MaskSize<const LANES: usize> = Mask<isize, LANES>;
+
 /// Vector of eight 8-bit masks
 pub type mask8x8 = Mask8<8>;
 
@@ -505,7 +967,7 @@ pub type mask8x16 = Mask8<16>;
 /// Vector of 32 8-bit masks
 pub type mask8x32 = Mask8<32>;
 
-/// Vector of 16 8-bit masks
+/// Vector of 64 8-bit masks
 pub type mask8x64 = Mask8<64>;
 
 /// Vector of four 16-bit masks
@@ -518,7 +980,7 @@ pub type mask16x8 = Mask16<8>;
 pub type mask16x16 = Mask16<16>;
 
 /// Vector of 32 16-bit masks
-pub type mask16x32 = Mask32<32>;
+pub type mask16x32 = Mask16<32>;
 
 /// Vector of two 32-bit masks
 pub type mask32x2 = Mask32<2>;
@@ -550,25 +1012,81 @@ pub type masksizex4 = MaskSize<4>;
 /// Vector of eight pointer-width masks
 pub type masksizex8 = MaskSize<8>;
 
+from_mask_x86! { mask8x16 => __m128i }
+from_mask_x86! { mask8x32 => __m256i }
+from_mask_x86! { mask16x8 => __m128i }
+from_mask_x86! { mask16x16 => __m256i }
+from_mask_x86! { mask32x4 => __m128i }
+from_mask_x86! { mask32x8 => __m256i }
+from_mask_x86! { mask64x2 => __m128i }
+from_mask_x86! { mask64x4 => __m256i }
+from_mask_x86! { mask8x64 => __m512i }
+from_mask_x86! { mask16x32 => __m512i }
+from_mask_x86! { mask32x16 => __m512i }
+from_mask_x86! { mask64x8 => __m512i }
+
+#[cfg(target_pointer_width = "32")]
+from_mask_x86! { masksizex4 => __m128i }
+#[cfg(target_pointer_width = "32")]
+from_mask_x86! { masksizex8 => __m256i }
+#[cfg(target_pointer_width = "32")]
+from_mask_x86! { MaskSize<16> => __m512i }
+
+#[cfg(target_pointer_width = "64")]
+from_mask_x86! { masksizex2 => __m128i }
+#[cfg(target_pointer_width = "64")]
+from_mask_x86! { masksizex4 => __m256i }
+#[cfg(target_pointer_width = "64")]
+from_mask_x86! { masksizex8 => __m512i }
+
+/// Implements conversions between masks with `$lanes` lanes and the AVX-512 mask register type
+/// `$int` (`__mmask8`, `__mmask16`, `__mmask32`, or `__mmask64`), with one bit set per lane.
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "avx512f"))]
+macro_rules! impl_mmask {
+    { $int:ty, $lanes:literal => $($mask:ident),* } => {
+        $(
+        impl From<$mask<$lanes>> for $int {
+            #[inline]
+            fn from(mask: $mask<$lanes>) -> Self {
+                <$int>::from_le_bytes(mask.to_bitmask())
+            }
+        }
+
+        impl From<$int> for $mask<$lanes> {
+            #[inline]
+            fn from(bitmask: $int) -> Self {
+                Self::from_bitmask(bitmask.to_le_bytes())
+            }
+        }
+        )*
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "avx512f"))]
+impl_mmask! { u8, 8 => Mask8, Mask16, Mask32, Mask64, MaskSize }
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "avx512f"))]
+impl_mmask! { u16, 16 => Mask8, Mask16, Mask32, Mask64, MaskSize }
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "avx512f"))]
+impl_mmask! { u32, 32 => Mask8, Mask16, Mask32, Mask64, MaskSize }
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "avx512f"))]
+impl_mmask! { u64, 64 => Mask8, Mask16, Mask32, Mask64, MaskSize }
+
 macro_rules! impl_from {
-    { $from:ident ($from_inner:ident) => $($to:ident ($to_inner:ident)),* } => {
+    { $from:ty => $($to:ty),* } => {
         $(
-        impl<const LANES: usize> From<$from<LANES>> for $to<LANES>
+        impl<const LANES: usize> From<Mask<$from, LANES>> for Mask<$to, LANES>
         where
-            crate::$from_inner<LANES>: crate::LanesAtMost32,
-            crate::$to_inner<LANES>: crate::LanesAtMost32,
-            $from<LANES>: Mask,
-            Self: Mask,
+            LaneCount<LANES>: SupportedLaneCount,
         {
-            fn from(value: $from<LANES>) -> Self {
+            fn from(value: Mask<$from, LANES>) -> Self {
                 Self(value.0.into())
             }
         }
         )*
     }
 }
-impl_from! { Mask8 (SimdI8) => Mask16 (SimdI16), Mask32 (SimdI32), Mask64 (SimdI64), MaskSize (SimdIsize) }
-impl_from! { Mask16 (SimdI16) => Mask32 (SimdI32), Mask64 (SimdI64), MaskSize (SimdIsize), Mask8 (SimdI8) }
-impl_from! { Mask32 (SimdI32) => Mask64 (SimdI64), MaskSize (SimdIsize), Mask8 (SimdI8), Mask16 (SimdI16) }
-impl_from! { Mask64 (SimdI64) => MaskSize (SimdIsize), Mask8 (SimdI8), Mask16 (SimdI16), Mask32 (SimdI32) }
-impl_from! { MaskSize (SimdIsize) => Mask8 (SimdI8), Mask16 (SimdI16), Mask32 (SimdI32), Mask64 (SimdI64) }
+impl_from! { i8 => i16, i32, i64, isize }
+impl_from! { i16 => i32, i64, isize, i8 }
+impl_from! { i32 => i64, isize, i8, i16 }
+impl_from! { i64 => isize, i8, i16, i32 }
+impl_from! { isize => i8, i16, i32, i64 }
@@ -0,0 +1,390 @@
+//! Masks with an explicitly chosen representation.
+//!
+//! The opaque mask types use whichever representation is best for the target.  These wrappers
+//! fix the representation instead, for algorithms known to favor one over the other.
+
+use super::{Mask16, Mask32, Mask64, Mask8, MaskSize, ToBitMask};
+use crate::{LaneCount, SimdI16, SimdI32, SimdI64, SimdI8, SimdIsize, SupportedLaneCount};
+
+/// Helper trait relating an opaque mask to its integer vector representation.
+pub trait MaskRepr: ToBitMask {
+    /// The integer vector with a lane of `0` or `-1` for each lane of the mask.
+    type Int: Copy;
+}
+
+/// A mask stored as an integer vector, where each lane is either `0` (false) or `-1` (true).
+///
+/// This is the representation used by most targets, and is best for masks that are mostly
+/// used to select lanes from vectors.  `M` is the opaque mask type, such as `Mask32<4>`.
+///
+/// ```
+/// # use core_simd::*;
+/// let mask = VectorMask::from(Mask32::from_array([true, false, false, true]));
+/// assert_eq!(mask.to_int(), SimdI32::from_array([-1, 0, 0, -1]));
+/// assert_eq!(Mask32::from(!mask), Mask32::from_array([false, true, true, false]));
+/// ```
+#[repr(transparent)]
+pub struct VectorMask<M: MaskRepr>(M::Int);
+
+/// A mask stored with one bit per lane, where bit 0 corresponds to lane 0.
+///
+/// This is the representation used by targets with dedicated mask registers (such as AVX-512),
+/// and is best for masks that are mostly combined, counted, or inspected.  `M` is the opaque mask
+/// type, such as `Mask32<4>`.
+///
+/// ```
+/// # use core_simd::*;
+/// let mask = BitMask::from(Mask32::from_array([true, false, false, true]));
+/// assert_eq!(mask.to_bitmask(), [0b1001]);
+/// assert_eq!(Mask32::from(!mask), Mask32::from_array([false, true, true, false]));
+/// ```
+#[repr(transparent)]
+pub struct BitMask<M: ToBitMask>(M::BitMask);
+
+impl<M: MaskRepr> Copy for VectorMask<M> {}
+
+impl<M: MaskRepr> Clone for VectorMask<M> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M: ToBitMask> Copy for BitMask<M> {}
+
+impl<M: ToBitMask> Clone for BitMask<M> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M: ToBitMask> PartialEq for BitMask<M> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_ref() == other.0.as_ref()
+    }
+}
+
+impl<M: ToBitMask> Eq for BitMask<M> {}
+
+impl<M: ToBitMask> BitMask<M> {
+    /// Construct a mask by setting all lanes to the given value.
+    #[inline]
+    pub const fn splat(value: bool) -> Self {
+        if value {
+            Self(M::ALL_BITMASK)
+        } else {
+            Self(M::NONE_BITMASK)
+        }
+    }
+
+    /// Construct a mask from a bitmask, with one bit per lane.
+    ///
+    /// Bits beyond the last lane are ignored.
+    #[inline]
+    pub fn from_bitmask(mut bitmask: M::BitMask) -> Self {
+        for (x, all) in bitmask.as_mut().iter_mut().zip(M::ALL_BITMASK.as_ref()) {
+            *x &= all;
+        }
+        Self(bitmask)
+    }
+
+    /// Converts the mask to a bitmask, with one bit per lane.
+    #[inline]
+    pub fn to_bitmask(self) -> M::BitMask {
+        self.0
+    }
+
+    /// Tests the value of the specified lane.
+    ///
+    /// # Panics
+    /// Panics if `lane` is greater than or equal to the number of lanes in the mask.
+    #[inline]
+    pub fn test(&self, lane: usize) -> bool {
+        assert!(
+            (M::ALL_BITMASK.as_ref()[lane / 8] >> (lane % 8)) & 1 == 1,
+            "lane index out of range"
+        );
+        (self.0.as_ref()[lane / 8] >> (lane % 8)) & 1 == 1
+    }
+
+    /// Sets the value of the specified lane.
+    ///
+    /// # Panics
+    /// Panics if `lane` is greater than or equal to the number of lanes in the mask.
+    #[inline]
+    pub fn set(&mut self, lane: usize, value: bool) {
+        let old = self.test(lane);
+        self.0.as_mut()[lane / 8] ^= ((old ^ value) as u8) << (lane % 8);
+    }
+
+    /// Returns true if any lane is set.
+    #[inline]
+    pub fn any(self) -> bool {
+        self.0.as_ref().iter().any(|x| *x != 0)
+    }
+
+    /// Returns true if all lanes are set.
+    #[inline]
+    pub fn all(self) -> bool {
+        self.0.as_ref() == M::ALL_BITMASK.as_ref()
+    }
+}
+
+impl<M: ToBitMask> core::fmt::Debug for BitMask<M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let lanes = M::ALL_BITMASK.as_ref().iter().map(|x| x.count_ones() as usize).sum();
+        f.debug_list()
+            .entries((0..lanes).map(|lane| self.test(lane)))
+            .finish()
+    }
+}
+
+impl<M: ToBitMask> Default for BitMask<M> {
+    #[inline]
+    fn default() -> Self {
+        Self::splat(false)
+    }
+}
+
+macro_rules! impl_bitmask_op {
+    { $trait:ident :: $fn:ident, $assign_trait:ident :: $assign_fn:ident, $assign_op:tt } => {
+        impl<M: ToBitMask> core::ops::$trait for BitMask<M> {
+            type Output = Self;
+            #[inline]
+            fn $fn(mut self, rhs: Self) -> Self {
+                for (l, r) in self.0.as_mut().iter_mut().zip(rhs.0.as_ref()) {
+                    *l $assign_op *r;
+                }
+                self
+            }
+        }
+
+        impl<M: ToBitMask> core::ops::$assign_trait for BitMask<M> {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = core::ops::$trait::$fn(*self, rhs);
+            }
+        }
+    }
+}
+
+impl_bitmask_op! { BitAnd::bitand, BitAndAssign::bitand_assign, &= }
+impl_bitmask_op! { BitOr::bitor, BitOrAssign::bitor_assign, |= }
+impl_bitmask_op! { BitXor::bitxor, BitXorAssign::bitxor_assign, ^= }
+
+impl<M: ToBitMask> core::ops::Not for BitMask<M> {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        self ^ Self::splat(true)
+    }
+}
+
+macro_rules! impl_mask_repr {
+    { $($mask:ident ($int:ident)),* } => {
+        $(
+        impl<const LANES: usize> MaskRepr for $mask<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Int = $int<LANES>;
+        }
+
+        impl<const LANES: usize> VectorMask<$mask<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Construct a mask by setting all lanes to the given value.
+            #[inline]
+            pub fn splat(value: bool) -> Self {
+                Self($int::splat(if value { -1 } else { 0 }))
+            }
+
+            /// Converts a vector of integers to a mask, where 0 represents `false` and -1
+            /// represents `true`.
+            ///
+            /// # Safety
+            /// All lanes must be either 0 or -1.
+            #[inline]
+            pub unsafe fn from_int_unchecked(value: $int<LANES>) -> Self {
+                Self(value)
+            }
+
+            /// Converts the mask to a vector of integers, where 0 represents `false` and -1
+            /// represents `true`.
+            #[inline]
+            pub fn to_int(self) -> $int<LANES> {
+                self.0
+            }
+
+            /// Tests the value of the specified lane.
+            ///
+            /// # Panics
+            /// Panics if `lane` is greater than or equal to the number of lanes in the mask.
+            #[inline]
+            pub fn test(&self, lane: usize) -> bool {
+                self.0[lane] != 0
+            }
+
+            /// Sets the value of the specified lane.
+            ///
+            /// # Panics
+            /// Panics if `lane` is greater than or equal to the number of lanes in the mask.
+            #[inline]
+            pub fn set(&mut self, lane: usize, value: bool) {
+                self.0[lane] = if value { -1 } else { 0 };
+            }
+
+            /// Returns true if any lane is set.
+            #[inline]
+            pub fn any(self) -> bool {
+                self.0.horizontal_or() != 0
+            }
+
+            /// Returns true if all lanes are set.
+            #[inline]
+            pub fn all(self) -> bool {
+                self.0.horizontal_and() != 0
+            }
+        }
+
+        impl<const LANES: usize> PartialEq for VectorMask<$mask<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<const LANES: usize> Eq for VectorMask<$mask<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {}
+
+        impl<const LANES: usize> Default for VectorMask<$mask<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn default() -> Self {
+                Self::splat(false)
+            }
+        }
+
+        impl<const LANES: usize> core::fmt::Debug for VectorMask<$mask<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.debug_list()
+                    .entries((0..LANES).map(|lane| self.test(lane)))
+                    .finish()
+            }
+        }
+
+        impl_mask_repr! { @op $mask, $int, BitAnd::bitand, BitAndAssign::bitand_assign }
+        impl_mask_repr! { @op $mask, $int, BitOr::bitor, BitOrAssign::bitor_assign }
+        impl_mask_repr! { @op $mask, $int, BitXor::bitxor, BitXorAssign::bitxor_assign }
+
+        impl<const LANES: usize> core::ops::Not for VectorMask<$mask<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+            #[inline]
+            fn not(self) -> Self {
+                Self(!self.0)
+            }
+        }
+
+        impl<const LANES: usize> From<$mask<LANES>> for VectorMask<$mask<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn from(mask: $mask<LANES>) -> Self {
+                Self(mask.to_int())
+            }
+        }
+
+        impl<const LANES: usize> From<VectorMask<$mask<LANES>>> for $mask<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn from(mask: VectorMask<$mask<LANES>>) -> Self {
+                unsafe { Self::from_int_unchecked(mask.0) }
+            }
+        }
+
+        impl<const LANES: usize> From<$mask<LANES>> for BitMask<$mask<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn from(mask: $mask<LANES>) -> Self {
+                Self(mask.to_bitmask())
+            }
+        }
+
+        impl<const LANES: usize> From<BitMask<$mask<LANES>>> for $mask<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn from(mask: BitMask<$mask<LANES>>) -> Self {
+                Self::from_bitmask(mask.0)
+            }
+        }
+
+        impl<const LANES: usize> From<VectorMask<$mask<LANES>>> for BitMask<$mask<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn from(mask: VectorMask<$mask<LANES>>) -> Self {
+                $mask::from(mask).into()
+            }
+        }
+
+        impl<const LANES: usize> From<BitMask<$mask<LANES>>> for VectorMask<$mask<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn from(mask: BitMask<$mask<LANES>>) -> Self {
+                $mask::from(mask).into()
+            }
+        }
+        )*
+    };
+
+    { @op $mask:ident, $int:ident, $trait:ident :: $fn:ident, $assign_trait:ident :: $assign_fn:ident } => {
+        impl<const LANES: usize> core::ops::$trait for VectorMask<$mask<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self {
+                Self(core::ops::$trait::$fn(self.0, rhs.0))
+            }
+        }
+
+        impl<const LANES: usize> core::ops::$assign_trait for VectorMask<$mask<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = core::ops::$trait::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_mask_repr! { Mask8 (SimdI8), Mask16 (SimdI16), Mask32 (SimdI32), Mask64 (SimdI64), MaskSize (SimdIsize) }
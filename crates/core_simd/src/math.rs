@@ -1,6 +1,6 @@
 macro_rules! impl_uint_arith {
     ($(($name:ident, $n:ident)),+) => {
-        $( impl<const LANES: usize> $name<LANES> where Self: crate::LanesAtMost32 {
+        $( impl<const LANES: usize> $name<LANES> where crate::LaneCount<LANES>: crate::SupportedLaneCount {
 
             /// Lanewise saturating add.
             ///
@@ -36,13 +36,91 @@ macro_rules! impl_uint_arith {
             pub fn saturating_sub(self, second: Self) -> Self {
                 unsafe { crate::intrinsics::simd_saturating_sub(self, second) }
             }
+
+            /// Lanewise wrapping division.
+            /// For unsigned integers this is the same as `/`, which never overflows.
+            ///
+            /// # Panics
+            /// Panics if any lane of `rhs` is zero.
+            #[inline]
+            pub fn wrapping_div(self, rhs: Self) -> Self {
+                self / rhs
+            }
+
+            /// Lanewise wrapping remainder.
+            /// For unsigned integers this is the same as `%`, which never overflows.
+            ///
+            /// # Panics
+            /// Panics if any lane of `rhs` is zero.
+            #[inline]
+            pub fn wrapping_rem(self, rhs: Self) -> Self {
+                self % rhs
+            }
+
+            /// Lanewise funnel shift left.
+            ///
+            /// Concatenates each lane of `self` (high half) with the corresponding lane of `low`
+            /// (low half), shifts the double-width value left by `shift`, and returns the high
+            /// half.  The shift amount is taken modulo the number of bits in a lane, so this never
+            /// overflows.  This is equivalent to LLVM's `fshl` intrinsic, and passing `self` as
+            /// `low` is a rotate left.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let high = ", stringify!($name), "::from_array([1, 1, 1, 1]);")]
+            #[doc = concat!("let low = ", stringify!($name), "::splat(MAX);")]
+            #[doc = concat!("let shift = ", stringify!($name), "::from_array([0, 1, 2, ", stringify!($n), "::BITS as ", stringify!($n), " + 1]);")]
+            #[doc = concat!("assert_eq!(high.funnel_shl(low, shift), ", stringify!($name), "::from_array([1, 3, 7, 3]));")]
+            /// ```
+            #[inline]
+            pub fn funnel_shl(self, low: Self, shift: Self) -> Self {
+                let bits = Self::splat(<$n>::BITS as $n - 1);
+                let shift = shift & bits;
+                // shift `low` in two steps so that a shift of zero never shifts by the full width
+                unsafe {
+                    let high = crate::intrinsics::simd_shl(self, shift);
+                    let low = crate::intrinsics::simd_shr(low >> 1, bits - shift);
+                    high | low
+                }
+            }
+
+            /// Lanewise funnel shift right.
+            ///
+            /// Concatenates each lane of `self` (high half) with the corresponding lane of `low`
+            /// (low half), shifts the double-width value right by `shift`, and returns the low
+            /// half.  The shift amount is taken modulo the number of bits in a lane, so this never
+            /// overflows.  This is equivalent to LLVM's `fshr` intrinsic, and passing `self` as
+            /// `low` is a rotate right.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let high = ", stringify!($name), "::splat(MAX);")]
+            #[doc = concat!("let low = ", stringify!($name), "::from_array([0, 0, 0, 0]);")]
+            #[doc = concat!("let shift = ", stringify!($name), "::from_array([0, 1, 2, ", stringify!($n), "::BITS as ", stringify!($n), " + 1]);")]
+            #[doc = concat!("assert_eq!(high.funnel_shr(low, shift), ", stringify!($name), "::from_array([0, 1 << (", stringify!($n), "::BITS - 1), 3 << (", stringify!($n), "::BITS - 2), 1 << (", stringify!($n), "::BITS - 1)]));")]
+            /// ```
+            #[inline]
+            pub fn funnel_shr(self, low: Self, shift: Self) -> Self {
+                let bits = Self::splat(<$n>::BITS as $n - 1);
+                let shift = shift & bits;
+                // shift `self` in two steps so that a shift of zero never shifts by the full width
+                unsafe {
+                    let high = crate::intrinsics::simd_shl(self << 1, bits - shift);
+                    let low = crate::intrinsics::simd_shr(low, shift);
+                    high | low
+                }
+            }
         })+
     }
 }
 
 macro_rules! impl_int_arith {
-    ($(($name:ident, $n:ident)),+) => {
-        $( impl<const LANES: usize> $name<LANES> where Self: crate::LanesAtMost32 {
+    ($(($name:ident, $n:ident, $mask:ident)),+) => {
+        $( impl<const LANES: usize> $name<LANES> where crate::LaneCount<LANES>: crate::SupportedLaneCount {
 
             /// Lanewise saturating add.
             ///
@@ -134,6 +212,433 @@ macro_rules! impl_int_arith {
             pub fn saturating_neg(self) -> Self {
                 Self::splat(0).saturating_sub(self)
             }
+
+            /// Lanewise wrapping negation.
+            /// As neg(), the MIN value wraps around to itself.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([MIN, -2, 3, MAX]);")]
+            #[doc = concat!("assert_eq!(x.wrapping_neg(), ", stringify!($name), "::from_array([MIN, 2, -3, MIN + 1]));")]
+            /// ```
+            #[inline]
+            pub fn wrapping_neg(self) -> Self {
+                Self::splat(0) - self
+            }
+
+            /// Lanewise overflowing negation.
+            /// Returns the wrapping negation along with a mask of the lanes that overflowed,
+            /// which are the lanes equal to MIN.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([MIN, -2, 3, MAX]);")]
+            /// let (neg, overflowed) = x.overflowing_neg();
+            #[doc = concat!("assert_eq!(neg, ", stringify!($name), "::from_array([MIN, 2, -3, MIN + 1]));")]
+            /// assert_eq!(overflowed.to_array(), [true, false, false, false]);
+            /// ```
+            #[inline]
+            pub fn overflowing_neg(self) -> (Self, crate::$mask<LANES>) {
+                (self.wrapping_neg(), self.lanes_eq(Self::splat(<$n>::MIN)))
+            }
+
+            /// Lanewise wrapping division.
+            /// Unlike `/`, dividing MIN by -1 wraps around to MIN instead of panicking.
+            ///
+            /// # Panics
+            /// Panics if any lane of `rhs` is zero.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([MIN, MIN, 7, MAX]);")]
+            #[doc = concat!("let y = ", stringify!($name), "::from_array([-1, 2, -2, -1]);")]
+            #[doc = concat!("assert_eq!(x.wrapping_div(y), ", stringify!($name), "::from_array([MIN, MIN / 2, -3, -MAX]));")]
+            /// ```
+            #[inline]
+            pub fn wrapping_div(self, rhs: Self) -> Self {
+                self / self.overflow_safe_divisor(rhs)
+            }
+
+            /// Lanewise wrapping remainder.
+            /// Unlike `%`, the remainder of MIN divided by -1 is 0 instead of panicking.
+            ///
+            /// # Panics
+            /// Panics if any lane of `rhs` is zero.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([MIN, MIN, 7, MAX]);")]
+            #[doc = concat!("let y = ", stringify!($name), "::from_array([-1, 3, -2, -1]);")]
+            #[doc = concat!("assert_eq!(x.wrapping_rem(y), ", stringify!($name), "::from_array([0, MIN % 3, 1, 0]));")]
+            /// ```
+            #[inline]
+            pub fn wrapping_rem(self, rhs: Self) -> Self {
+                self % self.overflow_safe_divisor(rhs)
+            }
+
+            /// Replaces the divisor with 1 in lanes dividing MIN by -1, which gives the wrapping
+            /// result for both division and remainder.
+            #[inline]
+            fn overflow_safe_divisor(self, rhs: Self) -> Self {
+                let overflow = self.lanes_eq(Self::splat(<$n>::MIN)) & rhs.lanes_eq(Self::splat(-1));
+                overflow.select(Self::splat(1), rhs)
+            }
+
+            /// Lanewise checked negation.
+            /// Returns `None` if any lane is MIN, and would overflow.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([-2, 3, MAX, 0]);")]
+            #[doc = concat!("assert_eq!(x.checked_neg(), Some(", stringify!($name), "::from_array([2, -3, MIN + 1, 0])));")]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::from_array([MIN, 0, 0, 0]).checked_neg(), None);")]
+            /// ```
+            #[inline]
+            pub fn checked_neg(self) -> Option<Self> {
+                let (neg, overflowed) = self.overflowing_neg();
+                if overflowed.any() {
+                    None
+                } else {
+                    Some(neg)
+                }
+            }
+        })+
+    }
+}
+
+macro_rules! impl_integer_pow {
+    ($(($name:ident, $n:ident, $mask:ident, $int:ident)),+) => {
+        $( impl<const LANES: usize> $name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            /// Lanewise wrapping exponentiation.
+            /// Raises each lane to the power of `exp`, wrapping around on overflow.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 1, 2, 3]);")]
+            #[doc = concat!("assert_eq!(x.wrapping_pow(3), ", stringify!($name), "::from_array([0, 1, 8, 27]));")]
+            #[doc = concat!("assert_eq!(x.wrapping_pow(0), ", stringify!($name), "::splat(1));")]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::<4>::splat(2).wrapping_pow(", stringify!($n), "::BITS), ", stringify!($name), "::splat(0));")]
+            /// ```
+            #[inline]
+            pub fn wrapping_pow(self, exp: u32) -> Self {
+                self.pow_with(exp, |a, b| (a * b, crate::$mask::splat(false))).0
+            }
+
+            /// Lanewise checked exponentiation.
+            /// Returns `None` if raising any lane to the power of `exp` would overflow.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 1, 2, 3]);")]
+            #[doc = concat!("assert_eq!(x.checked_pow(3), Some(", stringify!($name), "::from_array([0, 1, 8, 27])));")]
+            #[doc = concat!("assert_eq!(x.checked_pow(", stringify!($n), "::BITS), None);")]
+            /// ```
+            #[inline]
+            pub fn checked_pow(self, exp: u32) -> Option<Self> {
+                let (pow, overflowed) = self.pow_with(exp, Self::overflowing_mul_lanes);
+                if overflowed.any() {
+                    None
+                } else {
+                    Some(pow)
+                }
+            }
+
+            /// Exponentiation by squaring, using `mul` to multiply and detect overflow.
+            #[inline]
+            fn pow_with(
+                self,
+                mut exp: u32,
+                mul: impl Fn(Self, Self) -> (Self, crate::$mask<LANES>),
+            ) -> (Self, crate::$mask<LANES>) {
+                if exp == 0 {
+                    return (Self::splat(1), crate::$mask::splat(false));
+                }
+                let mut base = self;
+                let mut acc = Self::splat(1);
+                let mut overflowed = crate::$mask::splat(false);
+                while exp > 1 {
+                    if exp & 1 == 1 {
+                        let (product, overflow) = mul(acc, base);
+                        acc = product;
+                        overflowed |= overflow;
+                    }
+                    exp /= 2;
+                    let (square, overflow) = mul(base, base);
+                    base = square;
+                    overflowed |= overflow;
+                }
+                // the final multiplication is separate to avoid squaring `base` past the last bit
+                let (product, overflow) = mul(acc, base);
+                (product, overflowed | overflow)
+            }
+
+            /// Wrapping multiplication, along with a mask of the lanes that overflowed.
+            #[inline]
+            fn overflowing_mul_lanes(self, rhs: Self) -> (Self, crate::$mask<LANES>) {
+                let product = self * rhs;
+                let nonzero = rhs.lanes_ne(Self::splat(0));
+                let divisor = nonzero.select(rhs, Self::splat(1));
+                let mut overflow = nonzero & product.wrapping_div(divisor).lanes_ne(self);
+                if <$n>::MIN != 0 {
+                    // MIN * -1 wraps back to MIN, which the division doesn't catch
+                    let minus_one = Self::splat(<$n>::MIN.wrapping_add(<$n>::MAX));
+                    overflow |= self.lanes_eq(Self::splat(<$n>::MIN)) & rhs.lanes_eq(minus_one);
+                }
+                (product, overflow)
+            }
+        })+
+    }
+}
+
+macro_rules! impl_uint_log {
+    ($(($name:ident, $n:ident, $mask:ident, $int:ident)),+) => {
+        $( impl<const LANES: usize> $name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            /// Returns the number of leading zeros in the binary representation of each lane.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 1, 6, MAX]);")]
+            #[doc = concat!("let bits = ", stringify!($n), "::BITS as ", stringify!($n), ";")]
+            #[doc = concat!("assert_eq!(x.leading_zeros(), ", stringify!($name), "::from_array([bits, bits - 1, bits - 3, 0]));")]
+            /// ```
+            #[inline]
+            pub fn leading_zeros(self) -> Self {
+                // binary search for the highest set bit, shifting it to the top of the lane
+                let mut x = self;
+                let mut count = Self::splat(0);
+                let mut shift = <$n>::BITS / 2;
+                while shift > 0 {
+                    let top_empty = (x >> (<$n>::BITS - shift) as $n).lanes_eq(Self::splat(0));
+                    count += top_empty.select(Self::splat(shift as $n), Self::splat(0));
+                    x = top_empty.select(x << shift as $n, x);
+                    shift /= 2;
+                }
+                count + x.lanes_eq(Self::splat(0)).select(Self::splat(1), Self::splat(0))
+            }
+
+            /// Returns the base 2 logarithm of each lane, rounded down.
+            ///
+            /// # Panics
+            /// Panics if any lane is zero.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([1, 2, 7, MAX]);")]
+            #[doc = concat!("let bits = ", stringify!($n), "::BITS as ", stringify!($n), ";")]
+            #[doc = concat!("assert_eq!(x.ilog2(), ", stringify!($name), "::from_array([0, 1, 2, bits - 1]));")]
+            /// ```
+            #[inline]
+            #[track_caller]
+            pub fn ilog2(self) -> Self {
+                let (log, zero) = self.checked_ilog2();
+                assert!(!zero.any(), "argument of integer logarithm must be positive");
+                log
+            }
+
+            /// Returns the base 2 logarithm of each lane, rounded down, along with a mask of the
+            /// lanes that are zero.  The logarithm of a zero lane is 0.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([1, 0, 7, 8]);")]
+            /// let (log, zero) = x.checked_ilog2();
+            #[doc = concat!("assert_eq!(log, ", stringify!($name), "::from_array([0, 0, 2, 3]));")]
+            /// assert_eq!(zero.to_array(), [false, true, false, false]);
+            /// ```
+            #[inline]
+            pub fn checked_ilog2(self) -> (Self, crate::$mask<LANES>) {
+                let zero = self.lanes_eq(Self::splat(0));
+                let log = Self::splat(<$n>::BITS as $n - 1) - self.leading_zeros();
+                (zero.select(Self::splat(0), log), zero)
+            }
+
+            /// Returns the base 10 logarithm of each lane, rounded down.
+            ///
+            /// # Panics
+            /// Panics if any lane is zero.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([1, 9, 10, 99]);")]
+            #[doc = concat!("assert_eq!(x.ilog10(), ", stringify!($name), "::from_array([0, 0, 1, 1]));")]
+            /// ```
+            #[inline]
+            #[track_caller]
+            pub fn ilog10(self) -> Self {
+                let (log, zero) = self.checked_ilog10();
+                assert!(!zero.any(), "argument of integer logarithm must be positive");
+                log
+            }
+
+            /// Returns the base 10 logarithm of each lane, rounded down, along with a mask of the
+            /// lanes that are zero.  The logarithm of a zero lane is 0.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([1, 0, 10, 99]);")]
+            /// let (log, zero) = x.checked_ilog10();
+            #[doc = concat!("assert_eq!(log, ", stringify!($name), "::from_array([0, 0, 1, 1]));")]
+            /// assert_eq!(zero.to_array(), [false, true, false, false]);
+            /// ```
+            #[inline]
+            pub fn checked_ilog10(self) -> (Self, crate::$mask<LANES>) {
+                // count the powers of ten that each lane reaches, which is 0 for zero lanes
+                let mut log = Self::splat(0);
+                let mut power: $n = 10;
+                loop {
+                    log += self.lanes_ge(Self::splat(power)).select(Self::splat(1), Self::splat(0));
+                    match power.checked_mul(10) {
+                        Some(next) => power = next,
+                        None => break,
+                    }
+                }
+                (log, self.lanes_eq(Self::splat(0)))
+            }
+        })+
+    }
+}
+
+macro_rules! impl_uint_power_of_two {
+    ($(($name:ident, $n:ident, $mask:ident, $int:ident)),+) => {
+        $( impl<const LANES: usize> $name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            /// Returns true for each lane that is a power of two.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 1, 6, 8]);")]
+            /// assert_eq!(x.is_power_of_two().to_array(), [false, true, false, true]);
+            /// ```
+            #[inline]
+            pub fn is_power_of_two(self) -> crate::$mask<LANES> {
+                let zero = Self::splat(0);
+                self.lanes_ne(zero) & (self & (self - Self::splat(1))).lanes_eq(zero)
+            }
+
+            /// Returns the smallest power of two greater than or equal to each lane.
+            ///
+            /// When a lane's result overflows, this panics in debug mode and the lane wraps to 0
+            /// in release mode, like the scalar method.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 1, 6, 8]);")]
+            #[doc = concat!("assert_eq!(x.next_power_of_two(), ", stringify!($name), "::from_array([1, 1, 8, 8]));")]
+            /// ```
+            #[inline]
+            #[track_caller]
+            pub fn next_power_of_two(self) -> Self {
+                debug_assert!(
+                    self.lanes_le(Self::splat(<$n>::MAX / 2 + 1)).all(),
+                    "attempt to add with overflow"
+                );
+                self.wrapping_next_power_of_two()
+            }
+
+            /// Returns the smallest power of two greater than or equal to each lane.
+            /// Returns `None` if any lane's result would overflow.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 1, 6, 8]);")]
+            #[doc = concat!("assert_eq!(x.checked_next_power_of_two(), Some(", stringify!($name), "::from_array([1, 1, 8, 8])));")]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::from_array([0, 1, 6, MAX]).checked_next_power_of_two(), None);")]
+            /// ```
+            #[inline]
+            pub fn checked_next_power_of_two(self) -> Option<Self> {
+                if self.lanes_gt(Self::splat(<$n>::MAX / 2 + 1)).any() {
+                    None
+                } else {
+                    Some(self.wrapping_next_power_of_two())
+                }
+            }
+
+            /// Returns the smallest power of two greater than or equal to each lane.
+            /// Lanes whose result would overflow wrap to 0.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 1, 6, MAX]);")]
+            #[doc = concat!("assert_eq!(x.wrapping_next_power_of_two(), ", stringify!($name), "::from_array([1, 1, 8, 0]));")]
+            /// ```
+            #[inline]
+            pub fn wrapping_next_power_of_two(self) -> Self {
+                // smear the highest set bit of `self - 1` into every lower bit
+                let mut x = self - Self::splat(1);
+                let mut shift = 1;
+                while shift < <$n>::BITS {
+                    x |= x >> shift as $n;
+                    shift *= 2;
+                }
+                let one = Self::splat(1);
+                self.lanes_le(one).select(one, x + one)
+            }
+        })+
+    }
+}
+
+macro_rules! impl_int_sign_bits {
+    ($(($name:ident, $n:ident, $unsigned:ident)),+) => {
+        $( impl<const LANES: usize> $name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            /// Returns the number of bits following the sign bit of each lane that are equal to
+            /// the sign bit, like the ARM `vcls` instruction.
+            ///
+            /// These bits are redundant copies of the sign, so shifting a lane left by this amount
+            /// normalizes it without overflowing.  The result is one less than the bit width for
+            /// lanes equal to 0 or -1.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MIN;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, -1, -2, MIN]);")]
+            #[doc = concat!("let bits = ", stringify!($n), "::BITS as ", stringify!($n), ";")]
+            #[doc = concat!("assert_eq!(x.leading_sign_bits(), ", stringify!($name), "::from_array([bits - 1, bits - 1, bits - 2, 0]));")]
+            /// ```
+            #[inline]
+            pub fn leading_sign_bits(self) -> Self {
+                // invert negative lanes, turning the redundant sign bits into leading zeros
+                let flipped = self ^ (self >> (<$n>::BITS - 1) as $n);
+                let flipped: crate::$unsigned<LANES> = unsafe { crate::intrinsics::simd_cast(flipped) };
+                let zeros: Self = unsafe { crate::intrinsics::simd_cast(flipped.leading_zeros()) };
+                zeros - Self::splat(1)
+            }
         })+
     }
 }
@@ -141,4 +646,9 @@ macro_rules! impl_int_arith {
 use crate::vector::*;
 
 impl_uint_arith! { (SimdU8, u8), (SimdU16, u16), (SimdU32, u32), (SimdU64, u64), (SimdUsize, usize) }
-impl_int_arith! { (SimdI8, i8), (SimdI16, i16), (SimdI32, i32), (SimdI64, i64), (SimdIsize, isize) }
+impl_int_arith! { (SimdI8, i8, Mask8), (SimdI16, i16, Mask16), (SimdI32, i32, Mask32), (SimdI64, i64, Mask64), (SimdIsize, isize, MaskSize) }
+impl_integer_pow! { (SimdU8, u8, Mask8, SimdI8), (SimdU16, u16, Mask16, SimdI16), (SimdU32, u32, Mask32, SimdI32), (SimdU64, u64, Mask64, SimdI64), (SimdUsize, usize, MaskSize, SimdIsize) }
+impl_integer_pow! { (SimdI8, i8, Mask8, SimdI8), (SimdI16, i16, Mask16, SimdI16), (SimdI32, i32, Mask32, SimdI32), (SimdI64, i64, Mask64, SimdI64), (SimdIsize, isize, MaskSize, SimdIsize) }
+impl_uint_log! { (SimdU8, u8, Mask8, SimdI8), (SimdU16, u16, Mask16, SimdI16), (SimdU32, u32, Mask32, SimdI32), (SimdU64, u64, Mask64, SimdI64), (SimdUsize, usize, MaskSize, SimdIsize) }
+impl_uint_power_of_two! { (SimdU8, u8, Mask8, SimdI8), (SimdU16, u16, Mask16, SimdI16), (SimdU32, u32, Mask32, SimdI32), (SimdU64, u64, Mask64, SimdI64), (SimdUsize, usize, MaskSize, SimdIsize) }
+impl_int_sign_bits! { (SimdI8, i8, SimdU8), (SimdI16, i16, SimdU16), (SimdI32, i32, SimdU32), (SimdI64, i64, SimdU64), (SimdIsize, isize, SimdUsize) }
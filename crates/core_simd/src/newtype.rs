@@ -0,0 +1,134 @@
+/// Defines a newtype wrapper around a vector, forwarding operators to the inner vector.
+///
+/// The wrapper is `#[repr(transparent)]` and implements [`From`] in both directions, as well as
+/// [`Deref`](core::ops::Deref) and [`DerefMut`](core::ops::DerefMut) to the inner vector so its
+/// methods are available.  Each operator listed after `impl` is implemented between two values
+/// of the wrapper, along with its compound assignment operator when there is one.
+///
+/// The supported operators are `Add`, `Sub`, `Mul`, `Div`, `Rem`, `BitAnd`, `BitOr`, `BitXor`,
+/// `Shl`, `Shr`, `Neg`, and `Not`.
+///
+/// ```
+/// # use core_simd::*;
+/// simd_newtype! {
+///     /// Distances in meters.
+///     #[derive(Copy, Clone, Debug, PartialEq)]
+///     pub struct Meters(pub SimdF32<4>);
+///     impl Add, Sub, Neg;
+/// }
+///
+/// let mut a = Meters(SimdF32::splat(1.));
+/// a += Meters(SimdF32::from_array([1., 2., 3., 4.]));
+/// assert_eq!(-a, Meters(SimdF32::from_array([-2., -3., -4., -5.])));
+/// assert_eq!(a.horizontal_sum(), 14.);
+/// ```
+#[macro_export]
+macro_rules! simd_newtype {
+    {
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($field_vis:vis $inner:ty);
+        $(impl $($trait:ident),+ $(,)?;)?
+    } => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        $vis struct $name($field_vis $inner);
+
+        impl ::core::convert::From<$inner> for $name {
+            #[inline]
+            fn from(value: $inner) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $inner {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl ::core::ops::Deref for $name {
+            type Target = $inner;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl ::core::ops::DerefMut for $name {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        $($($crate::simd_newtype! { @impl $name, $trait })+)?
+    };
+
+    { @impl $name:ident, Add } => {
+        $crate::simd_newtype! { @binary $name, Add::add, AddAssign::add_assign }
+    };
+    { @impl $name:ident, Sub } => {
+        $crate::simd_newtype! { @binary $name, Sub::sub, SubAssign::sub_assign }
+    };
+    { @impl $name:ident, Mul } => {
+        $crate::simd_newtype! { @binary $name, Mul::mul, MulAssign::mul_assign }
+    };
+    { @impl $name:ident, Div } => {
+        $crate::simd_newtype! { @binary $name, Div::div, DivAssign::div_assign }
+    };
+    { @impl $name:ident, Rem } => {
+        $crate::simd_newtype! { @binary $name, Rem::rem, RemAssign::rem_assign }
+    };
+    { @impl $name:ident, BitAnd } => {
+        $crate::simd_newtype! { @binary $name, BitAnd::bitand, BitAndAssign::bitand_assign }
+    };
+    { @impl $name:ident, BitOr } => {
+        $crate::simd_newtype! { @binary $name, BitOr::bitor, BitOrAssign::bitor_assign }
+    };
+    { @impl $name:ident, BitXor } => {
+        $crate::simd_newtype! { @binary $name, BitXor::bitxor, BitXorAssign::bitxor_assign }
+    };
+    { @impl $name:ident, Shl } => {
+        $crate::simd_newtype! { @binary $name, Shl::shl, ShlAssign::shl_assign }
+    };
+    { @impl $name:ident, Shr } => {
+        $crate::simd_newtype! { @binary $name, Shr::shr, ShrAssign::shr_assign }
+    };
+    { @impl $name:ident, Neg } => {
+        $crate::simd_newtype! { @unary $name, Neg::neg }
+    };
+    { @impl $name:ident, Not } => {
+        $crate::simd_newtype! { @unary $name, Not::not }
+    };
+
+    { @binary $name:ident, $trait:ident :: $fn:ident, $assign_trait:ident :: $assign_fn:ident } => {
+        impl ::core::ops::$trait for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self::Output {
+                Self(::core::ops::$trait::$fn(self.0, rhs.0))
+            }
+        }
+
+        impl ::core::ops::$assign_trait for $name {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: Self) {
+                ::core::ops::$assign_trait::$assign_fn(&mut self.0, rhs.0)
+            }
+        }
+    };
+
+    { @unary $name:ident, $trait:ident :: $fn:ident } => {
+        impl ::core::ops::$trait for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self) -> Self::Output {
+                Self(::core::ops::$trait::$fn(self.0))
+            }
+        }
+    };
+}
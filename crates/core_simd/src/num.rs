@@ -0,0 +1,156 @@
+//! Implementations of [`num_traits`] traits, enabled by the `num-traits` feature.
+//!
+//! Every vector type also satisfies [`num_traits::NumOps`] through its blanket implementation.
+
+use num_traits::{Bounded, Inv, MulAdd, One, Saturating, WrappingAdd, WrappingMul, WrappingSub, Zero};
+
+/// Implements the traits shared by all vector types.
+macro_rules! impl_common {
+    { $name:ident, $type:ty, $zero:literal, $one:literal } => {
+        impl<const LANES: usize> Zero for crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            #[inline]
+            fn zero() -> Self {
+                Self::splat($zero)
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == Self::zero()
+            }
+        }
+
+        impl<const LANES: usize> One for crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            #[inline]
+            fn one() -> Self {
+                Self::splat($one)
+            }
+        }
+
+        impl<const LANES: usize> Bounded for crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            #[inline]
+            fn min_value() -> Self {
+                Self::splat(<$type>::MIN)
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                Self::splat(<$type>::MAX)
+            }
+        }
+    }
+}
+
+/// Implements the traits specific to integer vector types.
+macro_rules! impl_integer {
+    { $($name:ident, $type:ty;)* } => {
+        $(
+            impl_common! { $name, $type, 0, 1 }
+
+            impl<const LANES: usize> Saturating for crate::$name<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                #[inline]
+                fn saturating_add(self, v: Self) -> Self {
+                    self.saturating_add(v)
+                }
+
+                #[inline]
+                fn saturating_sub(self, v: Self) -> Self {
+                    self.saturating_sub(v)
+                }
+            }
+
+            // Vector arithmetic always wraps
+            impl<const LANES: usize> WrappingAdd for crate::$name<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                #[inline]
+                fn wrapping_add(&self, v: &Self) -> Self {
+                    self + v
+                }
+            }
+
+            impl<const LANES: usize> WrappingSub for crate::$name<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                #[inline]
+                fn wrapping_sub(&self, v: &Self) -> Self {
+                    self - v
+                }
+            }
+
+            impl<const LANES: usize> WrappingMul for crate::$name<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                #[inline]
+                fn wrapping_mul(&self, v: &Self) -> Self {
+                    self * v
+                }
+            }
+        )*
+    }
+}
+
+/// Implements the traits specific to floating point vector types.
+macro_rules! impl_float {
+    { $($name:ident, $type:ty, $bits_ty:ident;)* } => {
+        $(
+            impl_common! { $name, $type, 0., 1. }
+
+            impl<const LANES: usize> Inv for crate::$name<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn inv(self) -> Self {
+                    self.recip()
+                }
+            }
+
+            impl<const LANES: usize> MulAdd for crate::$name<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn mul_add(self, a: Self, b: Self) -> Self {
+                    self.mul_add(a, b)
+                }
+            }
+        )*
+    }
+}
+
+impl_integer! {
+    SimdU8, u8;
+    SimdU16, u16;
+    SimdU32, u32;
+    SimdU64, u64;
+    SimdUsize, usize;
+    SimdI8, i8;
+    SimdI16, i16;
+    SimdI32, i32;
+    SimdI64, i64;
+    SimdIsize, isize;
+}
+
+impl_float! {
+    SimdF32, f32, SimdU32;
+    SimdF64, f64, SimdU64;
+}
@@ -0,0 +1,151 @@
+//! Operations combining adjacent pairs of lanes into a vector of half the length.
+
+/// Implements pairwise operations on `$name<$n>`, producing `$name<$half>`.  `$split` names the
+/// shuffle intrinsic producing `$half` lanes, or is `array` for two lanes, since there is no
+/// single-lane shuffle.
+macro_rules! impl_pairwise {
+    { $name:ident, $n:literal => $half:literal, $split:ident, |$a:ident, $b:ident| $min:expr, $max:expr } => {
+        impl crate::$name<$n> {
+            /// Splits the vector into its even-indexed and odd-indexed lanes.
+            #[inline]
+            fn split_pairs(self) -> (crate::$name<$half>, crate::$name<$half>) {
+                impl_pairwise! { @split $name, self, $half, $split }
+            }
+
+            /// Combines adjacent pairs of lanes, producing a vector of half the length where lane
+            /// `i` is the minimum of lanes `2 * i` and `2 * i + 1`.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([1 as _, 4 as _, 3 as _, 2 as _]);")]
+            #[doc = concat!("assert_eq!(a.pairwise_min(), ", stringify!($name), "::from_array([1 as _, 2 as _]));")]
+            /// ```
+            #[inline]
+            pub fn pairwise_min(self) -> crate::$name<$half> {
+                let ($a, $b) = self.split_pairs();
+                $min
+            }
+
+            /// Combines adjacent pairs of lanes, producing a vector of half the length where lane
+            /// `i` is the maximum of lanes `2 * i` and `2 * i + 1`.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([1 as _, 4 as _, 3 as _, 2 as _]);")]
+            #[doc = concat!("assert_eq!(a.pairwise_max(), ", stringify!($name), "::from_array([4 as _, 3 as _]));")]
+            /// ```
+            #[inline]
+            pub fn pairwise_max(self) -> crate::$name<$half> {
+                let ($a, $b) = self.split_pairs();
+                $max
+            }
+        }
+    };
+    { @split $name:ident, $self:ident, $half:literal, array } => {
+        let [even, odd] = $self.to_array();
+        (crate::$name::from_array([even]), crate::$name::from_array([odd]))
+    };
+    { @split $name:ident, $self:ident, $half:literal, $split:ident } => {{
+        const fn idx(offset: u32) -> [u32; $half] {
+            let mut idx = [0u32; $half];
+            let mut i = 0;
+            while i < $half {
+                idx[i] = 2 * i as u32 + offset;
+                i += 1;
+            }
+            idx
+        }
+        const EVEN: [u32; $half] = idx(0);
+        const ODD: [u32; $half] = idx(1);
+        unsafe {
+            (
+                crate::intrinsics::$split($self, $self, EVEN),
+                crate::intrinsics::$split($self, $self, ODD),
+            )
+        }
+    }};
+    { $kind:ident: $($name:ident),* } => {
+        $(
+            impl_pairwise! { @lanes $kind, $name, 2 => 1, array }
+            impl_pairwise! { @lanes $kind, $name, 4 => 2, simd_shuffle2 }
+            impl_pairwise! { @lanes $kind, $name, 8 => 4, simd_shuffle4 }
+            impl_pairwise! { @lanes $kind, $name, 16 => 8, simd_shuffle8 }
+            impl_pairwise! { @lanes $kind, $name, 32 => 16, simd_shuffle16 }
+            impl_pairwise! { @lanes $kind, $name, 64 => 32, simd_shuffle32 }
+        )*
+    };
+    { @lanes integers, $name:ident, $n:literal => $half:literal, $split:ident } => {
+        impl_pairwise! {
+            $name, $n => $half, $split,
+            |a, b| a.lanes_le(b).select(a, b),
+            a.lanes_ge(b).select(a, b)
+        }
+    };
+    { @lanes floats, $name:ident, $n:literal => $half:literal, $split:ident } => {
+        impl_pairwise! { $name, $n => $half, $split, |a, b| a.min(b), a.max(b) }
+    };
+}
+
+impl_pairwise! { integers: SimdU8, SimdU16, SimdU32, SimdU64, SimdUsize }
+impl_pairwise! { integers: SimdI8, SimdI16, SimdI32, SimdI64, SimdIsize }
+impl_pairwise! { floats: SimdF32, SimdF64 }
+
+/// Implements widening pairwise operations on `$name<$n>`, producing `$wide<$half>`.
+macro_rules! impl_widening_pairwise {
+    { $name:ident => $wide:ident, $n:literal => $half:literal } => {
+        impl crate::$name<$n> {
+            /// Adds adjacent pairs of lanes, producing a vector of half the length with lanes of
+            /// twice the width, where lane `i` is the sum of lanes `2 * i` and `2 * i + 1`.
+            ///
+            /// The sum can't overflow.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([100, 100, 3, 4]);")]
+            #[doc = concat!("assert_eq!(a.widening_pairwise_add(), ", stringify!($wide), "::from_array([200, 7]));")]
+            /// ```
+            #[inline]
+            pub fn widening_pairwise_add(self) -> crate::$wide<$half> {
+                let (even, odd) = self.split_pairs();
+                let even: crate::$wide<$half> = unsafe { crate::intrinsics::simd_cast(even) };
+                let odd: crate::$wide<$half> = unsafe { crate::intrinsics::simd_cast(odd) };
+                even + odd
+            }
+
+            /// Multiplies the lanes of two vectors, widening the products, then adds adjacent
+            /// pairs of products, producing a vector of half the length where lane `i` is
+            /// `self[2 * i] * other[2 * i] + self[2 * i + 1] * other[2 * i + 1]`.
+            ///
+            /// The products can't overflow, but the sum wraps on overflow.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([100, 100, 3, 4]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([2, 1, 5, 6]);")]
+            #[doc = concat!("assert_eq!(a.widening_pairwise_mul_add(b), ", stringify!($wide), "::from_array([300, 39]));")]
+            /// ```
+            #[inline]
+            pub fn widening_pairwise_mul_add(self, other: Self) -> crate::$wide<$half> {
+                let (a_even, a_odd) = self.split_pairs();
+                let (b_even, b_odd) = other.split_pairs();
+                let widen = |x: crate::$name<$half>| -> crate::$wide<$half> {
+                    unsafe { crate::intrinsics::simd_cast(x) }
+                };
+                widen(a_even) * widen(b_even) + widen(a_odd) * widen(b_odd)
+            }
+        }
+    };
+    { $($name:ident => $wide:ident),* } => {
+        $(
+            impl_widening_pairwise! { $name => $wide, 2 => 1 }
+            impl_widening_pairwise! { $name => $wide, 4 => 2 }
+            impl_widening_pairwise! { $name => $wide, 8 => 4 }
+            impl_widening_pairwise! { $name => $wide, 16 => 8 }
+            impl_widening_pairwise! { $name => $wide, 32 => 16 }
+            impl_widening_pairwise! { $name => $wide, 64 => 32 }
+        )*
+    };
+}
+
+impl_widening_pairwise! { SimdU8 => SimdU16, SimdU16 => SimdU32, SimdU32 => SimdU64 }
+impl_widening_pairwise! { SimdI8 => SimdI16, SimdI16 => SimdI32, SimdI32 => SimdI64 }
@@ -0,0 +1,46 @@
+use crate::{LaneCount, SupportedLaneCount};
+
+macro_rules! impl_parity {
+    { $($vector:ident, $scalar:ident => $mask:ident ($inner_ty:ident),)* } => {
+        $(
+            impl<const LANES: usize> crate::$vector<LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                /// Test if each lane has an odd number of set bits.
+                ///
+                /// # Examples
+                /// ```
+                /// # use core_simd::*;
+                #[doc = concat!("let x = ", stringify!($vector), "::from_array([0, 1, 3, 7]);")]
+                /// assert_eq!(x.parity().to_array(), [false, true, false, true]);
+                /// ```
+                #[inline]
+                pub fn parity(self) -> crate::$mask<LANES> {
+                    // fold the high half of each lane onto the low half until one bit remains
+                    let mut x = self;
+                    let mut shift = <$scalar>::BITS / 2;
+                    while shift > 0 {
+                        x ^= x >> shift as $scalar;
+                        shift /= 2;
+                    }
+                    (x & Self::splat(1)).lanes_ne(Self::splat(0))
+                }
+            }
+        )*
+    }
+}
+
+impl_parity! {
+    SimdI8, i8 => Mask8 (SimdI8),
+    SimdI16, i16 => Mask16 (SimdI16),
+    SimdI32, i32 => Mask32 (SimdI32),
+    SimdI64, i64 => Mask64 (SimdI64),
+    SimdIsize, isize => MaskSize (SimdIsize),
+
+    SimdU8, u8 => Mask8 (SimdI8),
+    SimdU16, u16 => Mask16 (SimdI16),
+    SimdU32, u32 => Mask32 (SimdI32),
+    SimdU64, u64 => Mask64 (SimdI64),
+    SimdUsize, usize => MaskSize (SimdIsize),
+}
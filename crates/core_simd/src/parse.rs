@@ -0,0 +1,95 @@
+use crate::{LaneCount, SupportedLaneCount};
+
+/// An error which can be returned when parsing a vector.
+///
+/// `E` is the error returned when parsing a single lane, such as
+/// [`ParseIntError`](core::num::ParseIntError) or [`ParseFloatError`](core::num::ParseFloatError).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseVectorError<E> {
+    /// The input was not enclosed in square brackets.
+    MissingBrackets,
+
+    /// The input contained a different number of lanes than the vector has.
+    WrongLaneCount {
+        /// The number of lanes in the vector.
+        expected: usize,
+        /// The number of lanes in the input.
+        found: usize,
+    },
+
+    /// A lane could not be parsed.
+    Lane {
+        /// The index of the lane which could not be parsed.
+        index: usize,
+        /// The error returned when parsing the lane.
+        error: E,
+    },
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for ParseVectorError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingBrackets => f.write_str("vector must be enclosed in square brackets"),
+            Self::WrongLaneCount { expected, found } => {
+                write!(f, "expected {} lanes, found {}", expected, found)
+            }
+            Self::Lane { index, error } => write!(f, "invalid lane {}: {}", index, error),
+        }
+    }
+}
+
+macro_rules! impl_parse {
+    { $($type:ident ($scalar:ty)),* } => {
+        $(
+        impl<const LANES: usize> crate::$type<LANES> where LaneCount<LANES>: SupportedLaneCount {
+            /// Parses a comma-separated list of lanes, such as `"1, 2, 3, 4"`.
+            ///
+            /// Whitespace around each lane is ignored.  This accepts the output of the `Display`
+            /// implementation, while [`FromStr`](core::str::FromStr) additionally expects the
+            /// lanes to be enclosed in square brackets, like the output of `Debug`.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            /// let v = SimdF32::<4>::parse_lanes("1.0, 2.5,-3, 4e1").unwrap();
+            /// assert_eq!(v.to_array(), [1.0, 2.5, -3.0, 40.0]);
+            /// assert!(SimdF32::<4>::parse_lanes("1.0, 2.0").is_err());
+            /// ```
+            pub fn parse_lanes(s: &str) -> Result<Self, ParseVectorError<<$scalar as core::str::FromStr>::Err>> {
+                let found = s.split(',').count();
+                if found != LANES {
+                    return Err(ParseVectorError::WrongLaneCount { expected: LANES, found });
+                }
+
+                let mut vector = Self::splat(0 as $scalar);
+                for (index, lane) in s.split(',').enumerate() {
+                    vector[index] = lane
+                        .trim()
+                        .parse()
+                        .map_err(|error| ParseVectorError::Lane { index, error })?;
+                }
+                Ok(vector)
+            }
+        }
+
+        impl<const LANES: usize> core::str::FromStr for crate::$type<LANES> where LaneCount<LANES>: SupportedLaneCount {
+            type Err = ParseVectorError<<$scalar as core::str::FromStr>::Err>;
+
+            /// Parses a list of lanes enclosed in square brackets, such as `"[1, 2, 3, 4]"`.
+            ///
+            /// Whitespace around the brackets and each lane is ignored.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let s = s.trim();
+                let lanes = s
+                    .strip_prefix('[')
+                    .and_then(|s| s.strip_suffix(']'))
+                    .ok_or(ParseVectorError::MissingBrackets)?;
+                Self::parse_lanes(lanes)
+            }
+        }
+        )*
+    }
+}
+
+impl_parse! { SimdU8 (u8), SimdU16 (u16), SimdU32 (u32), SimdU64 (u64), SimdUsize (usize) }
+impl_parse! { SimdI8 (i8), SimdI16 (i16), SimdI32 (i32), SimdI64 (i64), SimdIsize (isize) }
+impl_parse! { SimdF32 (f32), SimdF64 (f64) }
@@ -1,6 +1,6 @@
 macro_rules! impl_shuffle_lane {
-    { $name:ident, $fn:ident, $n:literal } => {
-        impl $name<$n> {
+    { $fn:ident, $n:literal } => {
+        impl<T: crate::SimdElement> crate::Simd<T, $n> {
             /// A const SIMD shuffle that takes 2 SIMD vectors and produces another vector, using
             /// the indices in the const parameter. The first or "self" vector will have its lanes
             /// indexed from 0, and the second vector will have its first lane indexed at $n.
@@ -131,16 +131,56 @@ macro_rules! impl_shuffle_lane {
                 }
                 (self.shuffle::<{ even() }>(other), self.shuffle::<{ odd() }>(other))
             }
+
+            /// Rotates the lanes of the vector left by `OFFSET` lanes, so that lane `i` of the
+            /// result is lane `(i + OFFSET) % LANES` of `self`.
+            ///
+            /// ```
+            /// # use core_simd::SimdU32;
+            /// let a = SimdU32::from_array([0, 1, 2, 3]);
+            /// assert_eq!(a.rotate_lanes_left::<1>().to_array(), [1, 2, 3, 0]);
+            /// assert_eq!(a.rotate_lanes_left::<6>().to_array(), [2, 3, 0, 1]);
+            /// ```
+            #[inline]
+            pub fn rotate_lanes_left<const OFFSET: usize>(self) -> Self {
+                self.sliding_window(self, OFFSET % $n)
+            }
+
+            /// Rotates the lanes of the vector right by `OFFSET` lanes, so that lane
+            /// `(i + OFFSET) % LANES` of the result is lane `i` of `self`.
+            ///
+            /// ```
+            /// # use core_simd::SimdU32;
+            /// let a = SimdU32::from_array([0, 1, 2, 3]);
+            /// assert_eq!(a.rotate_lanes_right::<1>().to_array(), [3, 0, 1, 2]);
+            /// ```
+            #[inline]
+            pub fn rotate_lanes_right<const OFFSET: usize>(self) -> Self {
+                self.sliding_window(self, ($n - OFFSET % $n) % $n)
+            }
+
+            /// Copies lane `LANE` of the vector into every lane.
+            ///
+            /// # Panics
+            /// Panics if `LANE` is greater than or equal to the number of lanes in the vector.
+            ///
+            /// ```
+            /// # use core_simd::SimdU32;
+            /// let a = SimdU32::from_array([0, 1, 2, 3]);
+            /// assert_eq!(a.broadcast_lane::<2>().to_array(), [2, 2, 2, 2]);
+            /// ```
+            #[inline]
+            pub fn broadcast_lane<const LANE: usize>(self) -> Self {
+                assert!(LANE < $n, "lane index out of range");
+                Self::splat(self[LANE])
+            }
         }
     }
 }
 
-macro_rules! impl_shuffle_2pow_lanes {
-    { $name:ident } => {
-        impl_shuffle_lane!{ $name, simd_shuffle2, 2 }
-        impl_shuffle_lane!{ $name, simd_shuffle4, 4 }
-        impl_shuffle_lane!{ $name, simd_shuffle8, 8 }
-        impl_shuffle_lane!{ $name, simd_shuffle16, 16 }
-        impl_shuffle_lane!{ $name, simd_shuffle32, 32 }
-    }
-}
+impl_shuffle_lane! { simd_shuffle2, 2 }
+impl_shuffle_lane! { simd_shuffle4, 4 }
+impl_shuffle_lane! { simd_shuffle8, 8 }
+impl_shuffle_lane! { simd_shuffle16, 16 }
+impl_shuffle_lane! { simd_shuffle32, 32 }
+impl_shuffle_lane! { simd_shuffle64, 64 }
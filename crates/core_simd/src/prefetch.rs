@@ -0,0 +1,84 @@
+//! Software prefetching.
+//!
+//! Prefetching is a hint that memory will be accessed soon, so it can be brought into the cache
+//! ahead of time.  This is most useful for access patterns the hardware prefetcher can't
+//! predict, such as the indices of a gather.  On targets without prefetch instructions these
+//! functions do nothing.
+//!
+//! Prefetching never faults, so it is safe to prefetch any address, valid or not.
+
+use crate::{LaneCount, SimdUsize, SupportedLaneCount};
+
+/// How long prefetched data is expected to remain in use, which determines the levels of cache it
+/// is brought into.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Locality {
+    /// The data will be used once, and should displace as little other data as possible.
+    NonTemporal,
+    /// The data will be reused a little, and may be kept in the outermost cache.
+    Low,
+    /// The data will be reused moderately, and may be kept in the outer caches.
+    Moderate,
+    /// The data will be reused heavily, and should be kept in all levels of cache.
+    High,
+}
+
+/// Dispatches to a prefetch intrinsic, which requires the locality to be a constant.
+macro_rules! prefetch {
+    { $intrinsic:ident, $ptr:expr, $locality:expr } => {
+        unsafe {
+            match $locality {
+                Locality::NonTemporal => core::intrinsics::$intrinsic($ptr, 0),
+                Locality::Low => core::intrinsics::$intrinsic($ptr, 1),
+                Locality::Moderate => core::intrinsics::$intrinsic($ptr, 2),
+                Locality::High => core::intrinsics::$intrinsic($ptr, 3),
+            }
+        }
+    };
+}
+
+/// Prefetches the cache line containing `ptr` in anticipation of a read.
+///
+/// ```
+/// # use core_simd::prefetch::{prefetch_read, Locality};
+/// let data = [0u32; 64];
+/// prefetch_read(data[32..].as_ptr(), Locality::High);
+/// ```
+#[inline]
+#[allow(clippy::not_unsafe_ptr_arg_deref)] // the pointer is never dereferenced
+pub fn prefetch_read<T>(ptr: *const T, locality: Locality) {
+    prefetch! { prefetch_read_data, ptr, locality }
+}
+
+/// Prefetches the cache line containing `ptr` in anticipation of a write.
+#[inline]
+#[allow(clippy::not_unsafe_ptr_arg_deref)] // the pointer is never dereferenced
+pub fn prefetch_write<T>(ptr: *const T, locality: Locality) {
+    prefetch! { prefetch_write_data, ptr, locality }
+}
+
+/// Prefetches the elements of `slice` at each index in `idxs` in anticipation of a read, such as
+/// a later gather with the same indices.  Out-of-bounds indices are ignored.
+///
+/// ```
+/// # use core_simd::{prefetch::{prefetch_gather, Locality}, SimdArray, SimdF32, SimdUsize};
+/// let table = vec![1.0f32; 4096];
+/// let idxs = SimdUsize::<4>::from_array([17, 4000, 123, 9999]);
+/// prefetch_gather(&table, idxs, Locality::Moderate);
+/// // ... other work ...
+/// let values = SimdF32::gather_or_default(&table, idxs);
+/// ```
+#[inline]
+pub fn prefetch_gather<T, const LANES: usize>(
+    slice: &[T],
+    idxs: SimdUsize<LANES>,
+    locality: Locality,
+) where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    for idx in idxs.as_slice() {
+        if let Some(element) = slice.get(*idx) {
+            prefetch_read(element, locality);
+        }
+    }
+}
@@ -1,52 +1,153 @@
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// Reductions across the lanes of a vector.
+///
+/// The reductions return the vector's [`Scalar`](crate::Vector::Scalar) type, so generic code can
+/// bound on both traits without distinguishing two scalar types.
+///
+/// This trait allows writing generic code over any vector that can be reduced:
+/// ```
+/// # use core_simd::*;
+/// fn range<V: SimdReduce>(v: V) -> (V::Scalar, V::Scalar) {
+///     (v.reduce_min(), v.reduce_max())
+/// }
+///
+/// assert_eq!(range(SimdI32::<4>::from_array([3, -1, 4, 1])), (-1, 4));
+/// assert_eq!(range(SimdF64::<2>::from_array([0.5, 2.5])), (0.5, 2.5));
+/// ```
+pub trait SimdReduce: sealed::Sealed + crate::Vector {
+    /// Returns the sum of the lanes of the vector.  Integer addition wraps.
+    fn reduce_sum(self) -> Self::Scalar;
+
+    /// Returns the product of the lanes of the vector.  Integer multiplication wraps.
+    fn reduce_product(self) -> Self::Scalar;
+
+    /// Returns the maximum lane in the vector.
+    ///
+    /// For floats, returns values based on equality, so a vector containing both `0.` and `-0.`
+    /// may return either.  This function will not return `NaN` unless all lanes are `NaN`.
+    fn reduce_max(self) -> Self::Scalar;
+
+    /// Returns the minimum lane in the vector.
+    ///
+    /// For floats, returns values based on equality, so a vector containing both `0.` and `-0.`
+    /// may return either.  This function will not return `NaN` unless all lanes are `NaN`.
+    fn reduce_min(self) -> Self::Scalar;
+}
+
+/// Bitwise reductions across the lanes of an integer vector.
+pub trait SimdReduceBits: SimdReduce {
+    /// Returns the cumulative bitwise "and" across the lanes of the vector.
+    fn reduce_and(self) -> Self::Scalar;
+
+    /// Returns the cumulative bitwise "or" across the lanes of the vector.
+    fn reduce_or(self) -> Self::Scalar;
+
+    /// Returns the cumulative bitwise "xor" across the lanes of the vector.
+    fn reduce_xor(self) -> Self::Scalar;
+}
+
 macro_rules! impl_integer_reductions {
     { $name:ident, $scalar:ty } => {
+        impl<const LANES: usize> crate::reduction::sealed::Sealed for crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount
+        {}
+
+        impl<const LANES: usize> crate::SimdReduce for crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount
+        {
+            #[inline]
+            fn reduce_sum(self) -> $scalar {
+                unsafe { crate::intrinsics::simd_reduce_add_ordered(self, 0) }
+            }
+
+            #[inline]
+            fn reduce_product(self) -> $scalar {
+                unsafe { crate::intrinsics::simd_reduce_mul_ordered(self, 1) }
+            }
+
+            #[inline]
+            fn reduce_max(self) -> $scalar {
+                unsafe { crate::intrinsics::simd_reduce_max(self) }
+            }
+
+            #[inline]
+            fn reduce_min(self) -> $scalar {
+                unsafe { crate::intrinsics::simd_reduce_min(self) }
+            }
+        }
+
+        impl<const LANES: usize> crate::SimdReduceBits for crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount
+        {
+            #[inline]
+            fn reduce_and(self) -> $scalar {
+                unsafe { crate::intrinsics::simd_reduce_and(self) }
+            }
+
+            #[inline]
+            fn reduce_or(self) -> $scalar {
+                unsafe { crate::intrinsics::simd_reduce_or(self) }
+            }
+
+            #[inline]
+            fn reduce_xor(self) -> $scalar {
+                unsafe { crate::intrinsics::simd_reduce_xor(self) }
+            }
+        }
+
         impl<const LANES: usize> crate::$name<LANES>
         where
-            Self: crate::LanesAtMost32
+            crate::LaneCount<LANES>: crate::SupportedLaneCount
         {
             /// Horizontal wrapping add.  Returns the sum of the lanes of the vector, with wrapping addition.
             #[inline]
             pub fn horizontal_sum(self) -> $scalar {
-                unsafe { crate::intrinsics::simd_reduce_add_ordered(self, 0) }
+                crate::SimdReduce::reduce_sum(self)
             }
 
             /// Horizontal wrapping multiply.  Returns the product of the lanes of the vector, with wrapping multiplication.
             #[inline]
             pub fn horizontal_product(self) -> $scalar {
-                unsafe { crate::intrinsics::simd_reduce_mul_ordered(self, 1) }
+                crate::SimdReduce::reduce_product(self)
             }
 
             /// Horizontal bitwise "and".  Returns the cumulative bitwise "and" across the lanes of
             /// the vector.
             #[inline]
             pub fn horizontal_and(self) -> $scalar {
-                unsafe { crate::intrinsics::simd_reduce_and(self) }
+                crate::SimdReduceBits::reduce_and(self)
             }
 
             /// Horizontal bitwise "or".  Returns the cumulative bitwise "or" across the lanes of
             /// the vector.
             #[inline]
             pub fn horizontal_or(self) -> $scalar {
-                unsafe { crate::intrinsics::simd_reduce_or(self) }
+                crate::SimdReduceBits::reduce_or(self)
             }
 
             /// Horizontal bitwise "xor".  Returns the cumulative bitwise "xor" across the lanes of
             /// the vector.
             #[inline]
             pub fn horizontal_xor(self) -> $scalar {
-                unsafe { crate::intrinsics::simd_reduce_xor(self) }
+                crate::SimdReduceBits::reduce_xor(self)
             }
 
             /// Horizontal maximum.  Returns the maximum lane in the vector.
             #[inline]
             pub fn horizontal_max(self) -> $scalar {
-                unsafe { crate::intrinsics::simd_reduce_max(self) }
+                crate::SimdReduce::reduce_max(self)
             }
 
             /// Horizontal minimum.  Returns the minimum lane in the vector.
             #[inline]
             pub fn horizontal_min(self) -> $scalar {
-                unsafe { crate::intrinsics::simd_reduce_min(self) }
+                crate::SimdReduce::reduce_min(self)
             }
         }
     }
@@ -54,14 +155,17 @@ macro_rules! impl_integer_reductions {
 
 macro_rules! impl_float_reductions {
     { $name:ident, $scalar:ty } => {
-        impl<const LANES: usize> crate::$name<LANES>
+        impl<const LANES: usize> crate::reduction::sealed::Sealed for crate::$name<LANES>
         where
-            Self: crate::LanesAtMost32
-        {
+            crate::LaneCount<LANES>: crate::SupportedLaneCount
+        {}
 
-            /// Horizontal add.  Returns the sum of the lanes of the vector.
+        impl<const LANES: usize> crate::SimdReduce for crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount
+        {
             #[inline]
-            pub fn horizontal_sum(self) -> $scalar {
+            fn reduce_sum(self) -> $scalar {
                 // LLVM sum is inaccurate on i586
                 if cfg!(all(target_arch = "x86", not(target_feature = "sse2"))) {
                     self.as_slice().iter().sum()
@@ -70,9 +174,8 @@ macro_rules! impl_float_reductions {
                 }
             }
 
-            /// Horizontal multiply.  Returns the product of the lanes of the vector.
             #[inline]
-            pub fn horizontal_product(self) -> $scalar {
+            fn reduce_product(self) -> $scalar {
                 // LLVM product is inaccurate on i586
                 if cfg!(all(target_arch = "x86", not(target_feature = "sse2"))) {
                     self.as_slice().iter().product()
@@ -81,13 +184,41 @@ macro_rules! impl_float_reductions {
                 }
             }
 
+            #[inline]
+            fn reduce_max(self) -> $scalar {
+                unsafe { crate::intrinsics::simd_reduce_max(self) }
+            }
+
+            #[inline]
+            fn reduce_min(self) -> $scalar {
+                unsafe { crate::intrinsics::simd_reduce_min(self) }
+            }
+        }
+
+        impl<const LANES: usize> crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount
+        {
+
+            /// Horizontal add.  Returns the sum of the lanes of the vector.
+            #[inline]
+            pub fn horizontal_sum(self) -> $scalar {
+                crate::SimdReduce::reduce_sum(self)
+            }
+
+            /// Horizontal multiply.  Returns the product of the lanes of the vector.
+            #[inline]
+            pub fn horizontal_product(self) -> $scalar {
+                crate::SimdReduce::reduce_product(self)
+            }
+
             /// Horizontal maximum.  Returns the maximum lane in the vector.
             ///
             /// Returns values based on equality, so a vector containing both `0.` and `-0.` may
             /// return either.  This function will not return `NaN` unless all lanes are `NaN`.
             #[inline]
             pub fn horizontal_max(self) -> $scalar {
-                unsafe { crate::intrinsics::simd_reduce_max(self) }
+                crate::SimdReduce::reduce_max(self)
             }
 
             /// Horizontal minimum.  Returns the minimum lane in the vector.
@@ -96,17 +227,18 @@ macro_rules! impl_float_reductions {
             /// return either.  This function will not return `NaN` unless all lanes are `NaN`.
             #[inline]
             pub fn horizontal_min(self) -> $scalar {
-                unsafe { crate::intrinsics::simd_reduce_min(self) }
+                crate::SimdReduce::reduce_min(self)
             }
         }
     }
 }
 
 macro_rules! impl_full_mask_reductions {
-    { $name:ident, $bits_ty:ident } => {
-        impl<T: crate::Mask, const LANES: usize> $name<T, LANES>
+    { $name:ident } => {
+        impl<T, const LANES: usize> $name<T, LANES>
         where
-            crate::$bits_ty<LANES>: crate::LanesAtMost32
+            T: crate::MaskElement,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
             #[inline]
             pub fn any(self) -> bool {
@@ -121,12 +253,35 @@ macro_rules! impl_full_mask_reductions {
     }
 }
 
+/// Implements `any` and `all` for bitmask-backed masks by comparing the bitmask as a single
+/// integer, without converting to a vector.
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+macro_rules! impl_bitmask_reductions {
+    { $name:ident } => {
+        impl<T, const LANES: usize> $name<T, LANES>
+        where
+            T: crate::MaskElement,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            #[inline]
+            pub fn any(self) -> bool {
+                self.to_int_bitmask() != Self::splat(false).to_int_bitmask()
+            }
+
+            #[inline]
+            pub fn all(self) -> bool {
+                self.to_int_bitmask() == Self::splat(true).to_int_bitmask()
+            }
+        }
+    }
+}
+
 macro_rules! impl_opaque_mask_reductions {
-    { $name:ident, $bits_ty:ident } => {
-        impl<const LANES: usize> $name<LANES>
+    { $name:ident } => {
+        impl<T, const LANES: usize> $name<T, LANES>
         where
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
-            $name<LANES>: crate::Mask,
+            T: crate::MaskElement,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
             /// Returns true if any lane is set, or false otherwise.
             #[inline]
@@ -139,6 +294,26 @@ macro_rules! impl_opaque_mask_reductions {
             pub fn all(self) -> bool {
                 self.0.all()
             }
+
+            /// Returns the logical "and" of all lanes, which is equivalent to [`Self::all`].
+            #[inline]
+            pub fn horizontal_and(self) -> bool {
+                self.all()
+            }
+
+            /// Returns the logical "or" of all lanes, which is equivalent to [`Self::any`].
+            #[inline]
+            pub fn horizontal_or(self) -> bool {
+                self.any()
+            }
+
+            /// Returns the logical "xor" of all lanes, which is true if an odd number of lanes
+            /// are set.
+            #[inline]
+            pub fn horizontal_xor(self) -> bool {
+                let xor: T = unsafe { crate::intrinsics::simd_reduce_xor(self.to_int()) };
+                xor != T::FALSE
+            }
         }
     }
 }
@@ -0,0 +1,198 @@
+//! Changing the number of lanes of vectors and masks.
+
+/// Implements `resize` on the vector `$name` holding lanes of `$type`.
+macro_rules! impl_vector_resize {
+    { $($name:ident, $type:ty;)* } => {
+        $(
+            impl<const LANES: usize> crate::$name<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                /// Changes the number of lanes to `N`, truncating the vector or extending it with
+                /// lanes set to `value`.
+                ///
+                /// ```
+                /// # use core_simd::*;
+                #[doc = concat!("let a = ", stringify!($name), "::from_array([1 as _, 2 as _, 3 as _, 4 as _]);")]
+                #[doc = concat!("assert_eq!(a.resize::<2>(0 as _), ", stringify!($name), "::from_array([1 as _, 2 as _]));")]
+                #[doc = concat!("assert_eq!(a.resize::<8>(9 as _).to_array(), [1 as _, 2 as _, 3 as _, 4 as _, 9 as _, 9 as _, 9 as _, 9 as _]);")]
+                /// ```
+                #[inline]
+                pub fn resize<const N: usize>(self, value: $type) -> crate::$name<N>
+                where
+                    crate::LaneCount<N>: crate::SupportedLaneCount,
+                {
+                    let mut resized = crate::$name::<N>::splat(value);
+                    let len = LANES.min(N);
+                    resized.as_mut_slice()[..len].copy_from_slice(&self.as_slice()[..len]);
+                    resized
+                }
+            }
+        )*
+    }
+}
+
+/// Implements `split` on `$name<$n>` and `concat` on `$name<$half>`.  `$split` and `$concat` name
+/// the shuffle intrinsics producing `$half` and `$n` lanes; `$split` is `array` when `$half` is 1,
+/// since there is no single-lane shuffle.
+macro_rules! impl_vector_split_concat {
+    { $name:ident, $n:literal => $half:literal, $split:ident, $concat:ident } => {
+        impl crate::$name<$n> {
+            /// Splits the vector into its first and last halves.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([1 as _, 2 as _, 3 as _, 4 as _]);")]
+            #[doc = concat!("let (lo, hi) = a.split();")]
+            #[doc = concat!("assert_eq!(lo, ", stringify!($name), "::from_array([1 as _, 2 as _]));")]
+            #[doc = concat!("assert_eq!(hi, ", stringify!($name), "::from_array([3 as _, 4 as _]));")]
+            /// ```
+            #[inline]
+            pub fn split(self) -> (crate::$name<$half>, crate::$name<$half>) {
+                impl_vector_split_concat! { @split $name, self, $half, $split }
+            }
+        }
+
+        impl crate::$name<$half> {
+            /// Concatenates two vectors, with the lanes of `self` first, followed by the lanes
+            /// of `other`.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([1 as _, 2 as _]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([3 as _, 4 as _]);")]
+            #[doc = concat!("assert_eq!(a.concat(b), ", stringify!($name), "::from_array([1 as _, 2 as _, 3 as _, 4 as _]));")]
+            /// ```
+            #[inline]
+            pub fn concat(self, other: Self) -> crate::$name<$n> {
+                const fn idx() -> [u32; $n] {
+                    let mut idx = [0u32; $n];
+                    let mut i = 0;
+                    while i < $n {
+                        idx[i] = i as u32;
+                        i += 1;
+                    }
+                    idx
+                }
+                const IDX: [u32; $n] = idx();
+                unsafe { crate::intrinsics::$concat(self, other, IDX) }
+            }
+        }
+    };
+    { @split $name:ident, $self:ident, $half:literal, array } => {{
+        let [lo, hi] = $self.to_array();
+        (crate::$name::from_array([lo]), crate::$name::from_array([hi]))
+    }};
+    { @split $name:ident, $self:ident, $half:literal, $split:ident } => {{
+        const fn idx(offset: u32) -> [u32; $half] {
+            let mut idx = [0u32; $half];
+            let mut i = 0;
+            while i < $half {
+                idx[i] = i as u32 + offset;
+                i += 1;
+            }
+            idx
+        }
+        const LO: [u32; $half] = idx(0);
+        const HI: [u32; $half] = idx($half);
+        unsafe {
+            (
+                crate::intrinsics::$split($self, $self, LO),
+                crate::intrinsics::$split($self, $self, HI),
+            )
+        }
+    }};
+    { $($name:ident),* } => {
+        $(
+            impl_vector_split_concat! { $name, 2 => 1, array, simd_shuffle2 }
+            impl_vector_split_concat! { $name, 4 => 2, simd_shuffle2, simd_shuffle4 }
+            impl_vector_split_concat! { $name, 8 => 4, simd_shuffle4, simd_shuffle8 }
+            impl_vector_split_concat! { $name, 16 => 8, simd_shuffle8, simd_shuffle16 }
+            impl_vector_split_concat! { $name, 32 => 16, simd_shuffle16, simd_shuffle32 }
+            impl_vector_split_concat! { $name, 64 => 32, simd_shuffle32, simd_shuffle64 }
+        )*
+    };
+}
+
+/// Implements `resize`, `split`, and `concat` on the mask `$name`, in terms of the same
+/// operations on its integer representation `$bits_ty`.
+macro_rules! impl_mask_resize {
+    { $($name:ident ($bits_ty:ident)),* } => {
+        $(
+            impl<const LANES: usize> crate::$name<LANES>
+            where
+                crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            {
+                /// Changes the number of lanes to `N`, truncating the mask or extending it with
+                /// lanes set to `value`.
+                ///
+                /// ```
+                /// # use core_simd::*;
+                #[doc = concat!("let mask = ", stringify!($name), "::from_array([true, false]);")]
+                /// assert_eq!(mask.resize::<4>(false).to_array(), [true, false, false, false]);
+                /// ```
+                #[inline]
+                pub fn resize<const N: usize>(self, value: bool) -> crate::$name<N>
+                where
+                    crate::LaneCount<N>: crate::SupportedLaneCount,
+                {
+                    let value = if value { -1 } else { 0 };
+                    unsafe { crate::$name::from_int_unchecked(self.to_int().resize::<N>(value)) }
+                }
+            }
+
+            impl_mask_resize! { @split_concat $name, $bits_ty, 2 => 1 }
+            impl_mask_resize! { @split_concat $name, $bits_ty, 4 => 2 }
+            impl_mask_resize! { @split_concat $name, $bits_ty, 8 => 4 }
+            impl_mask_resize! { @split_concat $name, $bits_ty, 16 => 8 }
+            impl_mask_resize! { @split_concat $name, $bits_ty, 32 => 16 }
+            impl_mask_resize! { @split_concat $name, $bits_ty, 64 => 32 }
+        )*
+    };
+    { @split_concat $name:ident, $bits_ty:ident, $n:literal => $half:literal } => {
+        impl crate::$name<$n> {
+            /// Splits the mask into its first and last halves.
+            #[inline]
+            pub fn split(self) -> (crate::$name<$half>, crate::$name<$half>) {
+                let (lo, hi) = self.to_int().split();
+                unsafe { (crate::$name::from_int_unchecked(lo), crate::$name::from_int_unchecked(hi)) }
+            }
+        }
+
+        impl crate::$name<$half> {
+            /// Concatenates two masks, with the lanes of `self` first, followed by the lanes of
+            /// `other`.
+            #[inline]
+            pub fn concat(self, other: Self) -> crate::$name<$n> {
+                unsafe { crate::$name::from_int_unchecked(self.to_int().concat(other.to_int())) }
+            }
+        }
+    };
+}
+
+impl_vector_resize! {
+    SimdU8, u8;
+    SimdU16, u16;
+    SimdU32, u32;
+    SimdU64, u64;
+    SimdUsize, usize;
+    SimdI8, i8;
+    SimdI16, i16;
+    SimdI32, i32;
+    SimdI64, i64;
+    SimdIsize, isize;
+    SimdF32, f32;
+    SimdF64, f64;
+}
+
+impl_vector_split_concat! { SimdU8, SimdU16, SimdU32, SimdU64, SimdUsize }
+impl_vector_split_concat! { SimdI8, SimdI16, SimdI32, SimdI64, SimdIsize }
+impl_vector_split_concat! { SimdF32, SimdF64 }
+
+impl_mask_resize! {
+    Mask8 (SimdI8),
+    Mask16 (SimdI16),
+    Mask32 (SimdI32),
+    Mask64 (SimdI64),
+    MaskSize (SimdIsize)
+}
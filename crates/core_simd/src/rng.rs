@@ -0,0 +1,113 @@
+//! Lane-parallel pseudorandom number generation.
+//!
+//! Each lane of the generator is an independent stream, so a single call produces a whole
+//! vector of random values without serializing through a scalar generator.  These generators
+//! are not cryptographically secure.
+
+use crate::{LaneCount, SimdF32, SimdF64, SimdU32, SimdU64, SupportedLaneCount};
+
+/// Advances a SplitMix64 state, returning the next output.  Used to expand a single seed into
+/// well-distributed initial states.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// Rotates each lane left by `n` bits.
+#[inline]
+fn rotate_left<const LANES: usize>(x: SimdU64<LANES>, n: u64) -> SimdU64<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (x << n) | (x >> (64 - n))
+}
+
+/// A xoshiro256++ generator running an independent stream in each lane.
+///
+/// ```
+/// # use core_simd::{rng::Xoshiro256PlusPlus, SimdF64};
+/// let mut rng = Xoshiro256PlusPlus::<4>::seed_from_u64(42);
+/// let x = rng.next_f64();
+/// assert!(x.lanes_ge(SimdF64::splat(0.)).all() && x.lanes_lt(SimdF64::splat(1.)).all());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Xoshiro256PlusPlus<const LANES: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    s: [SimdU64<LANES>; 4],
+}
+
+impl<const LANES: usize> Xoshiro256PlusPlus<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Creates a generator from the initial state of each lane.
+    ///
+    /// The state of a lane must not be all zero.
+    ///
+    /// # Panics
+    /// Panics if the state of any lane is all zero.
+    #[inline]
+    pub fn from_state(s: [SimdU64<LANES>; 4]) -> Self {
+        let nonzero = s[0] | s[1] | s[2] | s[3];
+        assert!(
+            nonzero.as_slice().iter().all(|x| *x != 0),
+            "the state of each lane must not be all zero"
+        );
+        Self { s }
+    }
+
+    /// Creates a generator from a single seed, giving each lane a distinct stream.
+    ///
+    /// The seed is expanded with SplitMix64, as recommended by the xoshiro authors.
+    pub fn seed_from_u64(seed: u64) -> Self {
+        let mut state = seed;
+        let mut s = [SimdU64::splat(0); 4];
+        for lane in 0..LANES {
+            for word in s.iter_mut() {
+                word[lane] = splitmix64(&mut state);
+            }
+        }
+        Self { s }
+    }
+
+    /// Returns the next vector of random `u64`s.
+    #[inline]
+    pub fn next_u64(&mut self) -> SimdU64<LANES> {
+        let [s0, s1, s2, s3] = &mut self.s;
+        let result = rotate_left(*s0 + *s3, 23) + *s0;
+        let t = *s1 << 17;
+        *s2 ^= *s0;
+        *s3 ^= *s1;
+        *s1 ^= *s2;
+        *s0 ^= *s3;
+        *s2 ^= t;
+        *s3 = rotate_left(*s3, 45);
+        result
+    }
+
+    /// Returns the next vector of random `u32`s, taken from the upper bits of
+    /// [`Self::next_u64`].
+    #[inline]
+    pub fn next_u32(&mut self) -> SimdU32<LANES> {
+        unsafe { crate::intrinsics::simd_cast(self.next_u64() >> 32) }
+    }
+
+    /// Returns the next vector of random `f64`s, uniformly distributed in `[0, 1)`.
+    #[inline]
+    pub fn next_f64(&mut self) -> SimdF64<LANES> {
+        let bits: SimdF64<LANES> = unsafe { crate::intrinsics::simd_cast(self.next_u64() >> 11) };
+        bits * SimdF64::splat(1. / (1u64 << 53) as f64)
+    }
+
+    /// Returns the next vector of random `f32`s, uniformly distributed in `[0, 1)`.
+    #[inline]
+    pub fn next_f32(&mut self) -> SimdF32<LANES> {
+        let bits: SimdF32<LANES> = unsafe { crate::intrinsics::simd_cast(self.next_u64() >> 40) };
+        bits * SimdF32::splat(1. / (1u32 << 24) as f32)
+    }
+}
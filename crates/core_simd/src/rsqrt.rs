@@ -0,0 +1,91 @@
+//! Approximate reciprocal square roots of float vectors.
+
+macro_rules! impl_rsqrt {
+    {
+        $name:ident, $bits_ty:ident,
+        magic: $magic:literal,
+        steps: $steps:literal,
+        error: $error:literal,
+    } => {
+        impl<const LANES: usize> crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            /// Estimates the reciprocal square root of each lane, `1 / sqrt(x)`, from its bit
+            /// pattern.
+            ///
+            /// For positive normal lanes, the relative error is at most 3.5%.  Other lanes produce
+            /// unspecified values.  Refine the estimate with [`Self::rsqrt_refine`] or
+            /// [`Self::rsqrt_refine2`] for more precision.
+            #[inline]
+            pub fn rsqrt_estimate(self) -> Self {
+                Self::from_bits(crate::$bits_ty::splat($magic) - (self.to_bits() >> 1))
+            }
+
+            /// Refines an estimate of the reciprocal square root of each lane with one step of
+            /// Newton's method.
+            ///
+            /// Each step roughly squares the relative error: an estimate from
+            /// [`Self::rsqrt_estimate`] is refined to within 0.18%.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([1., 4., 0.25, 100.]);")]
+            /// let estimate = x.rsqrt_estimate();
+            /// let refined = x.rsqrt_refine(estimate);
+            #[doc = concat!("let exact = ", stringify!($name), "::from_array([1., 0.5, 2., 0.1]);")]
+            #[doc = concat!("let error = |y: ", stringify!($name), "<4>| ((y - exact) / exact).abs().horizontal_max();")]
+            /// assert!(error(refined) < error(estimate) / 10.);
+            /// ```
+            #[inline]
+            pub fn rsqrt_refine(self, estimate: Self) -> Self {
+                let half = Self::splat(0.5) * self;
+                estimate * (Self::splat(1.5) - half * estimate * estimate)
+            }
+
+            /// Refines an estimate of the reciprocal square root of each lane with two steps of
+            /// Newton's method.
+            ///
+            /// An estimate from [`Self::rsqrt_estimate`] is refined to within 0.0005%.
+            #[inline]
+            pub fn rsqrt_refine2(self, estimate: Self) -> Self {
+                self.rsqrt_refine(self.rsqrt_refine(estimate))
+            }
+
+            /// Computes the reciprocal square root of each lane, `1 / sqrt(x)`, approximately.
+            ///
+            /// The bit pattern estimate is refined with Newton's method, which is usually faster
+            #[doc = concat!("than a square root and division.  For positive normal lanes, the relative error is at most ", $error, ".")]
+            /// Other lanes produce unspecified values.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([1., 4., 0.25, 100.]);")]
+            #[doc = concat!("let exact = ", stringify!($name), "::from_array([1., 0.5, 2., 0.1]);")]
+            /// assert!(((x.rsqrt_fast() - exact) / exact).abs().horizontal_max() < 1e-5);
+            /// ```
+            #[inline]
+            pub fn rsqrt_fast(self) -> Self {
+                let mut estimate = self.rsqrt_estimate();
+                for _ in 0..$steps {
+                    estimate = self.rsqrt_refine(estimate);
+                }
+                estimate
+            }
+        }
+    }
+}
+
+impl_rsqrt! {
+    SimdF32, SimdU32,
+    magic: 0x5f37_5a86,
+    steps: 2,
+    error: "2<sup>-17</sup>",
+}
+
+impl_rsqrt! {
+    SimdF64, SimdU64,
+    magic: 0x5fe6_eb50_c7b5_37a9,
+    steps: 3,
+    error: "2<sup>-34</sup>",
+}
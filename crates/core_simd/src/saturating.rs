@@ -0,0 +1,76 @@
+use crate::{LaneCount, SupportedLaneCount};
+
+/// Provides intentionally-saturating arithmetic on integer vectors.
+///
+/// Operations like `+` on `Saturating<SimdU8<LANES>>` clamp each lane to the bounds of the lane
+/// type instead of wrapping around, allowing the overflow policy to be encoded in the type system.
+///
+/// Plain vector arithmetic already wraps on overflow, so it behaves like [`core::num::Wrapping`].
+///
+/// ```
+/// # use core_simd::*;
+/// let a = Saturating(SimdU8::from_array([1, 100, 200, 255]));
+/// let b = Saturating(SimdU8::splat(100));
+/// assert_eq!((a + b).0.to_array(), [101, 200, 255, 255]);
+/// assert_eq!((a - b).0.to_array(), [0, 0, 100, 155]);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default, Hash, Debug)]
+#[repr(transparent)]
+pub struct Saturating<T>(pub T);
+
+macro_rules! impl_saturating_op {
+    { $vector:ident, $trait:ident :: $fn:ident, $assign_trait:ident :: $assign_fn:ident, $saturating_fn:ident } => {
+        impl<const LANES: usize> core::ops::$trait for Saturating<crate::$vector<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self::Output {
+                Saturating(self.0.$saturating_fn(rhs.0))
+            }
+        }
+
+        impl<const LANES: usize> core::ops::$assign_trait for Saturating<crate::$vector<LANES>>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = core::ops::$trait::$fn(*self, rhs);
+            }
+        }
+    }
+}
+
+macro_rules! impl_unsigned_saturating {
+    { $($vector:ident),* } => {
+        $(
+            impl_saturating_op! { $vector, Add::add, AddAssign::add_assign, saturating_add }
+            impl_saturating_op! { $vector, Sub::sub, SubAssign::sub_assign, saturating_sub }
+        )*
+    }
+}
+
+macro_rules! impl_signed_saturating {
+    { $($vector:ident),* } => {
+        impl_unsigned_saturating! { $($vector),* }
+        $(
+            impl<const LANES: usize> core::ops::Neg for Saturating<crate::$vector<LANES>>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn neg(self) -> Self::Output {
+                    Saturating(self.0.saturating_neg())
+                }
+            }
+        )*
+    }
+}
+
+impl_unsigned_saturating! { SimdU8, SimdU16, SimdU32, SimdU64, SimdUsize }
+impl_signed_saturating! { SimdI8, SimdI16, SimdI32, SimdI64, SimdIsize }
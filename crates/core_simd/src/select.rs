@@ -14,12 +14,10 @@ macro_rules! impl_select {
         $mask:ident ($bits_ty:ident): $($type:ident),*
     } => {
         $(
-        impl<const LANES: usize> Sealed for crate::$type<LANES> where Self: crate::LanesAtMost32 {}
+        impl<const LANES: usize> Sealed for crate::$type<LANES> where crate::LaneCount<LANES>: crate::SupportedLaneCount {}
         impl<const LANES: usize> Select<crate::$mask<LANES>> for crate::$type<LANES>
         where
-            crate::$mask<LANES>: crate::Mask,
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
-            Self: crate::LanesAtMost32,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
             #[doc(hidden)]
             #[inline]
@@ -27,17 +25,40 @@ macro_rules! impl_select {
                 unsafe { crate::intrinsics::simd_select(mask.to_int(), true_values, false_values) }
             }
         }
+
+        impl<const LANES: usize> crate::$type<LANES> where crate::LaneCount<LANES>: crate::SupportedLaneCount {
+            /// Choose lanes from two vectors using the bits of an integer bitmask.
+            ///
+            /// For each lane, choose the corresponding lane from `true_values` if that bit of
+            /// `bitmask` is set, and `false_values` otherwise.  Bit 0 corresponds to lane 0.  The
+            /// bitmask is a `u8` for vectors of up to 8 lanes, a `u16` for 16 lanes, a `u32` for
+            /// 32 lanes, and a `u64` for 64 lanes.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            /// let a = SimdI32::from_array([0, 1, 2, 3]);
+            /// let b = SimdI32::from_array([4, 5, 6, 7]);
+            /// let c = SimdI32::select_bitmask(0b1001, a, b);
+            /// assert_eq!(c.to_array(), [0, 5, 6, 3]);
+            /// ```
+            #[inline]
+            pub fn select_bitmask(
+                bitmask: <crate::LaneCount<LANES> as crate::SupportedLaneCount>::IntBitMask,
+                true_values: Self,
+                false_values: Self,
+            ) -> Self {
+                unsafe { crate::intrinsics::simd_select_bitmask(bitmask, true_values, false_values) }
+            }
+        }
         )*
 
         impl<const LANES: usize> Sealed for crate::$mask<LANES>
         where
-            Self: crate::Mask,
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {}
         impl<const LANES: usize> Select<Self> for crate::$mask<LANES>
         where
-            Self: crate::Mask,
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
             #[doc(hidden)]
             #[inline]
@@ -45,40 +66,89 @@ macro_rules! impl_select {
                 mask & true_values | !mask & false_values
             }
         }
+    }
+}
 
-        impl<const LANES: usize> crate::$mask<LANES>
+macro_rules! impl_bitwise_select {
+    { $($type:ident),* } => {
+        $(
+        impl<const LANES: usize> crate::$type<LANES> where crate::LaneCount<LANES>: crate::SupportedLaneCount {
+            /// Choose bits from two vectors using a bit pattern.
+            ///
+            /// For each bit, choose the corresponding bit from `true_values` if that bit of
+            /// `mask_bits` is set, and `false_values` otherwise.  Unlike a lane mask, the selector
+            /// may be any bit pattern, so this computes `(true_values & mask_bits) |
+            /// (false_values & !mask_bits)`.  This lowers to a single bitwise select instruction
+            /// (such as `vbsl` or `vpternlogd`) where one is available.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            /// let a = SimdU8::from_array([0xff, 0xff, 0x00, 0xaa]);
+            /// let b = SimdU8::from_array([0x00, 0x00, 0xff, 0x55]);
+            /// let m = SimdU8::from_array([0x0f, 0xf0, 0x3c, 0xff]);
+            /// let c = SimdU8::bitwise_select(m, a, b);
+            /// assert_eq!(c.to_array(), [0x0f, 0xf0, 0xc3, 0xaa]);
+            /// ```
+            #[inline]
+            pub fn bitwise_select(mask_bits: Self, true_values: Self, false_values: Self) -> Self {
+                // written as `b ^ ((a ^ b) & m)` so that LLVM recognizes the bit select
+                false_values ^ ((true_values ^ false_values) & mask_bits)
+            }
+        }
+        )*
+    }
+}
+
+macro_rules! impl_count_where {
+    {
+        $mask:ident ($bits_ty:ident): $($type:ident),*
+    } => {
+        $(
+        impl<const LANES: usize> crate::$type<LANES>
         where
-            Self: crate::Mask,
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
-            /// Choose lanes from two vectors.
+            /// Returns the integer form of a mask, -1 in set lanes and 0 elsewhere, reinterpreted
+            /// as this vector type.
+            #[inline]
+            fn from_mask_int(mask: crate::$mask<LANES>) -> Self {
+                // SAFETY: the integer form of the mask has the same size and lanes as `Self`
+                unsafe { core::mem::transmute_copy(&mask.to_int()) }
+            }
+
+            /// Adds one to each lane for which `mask` is set, wrapping on overflow.
             ///
-            /// For each lane in the mask, choose the corresponding lane from `true_values` if
-            /// that lane mask is true, and `false_values` if that lane mask is false.
+            /// This counts occurrences in each lane: a set mask lane is -1 as an integer, so it is
+            /// subtracted directly rather than selecting between `self` and `self + 1`.
             ///
             /// ```
-            /// # use core_simd::{Mask32, SimdI32};
-            /// let a = SimdI32::from_array([0, 1, 2, 3]);
-            /// let b = SimdI32::from_array([4, 5, 6, 7]);
-            /// let mask = Mask32::from_array([true, false, false, true]);
-            /// let c = mask.select(a, b);
-            /// assert_eq!(c.to_array(), [0, 5, 6, 3]);
+            /// # use core_simd::*;
+            #[doc = concat!("let mut counts = ", stringify!($type), "::<4>::splat(0);")]
+            #[doc = concat!("let values = ", stringify!($type), "::from_array([1, 2, 1, 3]);")]
+            /// for target in [1, 3, 1].iter() {
+            #[doc = concat!("    counts = counts.incr_where(values.lanes_eq(", stringify!($type), "::splat(*target)));")]
+            /// }
+            /// assert_eq!(counts.to_array(), [2, 0, 2, 1]);
             /// ```
+            #[inline]
+            pub fn incr_where(self, mask: crate::$mask<LANES>) -> Self {
+                self - Self::from_mask_int(mask)
+            }
+
+            /// Subtracts one from each lane for which `mask` is set, wrapping on overflow.
             ///
-            /// `select` can also be used on masks:
             /// ```
-            /// # use core_simd::Mask32;
-            /// let a = Mask32::from_array([true, true, false, false]);
-            /// let b = Mask32::from_array([false, false, true, true]);
-            /// let mask = Mask32::from_array([true, false, false, true]);
-            /// let c = mask.select(a, b);
-            /// assert_eq!(c.to_array(), [true, false, true, false]);
+            /// # use core_simd::*;
+            #[doc = concat!("let counts = ", stringify!($type), "::from_array([2, 0, 2, 1]);")]
+            #[doc = concat!("let mask = ", stringify!($mask), "::from_array([true, false, false, true]);")]
+            /// assert_eq!(counts.decr_where(mask).to_array(), [1, 0, 2, 0]);
             /// ```
             #[inline]
-            pub fn select<S: Select<Self>>(self, true_values: S, false_values: S) -> S {
-                S::select(self, true_values, false_values)
+            pub fn decr_where(self, mask: crate::$mask<LANES>) -> Self {
+                self + Self::from_mask_int(mask)
             }
         }
+        )*
     }
 }
 
@@ -87,3 +157,105 @@ impl_select! { Mask16 (SimdI16): SimdU16, SimdI16 }
 impl_select! { Mask32 (SimdI32): SimdU32, SimdI32, SimdF32}
 impl_select! { Mask64 (SimdI64): SimdU64, SimdI64, SimdF64}
 impl_select! { MaskSize (SimdIsize): SimdUsize, SimdIsize }
+
+impl<T, const LANES: usize> crate::Mask<T, LANES>
+where
+    T: crate::MaskElement,
+    crate::LaneCount<LANES>: crate::SupportedLaneCount,
+{
+    /// Choose lanes from two vectors.
+    ///
+    /// For each lane in the mask, choose the corresponding lane from `true_values` if
+    /// that lane mask is true, and `false_values` if that lane mask is false.
+    ///
+    /// ```
+    /// # use core_simd::{Mask32, SimdI32};
+    /// let a = SimdI32::from_array([0, 1, 2, 3]);
+    /// let b = SimdI32::from_array([4, 5, 6, 7]);
+    /// let mask = Mask32::from_array([true, false, false, true]);
+    /// let c = mask.select(a, b);
+    /// assert_eq!(c.to_array(), [0, 5, 6, 3]);
+    /// ```
+    ///
+    /// `select` can also be used on masks:
+    /// ```
+    /// # use core_simd::Mask32;
+    /// let a = Mask32::from_array([true, true, false, false]);
+    /// let b = Mask32::from_array([false, false, true, true]);
+    /// let mask = Mask32::from_array([true, false, false, true]);
+    /// let c = mask.select(a, b);
+    /// assert_eq!(c.to_array(), [true, false, true, false]);
+    /// ```
+    #[inline]
+    pub fn select<S: Select<Self>>(self, true_values: S, false_values: S) -> S {
+        S::select(self, true_values, false_values)
+    }
+
+    /// Choose lanes from a vector, or a scalar value.
+    ///
+    /// For each lane in the mask, choose the corresponding lane from `true_values` if
+    /// that lane mask is true, and `false_value` if that lane mask is false.
+    ///
+    /// ```
+    /// # use core_simd::{Mask32, SimdF32};
+    /// let a = SimdF32::from_array([0., 1., 2., 3.]);
+    /// let mask = Mask32::from_array([true, false, false, true]);
+    /// assert_eq!(mask.select_or(a, -1.).to_array(), [0., -1., -1., 3.]);
+    /// ```
+    #[inline]
+    pub fn select_or<S>(self, true_values: S, false_value: S::Scalar) -> S
+    where
+        S: Select<Self> + crate::Vector,
+    {
+        S::select(self, true_values, S::splat(false_value))
+    }
+
+    /// Choose lanes from a vector, or the default value of its scalar type (zero).
+    ///
+    /// For each lane in the mask, choose the corresponding lane from `true_values` if
+    /// that lane mask is true, and zero if that lane mask is false.
+    ///
+    /// ```
+    /// # use core_simd::{Mask32, SimdI32};
+    /// let a = SimdI32::from_array([4, 5, 6, 7]);
+    /// let mask = Mask32::from_array([true, false, false, true]);
+    /// assert_eq!(mask.select_or_default(a).to_array(), [4, 0, 0, 7]);
+    /// ```
+    #[inline]
+    pub fn select_or_default<S>(self, true_values: S) -> S
+    where
+        S: Select<Self> + crate::Vector,
+        S::Scalar: Default,
+    {
+        self.select_or(true_values, Default::default())
+    }
+
+    /// Sums the lanes of a vector for which the mask is set.
+    ///
+    /// This is the dot product of `values` with the mask as a vector of ones and zeros.
+    /// The zeroed lanes are folded into the reduction, so this compiles to masked
+    /// additions where the target supports them.  Integer addition wraps.
+    ///
+    /// ```
+    /// # use core_simd::{Mask32, SimdF32, SimdU32};
+    /// let mask = Mask32::from_array([true, false, true, true]);
+    /// assert_eq!(mask.dot(SimdF32::from_array([1., 2., 4., 8.])), 13.);
+    /// assert_eq!(mask.dot(SimdU32::from_array([1, 2, 3, u32::MAX])), 3);
+    /// ```
+    #[inline]
+    pub fn dot<S>(self, values: S) -> S::Scalar
+    where
+        S: Select<Self> + crate::SimdReduce + Default,
+    {
+        crate::SimdReduce::reduce_sum(S::select(self, values, S::default()))
+    }
+}
+
+impl_bitwise_select! { SimdU8, SimdU16, SimdU32, SimdU64, SimdUsize }
+impl_bitwise_select! { SimdI8, SimdI16, SimdI32, SimdI64, SimdIsize }
+
+impl_count_where! { Mask8 (SimdI8): SimdU8, SimdI8 }
+impl_count_where! { Mask16 (SimdI16): SimdU16, SimdI16 }
+impl_count_where! { Mask32 (SimdI32): SimdU32, SimdI32 }
+impl_count_where! { Mask64 (SimdI64): SimdU64, SimdI64 }
+impl_count_where! { MaskSize (SimdIsize): SimdUsize, SimdIsize }
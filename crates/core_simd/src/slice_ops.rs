@@ -0,0 +1,620 @@
+//! Common operations over slices of scalars, accelerated with SIMD.
+//!
+//! These functions take care of splitting the slice into an aligned body that is processed a
+//! vector at a time and an unaligned head and tail that are processed one element at a time.
+
+use crate::*;
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// The number of independent accumulators used when folding vectors.
+///
+/// Using several accumulators breaks the dependency chain between consecutive vector
+/// operations, hiding their latency.
+const UNROLL: usize = 4;
+
+/// Folds a slice of vectors with `UNROLL` independent accumulators, returning `None` if the
+/// slice is empty.
+///
+/// Each vector is converted to an accumulator with `lift`, and the accumulators are combined with
+/// `op`.
+#[inline]
+fn fold_vectors<V, A>(vectors: &[V], lift: impl Fn(V) -> A, op: impl Fn(A, A) -> A) -> Option<A>
+where
+    V: Copy,
+    A: Copy,
+{
+    let mut chunks = vectors.chunks_exact(UNROLL);
+    let mut accumulators: Option<[A; UNROLL]> = None;
+    for chunk in &mut chunks {
+        let mut lifted = [lift(chunk[0]); UNROLL];
+        for (acc, v) in lifted.iter_mut().zip(chunk).skip(1) {
+            *acc = lift(*v);
+        }
+        accumulators = Some(match accumulators {
+            Some(mut accumulators) => {
+                for (acc, v) in accumulators.iter_mut().zip(lifted.iter()) {
+                    *acc = op(*acc, *v);
+                }
+                accumulators
+            }
+            None => lifted,
+        });
+    }
+
+    let mut vector = accumulators.map(|a| a.iter().copied().reduce(&op).unwrap());
+    for v in chunks.remainder() {
+        vector = Some(match vector {
+            Some(acc) => op(acc, lift(*v)),
+            None => lift(*v),
+        });
+    }
+    vector
+}
+
+/// Folds a slice a vector at a time, returning `None` if the slice is empty.
+///
+/// Each aligned vector of type `V` in the body of the slice is converted to an accumulator with
+/// `lift` and combined with `op`, which is then converted to the result with `reduce`.  The
+/// unaligned elements at the head and tail of the slice are converted to results with
+/// `lift_scalar` and combined with `op_scalar`.
+#[inline]
+fn fold<T, V, A, R>(
+    slice: &[T],
+    lift: impl Fn(V) -> A,
+    op: impl Fn(A, A) -> A,
+    reduce: impl Fn(A) -> R,
+    lift_scalar: impl Fn(T) -> R,
+    op_scalar: impl Fn(R, R) -> R,
+) -> Option<R>
+where
+    T: Copy,
+    V: Copy,
+    A: Copy,
+{
+    // SAFETY: every bit pattern is valid for both the scalars and vectors used in this module
+    let (head, body, tail) = unsafe { slice.align_to::<V>() };
+
+    let vector = fold_vectors(body, lift, op);
+
+    head.iter()
+        .chain(tail)
+        .copied()
+        .map(lift_scalar)
+        .fold(vector.map(reduce), |acc, x| match acc {
+            Some(acc) => Some(op_scalar(acc, x)),
+            None => Some(x),
+        })
+}
+
+/// Supporting trait for the reductions in this module.
+pub trait SliceReduce: Sealed + SimdElement + Copy + Sized {
+    #[doc(hidden)]
+    fn sum_impl(slice: &[Self]) -> Self;
+
+    #[doc(hidden)]
+    fn min_impl(slice: &[Self]) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn max_impl(slice: &[Self]) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn min_max_impl(slice: &[Self]) -> Option<(Self, Self)>;
+
+    #[doc(hidden)]
+    fn sum_vectors_impl<const LANES: usize>(vectors: &[Simd<Self, LANES>]) -> Self
+    where
+        LaneCount<LANES>: SupportedLaneCount;
+
+    #[doc(hidden)]
+    fn min_vectors_impl<const LANES: usize>(vectors: &[Simd<Self, LANES>]) -> Option<Self>
+    where
+        LaneCount<LANES>: SupportedLaneCount;
+
+    #[doc(hidden)]
+    fn max_vectors_impl<const LANES: usize>(vectors: &[Simd<Self, LANES>]) -> Option<Self>
+    where
+        LaneCount<LANES>: SupportedLaneCount;
+}
+
+/// Lane-wise minimum and maximum of two vectors.
+trait LaneMinMax: Copy {
+    fn lane_min(self, other: Self) -> Self;
+    fn lane_max(self, other: Self) -> Self;
+}
+
+macro_rules! impl_slice_reduce {
+    { $scalar:ident, $vector:ident<$lanes:literal>, $zero:literal, $scalar_add:expr } => {
+        impl Sealed for $scalar {}
+
+        impl SliceReduce for $scalar {
+            #[inline]
+            fn sum_impl(slice: &[Self]) -> Self {
+                fold(
+                    slice,
+                    |v: $vector<$lanes>| v,
+                    core::ops::Add::add,
+                    $vector::horizontal_sum,
+                    |x| x,
+                    $scalar_add,
+                ).unwrap_or($zero)
+            }
+
+            #[inline]
+            fn min_impl(slice: &[Self]) -> Option<Self> {
+                fold(
+                    slice,
+                    |v: $vector<$lanes>| v,
+                    LaneMinMax::lane_min,
+                    $vector::horizontal_min,
+                    |x| x,
+                    $scalar::min,
+                )
+            }
+
+            #[inline]
+            fn max_impl(slice: &[Self]) -> Option<Self> {
+                fold(
+                    slice,
+                    |v: $vector<$lanes>| v,
+                    LaneMinMax::lane_max,
+                    $vector::horizontal_max,
+                    |x| x,
+                    $scalar::max,
+                )
+            }
+
+            #[inline]
+            fn min_max_impl(slice: &[Self]) -> Option<(Self, Self)> {
+                fold(
+                    slice,
+                    |v: $vector<$lanes>| (v, v),
+                    |(a_min, a_max), (b_min, b_max)| (a_min.lane_min(b_min), a_max.lane_max(b_max)),
+                    |(min, max)| (min.horizontal_min(), max.horizontal_max()),
+                    |x| (x, x),
+                    |(a_min, a_max), (b_min, b_max)| (a_min.min(b_min), a_max.max(b_max)),
+                )
+            }
+
+            #[inline]
+            fn sum_vectors_impl<const LANES: usize>(vectors: &[Simd<Self, LANES>]) -> Self
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                fold_vectors(vectors, |v| v, core::ops::Add::add).map_or($zero, $vector::horizontal_sum)
+            }
+
+            #[inline]
+            fn min_vectors_impl<const LANES: usize>(vectors: &[Simd<Self, LANES>]) -> Option<Self>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                fold_vectors(vectors, |v| v, LaneMinMax::lane_min).map($vector::horizontal_min)
+            }
+
+            #[inline]
+            fn max_vectors_impl<const LANES: usize>(vectors: &[Simd<Self, LANES>]) -> Option<Self>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                fold_vectors(vectors, |v| v, LaneMinMax::lane_max).map($vector::horizontal_max)
+            }
+        }
+    };
+    { int: $($scalar:ident => $vector:ident<$lanes:literal>,)* } => {
+        $(
+        impl<const LANES: usize> LaneMinMax for $vector<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn lane_min(self, other: Self) -> Self {
+                self.lanes_lt(other).select(self, other)
+            }
+
+            #[inline]
+            fn lane_max(self, other: Self) -> Self {
+                self.lanes_gt(other).select(self, other)
+            }
+        }
+
+        impl_slice_reduce! { $scalar, $vector<$lanes>, 0, $scalar::wrapping_add }
+        )*
+    };
+    { float: $($scalar:ident => $vector:ident<$lanes:literal>,)* } => {
+        $(
+        impl<const LANES: usize> LaneMinMax for $vector<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn lane_min(self, other: Self) -> Self {
+                self.min(other)
+            }
+
+            #[inline]
+            fn lane_max(self, other: Self) -> Self {
+                self.max(other)
+            }
+        }
+
+        impl_slice_reduce! { $scalar, $vector<$lanes>, 0., core::ops::Add::add }
+        )*
+    };
+}
+
+impl_slice_reduce! {
+    int:
+        u8 => SimdU8<32>,
+        u16 => SimdU16<16>,
+        u32 => SimdU32<8>,
+        u64 => SimdU64<4>,
+        i8 => SimdI8<32>,
+        i16 => SimdI16<16>,
+        i32 => SimdI32<8>,
+        i64 => SimdI64<4>,
+}
+
+impl_slice_reduce! {
+    float:
+        f32 => SimdF32<8>,
+        f64 => SimdF64<4>,
+}
+
+/// Supporting trait for [`dot`].
+pub trait SliceDot: Sealed + Copy + Sized {
+    #[doc(hidden)]
+    fn dot_impl(a: &[Self], b: &[Self]) -> Self;
+}
+
+macro_rules! impl_slice_dot {
+    { $($scalar:ident => $vector:ident<$lanes:literal>,)* } => {
+        $(
+        impl SliceDot for $scalar {
+            #[inline]
+            fn dot_impl(a: &[Self], b: &[Self]) -> Self {
+                assert_eq!(a.len(), b.len(), "slices must have the same length");
+
+                // The slices generally can't both be aligned, so use unaligned loads
+                #[inline]
+                fn load(slice: &[$scalar]) -> $vector<$lanes> {
+                    use core::convert::TryInto;
+                    $vector::from_array(slice[..$lanes].try_into().unwrap())
+                }
+
+                let mut accumulators = [$vector::<$lanes>::splat(0.); UNROLL];
+                let mut a_chunks = a.chunks_exact($lanes * UNROLL);
+                let mut b_chunks = b.chunks_exact($lanes * UNROLL);
+                for (a, b) in (&mut a_chunks).zip(&mut b_chunks) {
+                    for (i, acc) in accumulators.iter_mut().enumerate() {
+                        let offset = i * $lanes;
+                        *acc = load(&a[offset..]).mul_add(load(&b[offset..]), *acc);
+                    }
+                }
+
+                let mut a_chunks = a_chunks.remainder().chunks_exact($lanes);
+                let mut b_chunks = b_chunks.remainder().chunks_exact($lanes);
+                for (a, b) in (&mut a_chunks).zip(&mut b_chunks) {
+                    accumulators[0] = load(a).mul_add(load(b), accumulators[0]);
+                }
+
+                let sum = accumulators.iter().copied().sum::<$vector<$lanes>>().horizontal_sum();
+                a_chunks
+                    .remainder()
+                    .iter()
+                    .zip(b_chunks.remainder())
+                    .fold(sum, |acc, (a, b)| acc + a * b)
+            }
+        }
+        )*
+    }
+}
+
+impl_slice_dot! {
+    f32 => SimdF32<8>,
+    f64 => SimdF64<4>,
+}
+
+/// Returns the index of the first mismatched byte in the common prefix of two slices.
+#[inline]
+fn mismatch(a: &[u8], b: &[u8]) -> Option<usize> {
+    use core::convert::TryInto;
+    const LANES: usize = 32;
+
+    let len = a.len().min(b.len());
+    let mut a_chunks = a[..len].chunks_exact(LANES);
+    let mut b_chunks = b[..len].chunks_exact(LANES);
+    for (i, (a, b)) in (&mut a_chunks).zip(&mut b_chunks).enumerate() {
+        let a = SimdU8::<LANES>::from_array(a.try_into().unwrap());
+        let b = SimdU8::<LANES>::from_array(b.try_into().unwrap());
+        let ne = a.lanes_ne(b);
+        if ne.any() {
+            // the lowest set bit of the bitmask is the first mismatched lane
+            let bitmask = u32::from_le_bytes(ne.to_bitmask());
+            return Some(i * LANES + bitmask.trailing_zeros() as usize);
+        }
+    }
+
+    let a_tail = a_chunks.remainder();
+    a_tail
+        .iter()
+        .zip(b_chunks.remainder())
+        .position(|(a, b)| a != b)
+        .map(|i| len - a_tail.len() + i)
+}
+
+/// The number of bytes searched at a time by [`position`].
+const SEARCH_LANES: usize = 32;
+
+/// Returns the index of the first lane set in `mask` among the first `len` lanes, where `len` is
+/// nonzero.
+#[inline]
+fn first_set(mask: Mask8<SEARCH_LANES>, len: usize) -> Option<usize> {
+    // the lowest set bit of the bitmask is the first set lane
+    let bitmask = u32::from_le_bytes(mask.to_bitmask());
+    let valid = u32::MAX >> (SEARCH_LANES - len);
+    match bitmask & valid {
+        0 => None,
+        bitmask => Some(bitmask.trailing_zeros() as usize),
+    }
+}
+
+/// Searches a chunk shorter than a vector by padding it with zeros, ignoring the padding lanes.
+#[inline]
+fn position_partial(
+    chunk: &[u8],
+    pred: &mut impl FnMut(SimdU8<SEARCH_LANES>) -> Mask8<SEARCH_LANES>,
+) -> Option<usize> {
+    if chunk.is_empty() {
+        return None;
+    }
+    let mut padded = [0; SEARCH_LANES];
+    padded[..chunk.len()].copy_from_slice(chunk);
+    first_set(pred(SimdU8::from_array(padded)), chunk.len())
+}
+
+/// Returns the sum of the elements of the slice.
+///
+/// Integer sums use wrapping addition.  Floating point sums are computed in an unspecified
+/// order, so the result may differ slightly from summing the elements sequentially.
+///
+/// ```
+/// # use core_simd::slice_ops;
+/// let values: Vec<i32> = (1..=100).collect();
+/// assert_eq!(slice_ops::sum(&values), 5050);
+/// assert_eq!(slice_ops::sum::<f32>(&[]), 0.);
+/// ```
+#[inline]
+pub fn sum<T: SliceReduce>(slice: &[T]) -> T {
+    T::sum_impl(slice)
+}
+
+/// Returns the minimum element of the slice, or `None` if the slice is empty.
+///
+/// For floating point slices, `NaN` is only returned if every element is `NaN`.
+///
+/// ```
+/// # use core_simd::slice_ops;
+/// let values: Vec<i32> = (-50..50).rev().collect();
+/// assert_eq!(slice_ops::min(&values), Some(-50));
+/// assert_eq!(slice_ops::min::<u8>(&[]), None);
+/// ```
+#[inline]
+pub fn min<T: SliceReduce>(slice: &[T]) -> Option<T> {
+    T::min_impl(slice)
+}
+
+/// Returns the maximum element of the slice, or `None` if the slice is empty.
+///
+/// For floating point slices, `NaN` is only returned if every element is `NaN`.
+///
+/// ```
+/// # use core_simd::slice_ops;
+/// let values: Vec<i32> = (-50..50).collect();
+/// assert_eq!(slice_ops::max(&values), Some(49));
+/// assert_eq!(slice_ops::max::<u8>(&[]), None);
+/// ```
+#[inline]
+pub fn max<T: SliceReduce>(slice: &[T]) -> Option<T> {
+    T::max_impl(slice)
+}
+
+/// Returns the minimum and maximum elements of the slice in a single pass, or `None` if the
+/// slice is empty.
+///
+/// For floating point slices, `NaN` is only returned if every element is `NaN`.
+///
+/// ```
+/// # use core_simd::slice_ops;
+/// let values = [3.0f32, -1.0, 7.5, 2.0];
+/// assert_eq!(slice_ops::min_max(&values), Some((-1.0, 7.5)));
+/// ```
+#[inline]
+pub fn min_max<T: SliceReduce>(slice: &[T]) -> Option<(T, T)> {
+    T::min_max_impl(slice)
+}
+
+/// Returns the sum of every lane of every vector in the slice.
+///
+/// The vectors are added into several independent accumulators, which are only summed across
+/// their lanes at the end.  Integer sums use wrapping addition.  Floating point sums are computed
+/// in an unspecified order, so the result may differ slightly from summing the lanes
+/// sequentially.
+///
+/// ```
+/// # use core_simd::{slice_ops, SimdI32};
+/// let vectors = [SimdI32::<4>::from_array([1, 2, 3, 4]), SimdI32::splat(10)];
+/// assert_eq!(slice_ops::sum_vectors(&vectors), 50);
+/// assert_eq!(slice_ops::sum_vectors::<f32, 8>(&[]), 0.);
+/// ```
+#[inline]
+pub fn sum_vectors<T, const LANES: usize>(vectors: &[Simd<T, LANES>]) -> T
+where
+    T: SliceReduce,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    T::sum_vectors_impl(vectors)
+}
+
+/// Returns the minimum lane of every vector in the slice, or `None` if the slice is empty.
+///
+/// For floating point vectors, `NaN` is only returned if every lane is `NaN`.
+///
+/// ```
+/// # use core_simd::{slice_ops, SimdF32};
+/// let vectors = [SimdF32::<2>::from_array([3., -1.]), SimdF32::from_array([0.5, 7.])];
+/// assert_eq!(slice_ops::min_vectors(&vectors), Some(-1.));
+/// assert_eq!(slice_ops::min_vectors::<u8, 4>(&[]), None);
+/// ```
+#[inline]
+pub fn min_vectors<T, const LANES: usize>(vectors: &[Simd<T, LANES>]) -> Option<T>
+where
+    T: SliceReduce,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    T::min_vectors_impl(vectors)
+}
+
+/// Returns the maximum lane of every vector in the slice, or `None` if the slice is empty.
+///
+/// For floating point vectors, `NaN` is only returned if every lane is `NaN`.
+///
+/// ```
+/// # use core_simd::{slice_ops, SimdF32};
+/// let vectors = [SimdF32::<2>::from_array([3., -1.]), SimdF32::from_array([0.5, 7.])];
+/// assert_eq!(slice_ops::max_vectors(&vectors), Some(7.));
+/// assert_eq!(slice_ops::max_vectors::<u8, 4>(&[]), None);
+/// ```
+#[inline]
+pub fn max_vectors<T, const LANES: usize>(vectors: &[Simd<T, LANES>]) -> Option<T>
+where
+    T: SliceReduce,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    T::max_vectors_impl(vectors)
+}
+
+/// Returns the dot product of two slices.
+///
+/// The products are accumulated with fused multiply-adds into several independent accumulators,
+/// so the result may differ slightly from computing the dot product sequentially.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+///
+/// ```
+/// # use core_simd::slice_ops;
+/// let a = [1.0f32, 2.0, 3.0];
+/// let b = [4.0f32, 5.0, 6.0];
+/// assert_eq!(slice_ops::dot(&a, &b), 32.0);
+/// ```
+#[inline]
+pub fn dot<T: SliceDot>(a: &[T], b: &[T]) -> T {
+    T::dot_impl(a, b)
+}
+
+/// Returns `true` if two byte slices are equal.
+///
+/// ```
+/// # use core_simd::slice_ops;
+/// assert!(slice_ops::eq(b"hello, world", b"hello, world"));
+/// assert!(!slice_ops::eq(b"hello, world", b"hello, World"));
+/// assert!(!slice_ops::eq(b"hello", b"hello, world"));
+/// ```
+#[inline]
+pub fn eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && mismatch(a, b).is_none()
+}
+
+/// Lexicographically compares two byte slices, like [`Ord::cmp`] and `memcmp`.
+///
+/// ```
+/// # use core_simd::slice_ops;
+/// # use core::cmp::Ordering;
+/// assert_eq!(slice_ops::cmp(b"apple", b"apricot"), Ordering::Less);
+/// assert_eq!(slice_ops::cmp(b"apple", b"app"), Ordering::Greater);
+/// assert_eq!(slice_ops::cmp(b"apple", b"apple"), Ordering::Equal);
+/// ```
+#[inline]
+pub fn cmp(a: &[u8], b: &[u8]) -> core::cmp::Ordering {
+    match mismatch(a, b) {
+        Some(i) => a[i].cmp(&b[i]),
+        None => a.len().cmp(&b.len()),
+    }
+}
+
+/// Returns the index of the first byte for which `pred` sets the corresponding lane, or `None` if
+/// there is no such byte.
+///
+/// The slice is searched a vector of 32 bytes at a time, stopping at the first vector with a lane
+/// set.  The unaligned bytes at the head and tail of the slice are zero-padded to a full vector
+/// before calling `pred`, and lanes in the padding are ignored.
+///
+/// ```
+/// # use core_simd::{slice_ops, SimdU8};
+/// let haystack = b"the quick brown fox jumps over the lazy dog";
+/// let position = slice_ops::position(haystack, |v| v.lanes_eq(SimdU8::splat(b'z')));
+/// assert_eq!(position, Some(37));
+///
+/// // find the first byte that isn't ASCII
+/// let text = "naïve".as_bytes();
+/// assert_eq!(slice_ops::position(text, |v| v.lanes_ge(SimdU8::splat(0x80))), Some(2));
+/// ```
+#[inline]
+pub fn position<F>(haystack: &[u8], mut pred: F) -> Option<usize>
+where
+    F: FnMut(SimdU8<SEARCH_LANES>) -> Mask8<SEARCH_LANES>,
+{
+    // SAFETY: every bit pattern is valid for both the scalars and vectors used in this module
+    let (head, body, tail) = unsafe { haystack.align_to::<SimdU8<SEARCH_LANES>>() };
+
+    if let Some(i) = position_partial(head, &mut pred) {
+        return Some(i);
+    }
+    for (i, v) in body.iter().enumerate() {
+        if let Some(lane) = first_set(pred(*v), SEARCH_LANES) {
+            return Some(head.len() + i * SEARCH_LANES + lane);
+        }
+    }
+    position_partial(tail, &mut pred).map(|i| haystack.len() - tail.len() + i)
+}
+
+/// Transforms each element of the slice in place, a vector at a time.
+///
+/// The aligned body of the slice is transformed a vector at a time with `vector`, and the
+/// unaligned elements at the head and tail of the slice are transformed one element at a time
+/// with `scalar`.  The two closures should compute the same function.
+///
+/// # Panics
+/// Panics if the number of lanes in `V` isn't a power of two, since such vectors are padded.
+///
+/// ```
+/// # use core_simd::{slice_ops, SimdF32};
+/// let mut values: Vec<f32> = (0..100).map(|x| x as f32).collect();
+/// slice_ops::map_in_place(&mut values, |v: SimdF32<8>| v * 0.5 + 1., |x| x * 0.5 + 1.);
+/// assert!(values.iter().enumerate().all(|(i, x)| *x == i as f32 * 0.5 + 1.));
+/// ```
+#[inline]
+#[track_caller]
+pub fn map_in_place<V, F, G>(slice: &mut [V::Scalar], mut vector: F, mut scalar: G)
+where
+    V: Vector,
+    F: FnMut(V) -> V,
+    G: FnMut(V::Scalar) -> V::Scalar,
+{
+    assert!(
+        V::LANES.is_power_of_two(),
+        "vector must have a power-of-two number of lanes"
+    );
+    // SAFETY: every bit pattern is valid for both the scalars and vectors used in this module
+    let (head, body, tail) = unsafe { slice.align_to_mut::<V>() };
+    for x in head.iter_mut().chain(tail.iter_mut()) {
+        *x = scalar(*x);
+    }
+    for v in body {
+        *v = vector(*v);
+    }
+}
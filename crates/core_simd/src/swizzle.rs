@@ -0,0 +1,65 @@
+/// Rearranges the lanes of one or two vectors, using either a list of lane indices or a named
+/// pattern.
+///
+/// With a list of indices, each lane of the result is the lane of the input with that index.  When
+/// two vectors are given, lanes of the second vector are indexed starting at `LANES`.  The
+/// indices must be constant, and there must be exactly `LANES` of them:
+///
+/// ```
+/// # use core_simd::*;
+/// let a = SimdU32::from_array([0, 1, 2, 3]);
+/// let b = SimdU32::from_array([4, 5, 6, 7]);
+/// assert_eq!(simd_swizzle!(a, [3, 3, 0, 1]).to_array(), [3, 3, 0, 1]);
+/// assert_eq!(simd_swizzle!(a, b, [0, 4, 7, 2]).to_array(), [0, 4, 7, 2]);
+/// ```
+///
+/// The named patterns over one vector are:
+/// * `reverse`, which reverses the order of the lanes
+/// * `rotate<N>`, which rotates the lanes left by `N`
+/// * `broadcast<N>`, which copies lane `N` into every lane
+///
+/// ```
+/// # use core_simd::*;
+/// let a = SimdU32::from_array([0, 1, 2, 3]);
+/// assert_eq!(simd_swizzle!(a, reverse).to_array(), [3, 2, 1, 0]);
+/// assert_eq!(simd_swizzle!(a, rotate<1>).to_array(), [1, 2, 3, 0]);
+/// assert_eq!(simd_swizzle!(a, broadcast<2>).to_array(), [2, 2, 2, 2]);
+/// ```
+///
+/// The named patterns over two vectors are `interleave_lo` and `interleave_hi`, which alternate
+/// lanes from the first and last halves of the vectors respectively:
+///
+/// ```
+/// # use core_simd::*;
+/// let a = SimdU32::from_array([0, 1, 2, 3]);
+/// let b = SimdU32::from_array([4, 5, 6, 7]);
+/// assert_eq!(simd_swizzle!(a, b, interleave_lo).to_array(), [0, 4, 1, 5]);
+/// assert_eq!(simd_swizzle!(a, b, interleave_hi).to_array(), [2, 6, 3, 7]);
+/// ```
+#[macro_export]
+macro_rules! simd_swizzle {
+    { $vector:expr, reverse } => {
+        $vector.reverse()
+    };
+    { $vector:expr, rotate<$offset:literal> } => {
+        $vector.rotate_lanes_left::<$offset>()
+    };
+    { $vector:expr, broadcast<$lane:literal> } => {
+        $vector.broadcast_lane::<$lane>()
+    };
+    { $vector:expr, [$($index:expr),* $(,)?] } => {
+        {
+            let vector = $vector;
+            vector.shuffle::<{ [$($index),*] }>(vector)
+        }
+    };
+    { $first:expr, $second:expr, interleave_lo } => {
+        $first.interleave($second).0
+    };
+    { $first:expr, $second:expr, interleave_hi } => {
+        $first.interleave($second).1
+    };
+    { $first:expr, $second:expr, [$($index:expr),* $(,)?] } => {
+        $first.shuffle::<{ [$($index),*] }>($second)
+    };
+}
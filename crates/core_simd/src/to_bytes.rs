@@ -18,12 +18,8 @@ pub trait ToBytes: Sealed {
 macro_rules! impl_to_bytes {
     { $name:ident, $($int_width:literal -> $byte_width:literal),* } => {
         $(
-        impl Sealed for crate::$name<$int_width> where Self: crate::LanesAtMost32 {}
-        impl ToBytes for crate::$name<$int_width>
-        where
-            Self: crate::LanesAtMost32,
-            crate::SimdU8<$byte_width>: crate::LanesAtMost32,
-        {
+        impl Sealed for crate::$name<$int_width> {}
+        impl ToBytes for crate::$name<$int_width> {
             type Bytes = crate::SimdU8<$byte_width>;
             fn to_bytes_impl(self) -> Self::Bytes {
                 unsafe { core::mem::transmute(self) }
@@ -32,11 +28,26 @@ macro_rules! impl_to_bytes {
                 unsafe { core::mem::transmute(bytes) }
             }
         }
+
+        impl crate::$name<$int_width> {
+            /// Reverses the order of the bytes of the entire vector.
+            ///
+            /// This reverses both the order of the lanes and the order of the bytes within each
+            /// lane, unlike [`reverse`](Self::reverse), which only reverses the order of the
+            /// lanes.
+            #[inline]
+            pub fn reverse_bytes(self) -> Self {
+                let mut bytes = self.to_bytes_impl().to_array();
+                bytes.reverse();
+                Self::from_bytes_impl(crate::SimdU8::from_array(bytes))
+            }
+        }
         )*
 
         impl<const LANES: usize> crate::$name<LANES>
         where
-            Self: ToBytes + crate::LanesAtMost32,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+            Self: ToBytes,
         {
             /// Return the memory representation of this integer as a byte array in native byte
             /// order.
@@ -49,20 +60,183 @@ macro_rules! impl_to_bytes {
     }
 }
 
-impl_to_bytes! { SimdU8, 1 -> 1, 2 -> 2, 4 -> 4, 8 -> 8, 16 -> 16, 32 -> 32 }
-impl_to_bytes! { SimdU16, 1 -> 2, 2 -> 4, 4 -> 8, 8 -> 16, 16 -> 32 }
-impl_to_bytes! { SimdU32, 1 -> 4, 2 -> 8, 4 -> 16, 8 -> 32 }
-impl_to_bytes! { SimdU64, 1 -> 8, 2 -> 16, 4 -> 32 }
+impl_to_bytes! { SimdU8, 1 -> 1, 2 -> 2, 4 -> 4, 8 -> 8, 16 -> 16, 32 -> 32, 64 -> 64 }
+impl_to_bytes! { SimdU16, 1 -> 2, 2 -> 4, 4 -> 8, 8 -> 16, 16 -> 32, 32 -> 64 }
+impl_to_bytes! { SimdU32, 1 -> 4, 2 -> 8, 4 -> 16, 8 -> 32, 16 -> 64 }
+impl_to_bytes! { SimdU64, 1 -> 8, 2 -> 16, 4 -> 32, 8 -> 64 }
 #[cfg(target_pointer_width = "32")]
-impl_to_bytes! { SimdUsize, 1 -> 4, 2 -> 8, 4 -> 16, 8 -> 32 }
+impl_to_bytes! { SimdUsize, 1 -> 4, 2 -> 8, 4 -> 16, 8 -> 32, 16 -> 64 }
 #[cfg(target_pointer_width = "64")]
-impl_to_bytes! { SimdUsize, 1 -> 8, 2 -> 16, 4 -> 32 }
+impl_to_bytes! { SimdUsize, 1 -> 8, 2 -> 16, 4 -> 32, 8 -> 64 }
 
-impl_to_bytes! { SimdI8, 1 -> 1, 2 -> 2, 4 -> 4, 8 -> 8, 16 -> 16, 32 -> 32 }
-impl_to_bytes! { SimdI16, 1 -> 2, 2 -> 4, 4 -> 8, 8 -> 16, 16 -> 32 }
-impl_to_bytes! { SimdI32, 1 -> 4, 2 -> 8, 4 -> 16, 8 -> 32 }
-impl_to_bytes! { SimdI64, 1 -> 8, 2 -> 16, 4 -> 32 }
+impl_to_bytes! { SimdI8, 1 -> 1, 2 -> 2, 4 -> 4, 8 -> 8, 16 -> 16, 32 -> 32, 64 -> 64 }
+impl_to_bytes! { SimdI16, 1 -> 2, 2 -> 4, 4 -> 8, 8 -> 16, 16 -> 32, 32 -> 64 }
+impl_to_bytes! { SimdI32, 1 -> 4, 2 -> 8, 4 -> 16, 8 -> 32, 16 -> 64 }
+impl_to_bytes! { SimdI64, 1 -> 8, 2 -> 16, 4 -> 32, 8 -> 64 }
 #[cfg(target_pointer_width = "32")]
-impl_to_bytes! { SimdIsize, 1 -> 4, 2 -> 8, 4 -> 16, 8 -> 32 }
+impl_to_bytes! { SimdIsize, 1 -> 4, 2 -> 8, 4 -> 16, 8 -> 32, 16 -> 64 }
 #[cfg(target_pointer_width = "64")]
-impl_to_bytes! { SimdIsize, 1 -> 8, 2 -> 16, 4 -> 32 }
+impl_to_bytes! { SimdIsize, 1 -> 8, 2 -> 16, 4 -> 32, 8 -> 64 }
+
+/// Supporting trait for [`SimdU8::reverse_byte_groups`](crate::SimdU8::reverse_byte_groups).
+///
+/// Implemented for each group size supported by a vector: 2, 4, or 8 bytes, and at most the
+/// number of lanes.
+pub trait ReverseByteGroups<const GROUP: usize>: Sealed {
+    #[doc(hidden)]
+    fn reverse_byte_groups_impl(self) -> Self;
+}
+
+impl<const LANES: usize> crate::SimdU8<LANES>
+where
+    crate::LaneCount<LANES>: crate::SupportedLaneCount,
+{
+    /// Reverses the order of the bytes within each aligned group of `GROUP` bytes.
+    ///
+    /// This swaps the bytes of each `GROUP`-byte integer packed into the vector, such as when
+    /// converting the fields of a big-endian record to native byte order.
+    ///
+    /// `GROUP` must be 2, 4, or 8, and at most the number of lanes:
+    /// ```
+    /// # use core_simd::*;
+    /// let bytes = SimdU8::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    /// assert_eq!(bytes.reverse_byte_groups::<2>().to_array(), [1, 0, 3, 2, 5, 4, 7, 6]);
+    /// assert_eq!(bytes.reverse_byte_groups::<4>().to_array(), [3, 2, 1, 0, 7, 6, 5, 4]);
+    /// assert_eq!(bytes.reverse_byte_groups::<8>().to_array(), [7, 6, 5, 4, 3, 2, 1, 0]);
+    /// ```
+    ///
+    /// Other group sizes don't compile:
+    /// ```compile_fail
+    /// # use core_simd::*;
+    /// let _ = SimdU8::<4>::splat(0).reverse_byte_groups::<8>();
+    /// ```
+    #[inline]
+    pub fn reverse_byte_groups<const GROUP: usize>(self) -> Self
+    where
+        Self: ReverseByteGroups<GROUP>,
+    {
+        <Self as ReverseByteGroups<GROUP>>::reverse_byte_groups_impl(self)
+    }
+}
+
+/// Returns the shuffle indices reversing each group of `group` lanes of an `N`-lane vector.
+const fn reverse_byte_groups_idx<const N: usize>(group: usize) -> [u32; N] {
+    let mut idx = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        idx[i] = (i - i % group + (group - 1 - i % group)) as u32;
+        i += 1;
+    }
+    idx
+}
+
+macro_rules! impl_reverse_byte_groups {
+    { $($n:literal => $($group:literal),*;)* } => {
+        $(
+        $(
+        impl ReverseByteGroups<$group> for crate::SimdU8<$n> {
+            #[inline]
+            fn reverse_byte_groups_impl(self) -> Self {
+                self.shuffle::<{ reverse_byte_groups_idx::<$n>($group) }>(self)
+            }
+        }
+        )*
+        )*
+    }
+}
+
+impl_reverse_byte_groups! {
+    2 => 2;
+    4 => 2, 4;
+    8 => 2, 4, 8;
+    16 => 2, 4, 8;
+    32 => 2, 4, 8;
+    64 => 2, 4, 8;
+}
+
+macro_rules! impl_endian_slice {
+    { $($name:ident, $int:ty;)* } => {
+        $(
+        impl<const LANES: usize> crate::$name<LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            /// Loads a vector from the first `LANES` integers of `slice`, stored in native byte
+            /// order.  The slice doesn't need to be aligned.
+            ///
+            /// # Panics
+            #[doc = concat!("Panics if `slice` is shorter than `LANES * size_of::<", stringify!($int), ">()` bytes.")]
+            #[inline]
+            #[track_caller]
+            pub fn from_ne_slice(slice: &[u8]) -> Self {
+                assert!(
+                    slice.len() >= LANES * core::mem::size_of::<$int>(),
+                    "slice length must be at least the size of the vector"
+                );
+                // SAFETY: the slice is long enough, and any bytes are a valid integer.  The slice
+                // is read as an array, since the vector may be larger than its lanes.
+                Self::from_array(unsafe {
+                    core::ptr::read_unaligned(slice.as_ptr() as *const [$int; LANES])
+                })
+            }
+
+            /// Loads a vector from the first `LANES` integers of `slice`, stored in little-endian
+            /// byte order.  The slice doesn't need to be aligned.
+            ///
+            /// # Panics
+            #[doc = concat!("Panics if `slice` is shorter than `LANES * size_of::<", stringify!($int), ">()` bytes.")]
+            ///
+            /// ```
+            /// # use core_simd::*;
+            /// let mut bytes = [0; 16];
+            /// bytes[0] = 1;
+            #[doc = concat!("assert_eq!(", stringify!($name), "::<2>::from_le_slice(&bytes).to_array(), [1, 0]);")]
+            /// ```
+            #[inline]
+            #[track_caller]
+            pub fn from_le_slice(slice: &[u8]) -> Self {
+                let vector = Self::from_ne_slice(slice);
+                if cfg!(target_endian = "little") {
+                    vector
+                } else {
+                    vector.map(<$int>::swap_bytes)
+                }
+            }
+
+            /// Loads a vector from the first `LANES` integers of `slice`, stored in big-endian
+            /// byte order.  The slice doesn't need to be aligned.
+            ///
+            /// This parses fields in network byte order without any unsafe code.
+            ///
+            /// # Panics
+            #[doc = concat!("Panics if `slice` is shorter than `LANES * size_of::<", stringify!($int), ">()` bytes.")]
+            ///
+            /// ```
+            /// # use core_simd::*;
+            /// let mut bytes = [0; 16];
+            #[doc = concat!("bytes[core::mem::size_of::<", stringify!($int), ">() - 1] = 1;")]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::<2>::from_be_slice(&bytes).to_array(), [1, 0]);")]
+            /// ```
+            #[inline]
+            #[track_caller]
+            pub fn from_be_slice(slice: &[u8]) -> Self {
+                let vector = Self::from_ne_slice(slice);
+                if cfg!(target_endian = "big") {
+                    vector
+                } else {
+                    vector.map(<$int>::swap_bytes)
+                }
+            }
+        }
+        )*
+    }
+}
+
+impl_endian_slice! {
+    SimdU16, u16;
+    SimdU32, u32;
+    SimdU64, u64;
+    SimdI16, i16;
+    SimdI32, i32;
+    SimdI64, i64;
+}
@@ -26,3 +26,41 @@ macro_rules! from_transmute_x86 {
         from_transmute! { unsafe $generic => core::arch::x86_64::$intel }
     }
 }
+
+/// Provides implementations of `From<$mask> for $int` and `TryFrom<$int> for $mask` that
+/// convert through the integer representation of the mask.
+///
+/// Converting from `$int` fails if any lane is not 0 or -1, like `try_from_int`.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+macro_rules! from_mask {
+    { $mask:ty => $int:ty } => {
+        impl core::convert::From<$mask> for $int {
+            #[inline]
+            fn from(value: $mask) -> $int {
+                value.to_int().into()
+            }
+        }
+
+        impl core::convert::TryFrom<$int> for $mask {
+            type Error = crate::InvalidMaskError;
+
+            #[inline]
+            fn try_from(value: $int) -> Result<$mask, Self::Error> {
+                <$mask>::try_from_int(value.into())
+            }
+        }
+    };
+}
+
+/// Provides implementations of `From<$mask> for core::arch::{x86, x86_64}::$intel` and
+/// `TryFrom` in the other direction, which fails unless each lane of the intel type is either 0
+/// or -1.
+macro_rules! from_mask_x86 {
+    { $mask:ty => $intel:ident } => {
+        #[cfg(target_arch = "x86")]
+        from_mask! { $mask => core::arch::x86::$intel }
+
+        #[cfg(target_arch = "x86_64")]
+        from_mask! { $mask => core::arch::x86_64::$intel }
+    }
+}
@@ -0,0 +1,187 @@
+//! Sine and cosine of `f32` vectors.
+//!
+//! Arguments are reduced to `r` in `[-pi/4, pi/4]` and a quadrant `n`, such that
+//! `x = n * pi/2 + r`, and the sine and cosine of `r` are evaluated with polynomials.  Both steps
+//! are computed in `f64`, which leaves enough precision for a result within 1 ULP.
+//!
+//! Most arguments are reduced with the Cody-Waite method, subtracting `n * pi/2` in two parts so
+//! that the first product is exact.  This loses accuracy once `n` no longer fits in the bits left
+//! over by the first part, so larger arguments are reduced with the Payne-Hanek method, which
+//! multiplies by only the bits of `2/pi` that affect the result.
+
+// the constants are written with every digit of their source
+#![allow(clippy::excessive_precision)]
+
+use crate::{LaneCount, SimdF32, SimdF64, SimdI64, SupportedLaneCount};
+
+/// Above this magnitude, arguments are reduced with Payne-Hanek.
+const CODY_WAITE_LIMIT: f64 = (1u64 << 28) as f64;
+
+/// Adding and subtracting this rounds an `f64` to an integer.
+const TO_INT: f64 = 1.5 / f64::EPSILON;
+
+const FRAC_2_PI: f64 = core::f64::consts::FRAC_2_PI;
+
+/// The first 25 bits of pi/2, so that `n * PIO2_HI` is exact for `n` below 2^28.
+const PIO2_HI: f64 = 1.570_796_310_901_641_845_7;
+
+/// pi/2 - `PIO2_HI`.
+const PIO2_LO: f64 = 1.589_325_477_352_819_669_16e-8;
+
+/// Below this magnitude, `sin(x)` rounds to `x` and `cos(x)` rounds to 1.
+const TINY: f64 = 1. / 4096.;
+
+/// Coefficients of the polynomial approximating `sin(r)` on `[-pi/4, pi/4]`, from musl.
+const SIN: [f64; 4] = [
+    -0.166_666_666_416_265_235_595,
+    0.008_333_329_385_889_463_175_6,
+    -0.000_198_393_348_360_966_317_347,
+    0.000_002_718_311_493_989_821_906_4,
+];
+
+/// Coefficients of the polynomial approximating `cos(r)` on `[-pi/4, pi/4]`, from musl.
+const COS: [f64; 4] = [
+    -0.499_999_997_251_031_003_120,
+    0.041_666_623_323_739_063_189_4,
+    -0.001_388_676_377_460_992_946_92,
+    0.000_024_390_448_796_277_409_065_4,
+];
+
+/// The bits of 2/pi, overlapping so that any 96 bits can be read from 3 consecutive words.
+const FRAC_2_PI_BITS: [u32; 24] = [
+    0xa2, 0xa2f9, 0xa2f983, 0xa2f9836e, 0xf9836e4e, 0x836e4e44, 0x6e4e4415, 0x4e441529, 0x441529fc,
+    0x1529fc27, 0x29fc2757, 0xfc2757d1, 0x2757d1f5, 0x57d1f534, 0xd1f534dd, 0xf534ddc0, 0x34ddc0db,
+    0xddc0db62, 0xc0db6295, 0xdb629599, 0x6295993c, 0x95993c43, 0x993c4390, 0x3c439041,
+];
+
+/// Reduces a finite `x` with `|x| >= 2` by pi/2 with the Payne-Hanek method, returning the
+/// quadrant and the remainder.
+///
+/// Only 96 bits of 2/pi around the binary point of the product are needed: higher bits only add
+/// multiples of 4 to the quadrant, and lower bits are below the precision of the remainder.
+fn reduce_large(x: f32) -> (i64, f64) {
+    let bits = x.to_bits() & 0x7fff_ffff;
+    let words = &FRAC_2_PI_BITS[(bits >> 26) as usize & 15..];
+    let shift = (bits >> 23) & 7;
+    let mantissa = ((bits & 0x7f_ffff) | 0x80_0000) << shift;
+
+    // the product, as a fixed-point number with 62 fractional bits, modulo 4
+    let high = (mantissa.wrapping_mul(words[0]) as u64) << 32;
+    let middle = mantissa as u64 * words[4] as u64;
+    let low = mantissa as u64 * words[8] as u64;
+    let product = (high | (low >> 32)).wrapping_add(middle);
+
+    // round to the nearest quadrant, leaving a remainder in [-1/2, 1/2]
+    let n = product.wrapping_add(1 << 61) >> 62;
+    let fraction = product.wrapping_sub(n << 62) as i64;
+    let r = fraction as f64 * (core::f64::consts::FRAC_PI_2 / (1u64 << 62) as f64);
+    if x.is_sign_negative() {
+        (-(n as i64), -r)
+    } else {
+        (n as i64, r)
+    }
+}
+
+impl<const LANES: usize> SimdF32<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Reduces each lane by pi/2, returning the quadrant and the remainder in `f64`.
+    #[inline]
+    fn reduce_pio2(self) -> (SimdI64<LANES>, SimdF64<LANES>) {
+        let x: SimdF64<LANES> = unsafe { crate::intrinsics::simd_cast(self) };
+        let medium = x.abs().lanes_lt(SimdF64::splat(CODY_WAITE_LIMIT));
+
+        // zero the quadrant of other lanes, since converting infinity or NaN to an integer is
+        // undefined
+        let n = (x * FRAC_2_PI + TO_INT) - TO_INT;
+        let n = medium.select(n, SimdF64::splat(0.));
+        let r = x - n * PIO2_HI - n * PIO2_LO;
+        let n: SimdI64<LANES> = unsafe { crate::intrinsics::simd_cast(n) };
+
+        let large = !medium & x.is_finite();
+        if large.any() {
+            let (mut n, mut r) = (n, r);
+            for lane in 0..LANES {
+                if large.test(lane) {
+                    let (lane_n, lane_r) = reduce_large(self[lane]);
+                    n[lane] = lane_n;
+                    r[lane] = lane_r;
+                }
+            }
+            (n, r)
+        } else {
+            (n, r)
+        }
+    }
+
+    /// Simultaneously computes the sine and cosine of each lane, in radians.
+    ///
+    /// The error is at most 1 ULP for every finite argument.  Infinite and NaN arguments produce
+    /// NaN.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let x = SimdF32::from_array([0., core::f32::consts::FRAC_PI_2, 1e30, f32::INFINITY]);
+    /// let (sin, cos) = x.sin_cos();
+    /// assert_eq!(sin.to_array()[..2], [0., 1.]);
+    /// assert!(cos[1].abs() < 1e-7);
+    ///
+    /// // large arguments are accurate too
+    /// let exact = (1e30f32 as f64).sin();
+    /// assert!((sin[2] as f64 - exact).abs() < 1e-7);
+    /// assert!(sin[3].is_nan() && cos[3].is_nan());
+    /// ```
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (quadrant, r) = self.reduce_pio2();
+        // the polynomials are infinite rather than NaN for infinity
+        let r = r.is_finite().select(r, SimdF64::splat(f64::NAN));
+
+        let r2 = r * r;
+        let r3 = r2 * r;
+        let r4 = r2 * r2;
+        let sin_r = (r + r3 * (SimdF64::splat(SIN[0]) + r2 * SIN[1]))
+            + r3 * r4 * (SimdF64::splat(SIN[2]) + r2 * SIN[3]);
+        let cos_r = ((SimdF64::splat(1.) + r2 * COS[0]) + r4 * COS[1])
+            + (r4 * r2) * (SimdF64::splat(COS[2]) + r2 * COS[3]);
+
+        // sin(x) is sin(r), cos(r), -sin(r), -cos(r) in quadrants 0 through 3, and cos(x) is
+        // sin(x + pi/2)
+        let swap = (quadrant & 1).lanes_ne(SimdI64::splat(0));
+        let negate_sin = (quadrant & 2).lanes_ne(SimdI64::splat(0));
+        let negate_cos = ((quadrant + 1) & 2).lanes_ne(SimdI64::splat(0));
+        let sin = swap.select(cos_r, sin_r);
+        let cos = swap.select(sin_r, cos_r);
+        let sin = negate_sin.select(-sin, sin);
+        let cos = negate_cos.select(-cos, cos);
+
+        // preserve the sign of zero
+        let tiny = r.abs().lanes_lt(SimdF64::splat(TINY)) & quadrant.lanes_eq(SimdI64::splat(0));
+        let sin = tiny.select(r, sin);
+        unsafe {
+            (
+                crate::intrinsics::simd_cast(sin),
+                crate::intrinsics::simd_cast(cos),
+            )
+        }
+    }
+
+    /// Computes the sine of each lane, in radians.
+    ///
+    /// The error is at most 1 ULP for every finite argument.  Infinite and NaN arguments produce
+    /// NaN.
+    #[inline]
+    pub fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    /// Computes the cosine of each lane, in radians.
+    ///
+    /// The error is at most 1 ULP for every finite argument.  Infinite and NaN arguments produce
+    /// NaN.
+    #[inline]
+    pub fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+}
@@ -0,0 +1,193 @@
+//! Building blocks for transcoding between UTF-16 and UTF-8.
+//!
+//! Text is often mostly ASCII, so transcoding loops usually check each vector for ASCII and
+//! convert it directly, falling back to a slower path for other characters:
+//!
+//! ```
+//! # use core_simd::utf16;
+//! # use core_simd::SimdU16;
+//! fn to_utf8(input: &[u16]) -> String {
+//!     let mut output = String::new();
+//!     let mut i = 0;
+//!     while i + 8 <= input.len() {
+//!         let units = SimdU16::<8>::from_slice(&input[i..]);
+//!         if !utf16::is_ascii(units).all() {
+//!             break;
+//!         }
+//!         // ASCII is valid UTF-8
+//!         output.push_str(core::str::from_utf8(&utf16::narrow(units).to_array()).unwrap());
+//!         i += 8;
+//!     }
+//!     output.extend(char::decode_utf16(input[i..].iter().copied()).map(Result::unwrap));
+//!     output
+//! }
+//!
+//! let text = "plain ASCII text, then ünïcödé";
+//! assert_eq!(to_utf8(&text.encode_utf16().collect::<Vec<_>>()), text);
+//! ```
+
+use crate::{LaneCount, Mask16, SimdI16, SimdU16, SimdU8, SupportedLaneCount};
+
+/// Zero-extends bytes to UTF-16 code units.
+///
+/// This converts ASCII or Latin-1 text to UTF-16.
+///
+/// ```
+/// # use core_simd::*;
+/// let bytes = SimdU8::from_array([b'a', b'b', 0x7f, 0xe9]);
+/// assert_eq!(utf16::widen(bytes).to_array(), [0x61, 0x62, 0x7f, 0xe9]);
+/// ```
+#[inline]
+pub fn widen<const LANES: usize>(bytes: SimdU8<LANES>) -> SimdU16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    unsafe { crate::intrinsics::simd_cast(bytes) }
+}
+
+/// Truncates UTF-16 code units to bytes.
+///
+/// This converts UTF-16 text to ASCII or Latin-1, if every code unit is ASCII or Latin-1
+/// respectively.  Check the code units with [`is_ascii`] or [`is_latin1`] first.
+///
+/// ```
+/// # use core_simd::*;
+/// let units = SimdU16::from_array([0x61, 0x62, 0x7f, 0xe9]);
+/// assert_eq!(utf16::narrow(units).to_array(), [b'a', b'b', 0x7f, 0xe9]);
+/// ```
+#[inline]
+pub fn narrow<const LANES: usize>(units: SimdU16<LANES>) -> SimdU8<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    unsafe { crate::intrinsics::simd_cast(units) }
+}
+
+/// Returns which code units are ASCII.
+#[inline]
+pub fn is_ascii<const LANES: usize>(units: SimdU16<LANES>) -> Mask16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    units.lanes_lt(SimdU16::splat(0x80))
+}
+
+/// Returns which code units are Latin-1, and can be narrowed to a single byte.
+#[inline]
+pub fn is_latin1<const LANES: usize>(units: SimdU16<LANES>) -> Mask16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    units.lanes_lt(SimdU16::splat(0x100))
+}
+
+/// Returns which code units are surrogates, from U+D800 to U+DFFF.
+///
+/// ```
+/// # use core_simd::*;
+/// let units = SimdU16::from_array([0x61, 0xd83e, 0xdd80, 0xe000]);
+/// assert_eq!(utf16::is_surrogate(units).to_array(), [false, true, true, false]);
+/// assert_eq!(utf16::is_high_surrogate(units).to_array(), [false, true, false, false]);
+/// assert_eq!(utf16::is_low_surrogate(units).to_array(), [false, false, true, false]);
+/// ```
+#[inline]
+pub fn is_surrogate<const LANES: usize>(units: SimdU16<LANES>) -> Mask16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (units & SimdU16::splat(0xf800)).lanes_eq(SimdU16::splat(0xd800))
+}
+
+/// Returns which code units are high (leading) surrogates, from U+D800 to U+DBFF.
+#[inline]
+pub fn is_high_surrogate<const LANES: usize>(units: SimdU16<LANES>) -> Mask16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (units & SimdU16::splat(0xfc00)).lanes_eq(SimdU16::splat(0xd800))
+}
+
+/// Returns which code units are low (trailing) surrogates, from U+DC00 to U+DFFF.
+#[inline]
+pub fn is_low_surrogate<const LANES: usize>(units: SimdU16<LANES>) -> Mask16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (units & SimdU16::splat(0xfc00)).lanes_eq(SimdU16::splat(0xdc00))
+}
+
+/// Returns which code units of `input` reveal an unpaired surrogate, looking back into
+/// `previous`.
+///
+/// A lane is set if it is a low surrogate which doesn't follow a high surrogate, or if it follows
+/// a high surrogate but isn't a low surrogate.  `previous` and `input` must be consecutive vectors
+/// of input; at the start of the input, `previous` should be zero.  A high surrogate in the last
+/// lane of `input` is checked with the next vector, and at the end of the input it is unpaired.
+///
+/// ```
+/// # use core_simd::*;
+/// let previous = SimdU16::from_array([0x61, 0x62, 0x63, 0xd83e]);
+/// let input = SimdU16::from_array([0xdd80, 0xdd80, 0xd83e, 0x61]);
+/// let unpaired = utf16::unpaired_surrogates(previous, input);
+/// assert_eq!(unpaired.to_array(), [false, true, false, true]);
+/// ```
+#[inline]
+pub fn unpaired_surrogates<const LANES: usize>(
+    previous: SimdU16<LANES>,
+    input: SimdU16<LANES>,
+) -> Mask16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let follows_high = is_high_surrogate(previous.sliding_window(input, LANES - 1));
+    follows_high ^ is_low_surrogate(input)
+}
+
+/// Returns the number of UTF-8 bytes needed to encode each code unit.
+///
+/// ASCII needs 1 byte, other code units up to U+07FF need 2 bytes, and the rest of the basic
+/// multilingual plane needs 3 bytes.  Each half of a surrogate pair counts 2 bytes, for a total of
+/// 4 bytes for the pair.
+///
+/// ```
+/// # use core_simd::*;
+/// let units = SimdU16::from_array([0x61, 0xe9, 0x65e5, 0xd83e, 0xdd80, 0x62, 0x7ff, 0x800]);
+/// assert_eq!(utf16::utf8_lengths(units).to_array(), [1, 2, 3, 2, 2, 1, 2, 3]);
+/// ```
+#[inline]
+pub fn utf8_lengths<const LANES: usize>(units: SimdU16<LANES>) -> SimdU16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // each comparison is -1 when true, so subtract them from the shortest length
+    let two_bytes = units.lanes_ge(SimdU16::splat(0x80)).to_int();
+    let three_bytes = (units.lanes_ge(SimdU16::splat(0x800)) & !is_surrogate(units)).to_int();
+    unsafe { crate::intrinsics::simd_cast(SimdI16::splat(1) - two_bytes - three_bytes) }
+}
+
+/// Returns the number of bytes needed to encode UTF-16 text as UTF-8.
+///
+/// Unpaired surrogates are counted as 2 bytes each.  To count them as replacement characters,
+/// check for them first with [`unpaired_surrogates`].
+///
+/// ```
+/// # use core_simd::utf16;
+/// let text = "ASCII, ünïcödé, 日本語, 🦀";
+/// let units: Vec<u16> = text.encode_utf16().collect();
+/// assert_eq!(utf16::utf8_len(&units), text.len());
+/// ```
+pub fn utf8_len(units: &[u16]) -> usize {
+    const LANES: usize = 16;
+    let mut chunks = units.chunks_exact(LANES);
+    let mut total = 0;
+    for chunk in &mut chunks {
+        // 16 lanes of at most 3 can't overflow
+        total += utf8_lengths(SimdU16::<LANES>::from_slice(chunk)).horizontal_sum() as usize;
+    }
+    let mut padded = [0; LANES];
+    let remainder = chunks.remainder();
+    padded[..remainder.len()].copy_from_slice(remainder);
+    // each padding lane counts as one byte of ASCII
+    let remainder_len = utf8_lengths(SimdU16::from_array(padded)).horizontal_sum() as usize;
+    total + remainder_len - (LANES - remainder.len())
+}
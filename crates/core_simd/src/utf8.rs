@@ -0,0 +1,319 @@
+//! Building blocks for UTF-8 validation.
+//!
+//! These implement the lookup algorithm of Keiser and Lemire, "Validating UTF-8 In Less Than One
+//! Instruction Per Byte".  Each byte is classified together with the byte before it, using three
+//! 16-entry lookups by nibble, which detects every error involving two bytes.  The remaining
+//! errors, missing or unexpected continuation bytes of 3 and 4 byte sequences, are detected by
+//! also looking 2 and 3 bytes back.
+//!
+//! Inputs are processed as consecutive vectors of bytes, and each function takes the previous
+//! vector of input to look across the boundary.  [`Utf8Validator`] combines the functions into a
+//! complete validator:
+//!
+//! ```
+//! # use core_simd::utf8::Utf8Validator;
+//! # use core_simd::SimdU8;
+//! let mut validator = Utf8Validator::<16>::new();
+//! for chunk in "Grüße, Jürgen ❤ 🎉 and some ASCII padding".as_bytes().chunks(16) {
+//!     let mut block = [0; 16];
+//!     block[..chunk.len()].copy_from_slice(chunk);
+//!     validator.push(SimdU8::from_array(block));
+//! }
+//! assert!(validator.finish());
+//! ```
+
+use crate::{LaneCount, SimdU8, SupportedLaneCount};
+
+// Each bit flags one kind of error, and is set in all three lookups only if the pair of bytes
+// contains that error.
+
+/// The lead byte or ASCII is followed by a lead byte or ASCII, instead of a continuation.
+const TOO_SHORT: u8 = 1 << 0;
+/// ASCII is followed by a continuation.
+const TOO_LONG: u8 = 1 << 1;
+/// An overlong encoding of a 3 byte sequence.
+const OVERLONG_3: u8 = 1 << 2;
+/// A code point greater than U+10FFFF.
+const TOO_LARGE: u8 = 1 << 3;
+/// An encoding of a surrogate code point.
+const SURROGATE: u8 = 1 << 4;
+/// An overlong encoding of a 2 byte sequence.
+const OVERLONG_2: u8 = 1 << 5;
+/// A code point greater than U+10FFFF with a second byte of `1000____`.
+const TOO_LARGE_1000: u8 = 1 << 6;
+/// An overlong encoding of a 4 byte sequence, which shares the bit of `TOO_LARGE_1000`.
+const OVERLONG_4: u8 = 1 << 6;
+/// A continuation follows a continuation.  This is expected in 3 and 4 byte sequences, and is
+/// cancelled by [`check_multibyte_lengths`].
+const TWO_CONTS: u8 = 1 << 7;
+/// Errors which depend only on the high nibble of the first byte.
+const CARRY: u8 = TOO_SHORT | TOO_LONG | TWO_CONTS;
+
+/// Classifies the first byte of each pair by its high nibble.
+const BYTE_1_HIGH: [u8; 16] = [
+    // 0_______: ASCII
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    // 10______: continuation
+    TWO_CONTS,
+    TWO_CONTS,
+    TWO_CONTS,
+    TWO_CONTS,
+    // 1100____: 2 byte lead
+    TOO_SHORT | OVERLONG_2,
+    // 1101____: 2 byte lead
+    TOO_SHORT,
+    // 1110____: 3 byte lead
+    TOO_SHORT | OVERLONG_3 | SURROGATE,
+    // 1111____: 4 byte lead
+    TOO_SHORT | TOO_LARGE | TOO_LARGE_1000 | OVERLONG_4,
+];
+
+/// Classifies the first byte of each pair by its low nibble.
+const BYTE_1_LOW: [u8; 16] = [
+    // ____0000
+    CARRY | OVERLONG_3 | OVERLONG_2 | OVERLONG_4,
+    // ____0001
+    CARRY | OVERLONG_2,
+    // ____001_
+    CARRY,
+    CARRY,
+    // ____0100
+    CARRY | TOO_LARGE,
+    // ____0101 through ____1100
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    // ____1101
+    CARRY | TOO_LARGE | TOO_LARGE_1000 | SURROGATE,
+    // ____111_
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+];
+
+/// Classifies the second byte of each pair by its high nibble.
+const BYTE_2_HIGH: [u8; 16] = [
+    // 0_______: ASCII
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    // 1000____
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | OVERLONG_3 | TOO_LARGE_1000 | OVERLONG_4,
+    // 1001____
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | OVERLONG_3 | TOO_LARGE,
+    // 101_____
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | SURROGATE | TOO_LARGE,
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | SURROGATE | TOO_LARGE,
+    // 11______: lead
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+];
+
+/// Returns each lane of `input` preceded by `n` lanes, continuing into the end of `previous`.
+///
+/// Lane `i` of the result is the byte `n` positions before lane `i` of `input`, when `previous`
+/// and `input` are consecutive vectors of input.
+///
+/// # Panics
+/// Panics if `n` is greater than `LANES`.
+///
+/// ```
+/// # use core_simd::*;
+/// let previous = SimdU8::from_array([0, 1, 2, 3]);
+/// let input = SimdU8::from_array([4, 5, 6, 7]);
+/// assert_eq!(utf8::prev(previous, input, 1).to_array(), [3, 4, 5, 6]);
+/// assert_eq!(utf8::prev(previous, input, 3).to_array(), [1, 2, 3, 4]);
+/// ```
+#[inline]
+pub fn prev<const LANES: usize>(
+    previous: SimdU8<LANES>,
+    input: SimdU8<LANES>,
+    n: usize,
+) -> SimdU8<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    assert!(n <= LANES, "offset must not exceed the number of lanes");
+    previous.sliding_window(input, LANES - n)
+}
+
+/// Classifies each byte of `input` with the byte before it, returning the errors of each pair.
+///
+/// `prev1` is the byte before each lane, as returned by [`prev`] with an offset of 1.  Each lane
+/// of the result is nonzero if the pair of bytes is invalid UTF-8, except that the high bit
+/// flags a continuation following a continuation, which is only an error outside of 3 and 4 byte
+/// sequences.  The high bit is resolved by [`check_multibyte_lengths`].
+#[inline]
+pub fn check_special_cases<const LANES: usize>(
+    input: SimdU8<LANES>,
+    prev1: SimdU8<LANES>,
+) -> SimdU8<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let byte_1_high = (prev1 >> 4).lookup_nibbles(&BYTE_1_HIGH);
+    let byte_1_low = prev1.lookup_nibbles(&BYTE_1_LOW);
+    let byte_2_high = (input >> 4).lookup_nibbles(&BYTE_2_HIGH);
+    byte_1_high & byte_1_low & byte_2_high
+}
+
+/// Checks the continuation bytes of 3 and 4 byte sequences, returning the errors of each lane.
+///
+/// `special_cases` is the result of [`check_special_cases`] for `input`.  The result is nonzero
+/// for each lane of `input` which completes an invalid sequence.
+#[inline]
+pub fn check_multibyte_lengths<const LANES: usize>(
+    previous: SimdU8<LANES>,
+    input: SimdU8<LANES>,
+    special_cases: SimdU8<LANES>,
+) -> SimdU8<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // only bytes `111_____` and `1111____` remain at least 0x80 after subtracting
+    let is_third_byte = prev(previous, input, 2).saturating_sub(SimdU8::splat(0b1110_0000 - 0x80));
+    let is_fourth_byte = prev(previous, input, 3).saturating_sub(SimdU8::splat(0b1111_0000 - 0x80));
+    let must_be_continuation = (is_third_byte | is_fourth_byte) & SimdU8::splat(0x80);
+    must_be_continuation ^ special_cases
+}
+
+/// Returns which of the last lanes of `input` begin a sequence which continues past the end of
+/// the vector.
+///
+/// A lane of the result is nonzero if the sequence is incomplete.  If there is no further input,
+/// this is an error.
+#[inline]
+pub fn is_incomplete<const LANES: usize>(input: SimdU8<LANES>) -> SimdU8<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // a lead byte is incomplete if it needs more bytes than remain in the vector
+    let mut max_value = [0xff; LANES];
+    let leads = [0b1111_0000 - 1, 0b1110_0000 - 1, 0b1100_0000 - 1];
+    for (max, lead) in max_value.iter_mut().rev().zip(leads.iter().rev()) {
+        *max = *lead;
+    }
+    input.saturating_sub(SimdU8::from_array(max_value))
+}
+
+/// A UTF-8 validator which consumes input a vector at a time.
+///
+/// The vectors must have at least 4 lanes.
+#[derive(Copy, Clone, Debug)]
+pub struct Utf8Validator<const LANES: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    error: SimdU8<LANES>,
+    previous: SimdU8<LANES>,
+    previous_incomplete: SimdU8<LANES>,
+}
+
+impl<const LANES: usize> Utf8Validator<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Creates a validator at the start of the input.
+    ///
+    /// # Panics
+    /// Panics if `LANES` is less than 4.
+    #[inline]
+    #[track_caller]
+    pub fn new() -> Self {
+        assert!(LANES >= 4, "UTF-8 validation requires at least 4 lanes");
+        Self {
+            error: SimdU8::splat(0),
+            previous: SimdU8::splat(0),
+            previous_incomplete: SimdU8::splat(0),
+        }
+    }
+
+    /// Validates the next vector of input.
+    ///
+    /// To validate input which isn't a multiple of `LANES` bytes, pad the last vector with zeros
+    /// or other ASCII.
+    #[inline]
+    pub fn push(&mut self, input: SimdU8<LANES>) {
+        if input.lanes_lt(SimdU8::splat(0x80)).all() {
+            // ASCII is valid by itself, but can't follow an incomplete sequence
+            self.error |= self.previous_incomplete;
+            self.previous_incomplete = SimdU8::splat(0);
+        } else {
+            let prev1 = prev(self.previous, input, 1);
+            let special_cases = check_special_cases(input, prev1);
+            self.error |= check_multibyte_lengths(self.previous, input, special_cases);
+            self.previous_incomplete = is_incomplete(input);
+        }
+        self.previous = input;
+    }
+
+    /// Returns true if no errors have been found so far.
+    ///
+    /// The input may still end with an incomplete sequence.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.error.lanes_eq(SimdU8::splat(0)).all()
+    }
+
+    /// Ends the input, returning true if it was valid UTF-8.
+    #[inline]
+    pub fn finish(mut self) -> bool {
+        self.error |= self.previous_incomplete;
+        self.is_valid()
+    }
+}
+
+impl<const LANES: usize> Default for Utf8Validator<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns true if `bytes` is valid UTF-8.
+///
+/// ```
+/// # use core_simd::utf8;
+/// assert!(utf8::validate("naïve café 🍵".as_bytes()));
+/// assert!(!utf8::validate(b"\xc3\x28"));
+/// ```
+pub fn validate(bytes: &[u8]) -> bool {
+    const LANES: usize = 32;
+    let mut validator = Utf8Validator::<LANES>::new();
+    let mut chunks = bytes.chunks_exact(LANES);
+    for chunk in &mut chunks {
+        validator.push(SimdU8::from_slice(chunk));
+        // check periodically, so invalid input can fail early
+        if !validator.is_valid() {
+            return false;
+        }
+    }
+    let remainder = chunks.remainder();
+    if !remainder.is_empty() {
+        let mut padded = [0; LANES];
+        padded[..remainder.len()].copy_from_slice(remainder);
+        validator.push(SimdU8::from_array(padded));
+    }
+    validator.finish()
+}
@@ -8,3 +8,702 @@ pub use uint::*;
 
 // Vectors of pointers are not for public use at the current time.
 pub(crate) mod ptr;
+
+use crate::{LaneCount, SupportedLaneCount};
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// A SIMD vector of `LANES` values of type `T`.
+///
+/// The vector types [`SimdF32`], [`SimdU8`], etc. are aliases of this type.  Operations which
+/// don't depend on the element type, such as constructing, indexing, and shuffling vectors, are
+/// available for any element type, which allows writing a single function for every vector type
+/// with a given number of lanes:
+/// ```
+/// # use core_simd::*;
+/// fn pair_swap<T: SimdElement>(v: Simd<T, 4>) -> Simd<T, 4> {
+///     v.swap_lanes(0, 1).swap_lanes(2, 3)
+/// }
+///
+/// assert_eq!(pair_swap(SimdU8::from_array([1, 2, 3, 4])).to_array(), [2, 1, 4, 3]);
+/// assert_eq!(pair_swap(Simd::from_array([1., 2., 3., 4.])).to_array(), [2., 1., 4., 3.]);
+/// ```
+///
+/// Operations specific to an element type, such as arithmetic, can be used in generic code by
+/// bounding the vector type:
+/// ```
+/// # use core_simd::*;
+/// fn double<T: SimdElement>(v: Simd<T, 4>) -> Simd<T, 4>
+/// where
+///     Simd<T, 4>: core::ops::Add<Output = Simd<T, 4>>,
+/// {
+///     v + v
+/// }
+///
+/// assert_eq!(double(SimdI32::from_array([1, -2, 3, -4])).to_array(), [2, -4, 6, -8]);
+/// assert_eq!(double(SimdF64::from_array([0.5, 1., 1.5, 2.])).to_array(), [1., 2., 3., 4.]);
+/// ```
+///
+/// # Layout
+///
+/// Vectors are supported with any number of lanes from 1 to 8, or with 16, 32, or 64 lanes.  The
+/// lanes are stored in order, like `[T; LANES]`, but the size of a vector is rounded up to the next
+/// power of two, and vectors are usually aligned to their size.  A vector with a number of lanes
+/// that isn't a power of two is therefore followed by padding:
+/// ```
+/// # use core_simd::*;
+/// use core::mem::size_of;
+/// assert_eq!(size_of::<SimdF32<3>>(), size_of::<SimdF32<4>>());
+/// assert_eq!(size_of::<SimdF32<6>>(), size_of::<SimdF32<8>>());
+///
+/// let v = SimdF32::from_array([1., 2., 3.]);
+/// assert_eq!((v * 2.).to_array(), [2., 4., 6.]);
+/// assert_eq!(v.horizontal_sum(), 6.);
+/// ```
+/// The padding has no defined value, so a slice of such vectors can't be viewed as a slice of
+/// scalars.  Use [`to_array`](Self::to_array) and [`read_unaligned`](Self::read_unaligned)
+/// rather than casting pointers.
+#[repr(simd)]
+pub struct Simd<T, const LANES: usize>([T; LANES])
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount;
+
+/// A type which can be held in each lane of a [`Simd`] vector.
+///
+/// This trait is sealed, and is implemented for the primitive integer and float types.  Its
+/// associated [`Mask`](Self::Mask) type allows writing code that is generic over the element type
+/// and uses masks:
+/// ```
+/// # use core_simd::*;
+/// fn replace_lanes<T: SimdElement, const N: usize>(
+///     v: Simd<T, N>,
+///     mask: Mask<T::Mask, N>,
+///     value: T,
+/// ) -> Simd<T, N>
+/// where
+///     LaneCount<N>: SupportedLaneCount,
+///     Simd<T, N>: Select<Mask<T::Mask, N>>,
+/// {
+///     mask.select(Simd::splat(value), v)
+/// }
+///
+/// let mask = Mask32::from_array([true, false, false, true]);
+/// assert_eq!(replace_lanes(SimdF32::splat(1.), mask, 0.).to_array(), [0., 1., 1., 0.]);
+/// assert_eq!(replace_lanes(SimdU32::splat(1), mask, 0).to_array(), [0, 1, 1, 0]);
+/// ```
+pub trait SimdElement: Sealed + Copy {
+    /// The element type of a [`Mask`](crate::Mask) that selects lanes of vectors of this type,
+    /// which is the signed integer type with the same size.
+    type Mask: crate::MaskElement;
+}
+
+macro_rules! impl_element {
+    { $($type:ty => $mask:ty),* } => {
+        $(
+            impl Sealed for $type {}
+            impl SimdElement for $type {
+                type Mask = $mask;
+            }
+        )*
+    }
+}
+
+impl_element! {
+    u8 => i8,
+    u16 => i16,
+    u32 => i32,
+    u64 => i64,
+    usize => isize,
+    i8 => i8,
+    i16 => i16,
+    i32 => i32,
+    i64 => i64,
+    isize => isize,
+    f32 => i32,
+    f64 => i64
+}
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Construct a SIMD vector by setting all lanes to the given value.
+    pub const fn splat(value: T) -> Self {
+        Self([value; LANES])
+    }
+
+    /// Returns a slice containing the entire SIMD vector.
+    pub const fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns a mutable slice containing the entire SIMD vector.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+
+    /// Converts an array to a SIMD vector.
+    pub const fn from_array(array: [T; LANES]) -> Self {
+        Self(array)
+    }
+
+    /// Converts a SIMD vector to an array.
+    pub const fn to_array(self) -> [T; LANES] {
+        // workaround for rust-lang/rust#80108
+        // TODO fix this
+        #[cfg(target_arch = "wasm32")]
+        {
+            let mut arr = [self.0[0]; LANES];
+            let mut i = 0;
+            while i < LANES {
+                arr[i] = self.0[i];
+                i += 1;
+            }
+            arr
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.0
+        }
+    }
+
+    /// Returns the value of the lane at index `lane`, or `None` if the index is out of
+    /// bounds.
+    ///
+    /// Unlike indexing, this never panics.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let v = SimdI32::<4>::from_array([10, 20, 30, 40]);
+    /// assert_eq!(v.get(1), Some(20));
+    /// assert_eq!(v.get(4), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, lane: usize) -> Option<T> {
+        self.as_slice().get(lane).copied()
+    }
+
+    /// Returns a mutable reference to the lane at index `lane`, or `None` if the index is
+    /// out of bounds.
+    ///
+    /// Unlike indexing, this never panics.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mut v = SimdI32::<4>::from_array([10, 20, 30, 40]);
+    /// if let Some(lane) = v.get_mut(2) {
+    ///     *lane = 0;
+    /// }
+    /// assert!(v.get_mut(4).is_none());
+    /// assert_eq!(v.to_array(), [10, 20, 0, 40]);
+    /// ```
+    #[inline]
+    pub fn get_mut(&mut self, lane: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(lane)
+    }
+
+    /// Swaps two lanes of the vector.
+    ///
+    /// # Panics
+    /// Panics if `a` or `b` is out of bounds.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let v = SimdU32::<4>::from_array([0, 1, 2, 3]);
+    /// assert_eq!(v.swap_lanes(0, 3).to_array(), [3, 1, 2, 0]);
+    /// ```
+    #[inline]
+    pub fn swap_lanes(self, a: usize, b: usize) -> Self {
+        let mut array = self.to_array();
+        array.swap(a, b);
+        Self::from_array(array)
+    }
+
+    /// Swaps the `len` lanes starting at `a` with the `len` lanes starting at `b`.
+    ///
+    /// # Panics
+    /// Panics if either range is out of bounds, or if the ranges overlap.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let v = SimdU32::<8>::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    /// assert_eq!(v.swap_lane_ranges(0, 4, 4).to_array(), [4, 5, 6, 7, 0, 1, 2, 3]);
+    /// assert_eq!(v.swap_lane_ranges(1, 5, 2).to_array(), [0, 5, 6, 3, 4, 1, 2, 7]);
+    /// ```
+    #[inline]
+    pub fn swap_lane_ranges(self, a: usize, b: usize, len: usize) -> Self {
+        assert!(
+            a.max(b) <= LANES && len <= LANES - a.max(b),
+            "lane range out of bounds"
+        );
+        assert!(a.min(b) + len <= a.max(b), "lane ranges overlap");
+        let mut array = self.to_array();
+        for i in 0..len {
+            array.swap(a + i, b + i);
+        }
+        Self::from_array(array)
+    }
+
+    /// Returns the `LANES` consecutive lanes starting at lane `offset` of `self`
+    /// followed by `next`.
+    ///
+    /// This is the vector equivalent of loading from `offset` elements past the start of
+    /// `self`, when `self` and `next` were loaded from adjacent memory.
+    ///
+    /// # Panics
+    /// Panics if `offset` is greater than `LANES`.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let a = SimdU32::<4>::from_array([0, 1, 2, 3]);
+    /// let b = SimdU32::<4>::from_array([4, 5, 6, 7]);
+    /// assert_eq!(a.sliding_window(b, 0).to_array(), [0, 1, 2, 3]);
+    /// assert_eq!(a.sliding_window(b, 3).to_array(), [3, 4, 5, 6]);
+    /// assert_eq!(a.sliding_window(b, 4).to_array(), [4, 5, 6, 7]);
+    /// ```
+    #[inline]
+    pub fn sliding_window(self, next: Self, offset: usize) -> Self {
+        assert!(offset <= LANES, "window offset out of bounds");
+        let mut array = self.to_array();
+        for (i, lane) in array.iter_mut().enumerate() {
+            *lane = if i + offset < LANES {
+                self[i + offset]
+            } else {
+                next[i + offset - LANES]
+            };
+        }
+        Self::from_array(array)
+    }
+
+    /// Returns the `K` sliding windows of `self` followed by `next`, starting at offsets
+    /// `0` through `K - 1`.
+    ///
+    /// These are the inputs to each tap of a `K`-tap FIR filter or convolution, without
+    /// reloading the overlapping data from memory.  See [`Self::sliding_window`].
+    ///
+    /// # Panics
+    /// Panics if `K` is greater than `LANES + 1`.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let taps = [0.25, 0.5, 0.25];
+    /// let a = SimdF32::<4>::from_array([1., 2., 3., 4.]);
+    /// let b = SimdF32::<4>::from_array([5., 6., 7., 8.]);
+    /// let mut output = SimdF32::splat(0.);
+    /// for (window, tap) in a.sliding_windows::<3>(b).iter().zip(taps.iter()) {
+    ///     output += *window * *tap;
+    /// }
+    /// assert_eq!(output.to_array(), [2., 3., 4., 5.]);
+    /// ```
+    #[inline]
+    pub fn sliding_windows<const K: usize>(self, next: Self) -> [Self; K] {
+        let mut windows = [self; K];
+        for (offset, window) in windows.iter_mut().enumerate() {
+            *window = self.sliding_window(next, offset);
+        }
+        windows
+    }
+
+    /// Reads a SIMD vector from `ptr` without requiring `ptr` to be aligned.
+    ///
+    /// This is the vector equivalent of [`core::ptr::read_unaligned`].
+    ///
+    /// # Safety
+    /// `ptr` must be valid for reads of `LANES` consecutive elements.
+    #[inline]
+    pub unsafe fn read_unaligned(ptr: *const T) -> Self {
+        Self(core::ptr::read_unaligned(ptr as *const [T; LANES]))
+    }
+
+    /// Writes this SIMD vector to `ptr` without requiring `ptr` to be aligned.
+    ///
+    /// This is the vector equivalent of [`core::ptr::write_unaligned`].
+    ///
+    /// # Safety
+    /// `ptr` must be valid for writes of `LANES` consecutive elements.
+    #[inline]
+    pub unsafe fn write_unaligned(self, ptr: *mut T) {
+        core::ptr::write_unaligned(ptr as *mut [T; LANES], self.to_array())
+    }
+
+    /// Loads a SIMD vector from the first `LANES` elements of a slice.
+    ///
+    /// # Panics
+    /// Panics if the slice's length is less than the number of lanes.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let v = SimdI32::<4>::from_slice(&[1, 2, 3, 4, 5]);
+    /// assert_eq!(v.to_array(), [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn from_slice(slice: &[T]) -> Self {
+        assert!(
+            slice.len() >= LANES,
+            "slice length must be at least the number of lanes"
+        );
+        // SAFETY: the slice has at least `LANES` elements
+        unsafe { Self::read_unaligned(slice.as_ptr()) }
+    }
+
+    /// Stores this SIMD vector to the first `LANES` elements of a slice.
+    ///
+    /// This is the store counterpart of [`from_slice`](Self::from_slice).
+    ///
+    /// # Panics
+    /// Panics if the slice's length is less than the number of lanes.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mut dest = [0; 5];
+    /// SimdI32::<4>::from_array([1, 2, 3, 4]).copy_to_slice(&mut dest);
+    /// assert_eq!(dest, [1, 2, 3, 4, 0]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn copy_to_slice(self, slice: &mut [T]) {
+        assert!(
+            slice.len() >= LANES,
+            "slice length must be at least the number of lanes"
+        );
+        // SAFETY: the slice has at least `LANES` elements
+        unsafe { self.copy_to_slice_unchecked(slice) }
+    }
+
+    /// Stores this SIMD vector to the first `LANES` elements of a slice, without checking
+    /// the slice's length.
+    ///
+    /// # Safety
+    /// The slice's length must be at least the number of lanes.
+    #[inline]
+    pub unsafe fn copy_to_slice_unchecked(self, slice: &mut [T]) {
+        debug_assert!(slice.len() >= LANES);
+        self.write_unaligned(slice.as_mut_ptr())
+    }
+
+    /// Returns uninitialized storage for a SIMD vector.
+    ///
+    /// The lanes can be filled one at a time through [`uninit_lanes`](Self::uninit_lanes),
+    /// avoiding initializing the vector twice.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mut v = SimdU32::<4>::uninit();
+    /// for (i, lane) in SimdU32::uninit_lanes(&mut v).iter_mut().enumerate() {
+    ///     *lane = core::mem::MaybeUninit::new(i as u32 * 10);
+    /// }
+    /// // SAFETY: every lane was written
+    /// let v = unsafe { v.assume_init() };
+    /// assert_eq!(v.to_array(), [0, 10, 20, 30]);
+    /// ```
+    #[inline]
+    pub const fn uninit() -> core::mem::MaybeUninit<Self> {
+        core::mem::MaybeUninit::uninit()
+    }
+
+    /// Returns the lanes of possibly-uninitialized storage for a SIMD vector.
+    ///
+    /// Once every lane has been written, the vector may be read with
+    /// [`MaybeUninit::assume_init`](core::mem::MaybeUninit::assume_init).
+    #[inline]
+    pub fn uninit_lanes(
+        vector: &mut core::mem::MaybeUninit<Self>,
+    ) -> &mut [core::mem::MaybeUninit<T>; LANES] {
+        // SAFETY: a vector has the layout of an array of lanes, and `MaybeUninit` has the
+        // layout of the type it wraps
+        unsafe { &mut *(vector.as_mut_ptr() as *mut [core::mem::MaybeUninit<T>; LANES]) }
+    }
+
+    /// Writes this SIMD vector to `ptr` with a non-temporal (streaming) store, hinting
+    /// that the data won't be read again soon and shouldn't displace other data in the
+    /// cache.
+    ///
+    /// On targets without non-temporal stores this is an ordinary store.
+    ///
+    /// Non-temporal stores may be weakly ordered with respect to other stores.  Before
+    /// another thread reads the written memory, issue a fence such as
+    /// `_mm_sfence` on x86.
+    ///
+    /// # Safety
+    /// `ptr` must be valid for writes and aligned to `align_of::<Self>()`.
+    #[inline]
+    pub unsafe fn write_nontemporal(self, ptr: *mut Self) {
+        core::intrinsics::nontemporal_store(ptr, self)
+    }
+
+    /// Applies `f` to each lane, producing a vector of the results.
+    ///
+    /// This is a fallback for per-lane operations that have no vector form.  The output
+    /// may be any vector type with the same number of lanes.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let v = SimdU32::<4>::from_array([1, 2, 3, 4]);
+    /// let squares: SimdU32<4> = v.map(|x| x * x);
+    /// assert_eq!(squares.to_array(), [1, 4, 9, 16]);
+    /// let halves: SimdF32<4> = v.map(|x| x as f32 / 2.);
+    /// assert_eq!(halves.to_array(), [0.5, 1.0, 1.5, 2.0]);
+    /// ```
+    #[inline]
+    pub fn map<U, V, F>(self, mut f: F) -> V
+    where
+        V: Vector<Scalar = U, Array = [U; LANES]>,
+        F: FnMut(T) -> U,
+    {
+        let mut output = V::splat(f(self.0[0]));
+        for (output, value) in output
+            .as_mut_slice()
+            .iter_mut()
+            .zip(self.as_slice())
+            .skip(1)
+        {
+            *output = f(*value);
+        }
+        output
+    }
+
+    /// Applies `f` to each pair of corresponding lanes of `self` and `other`, producing a
+    /// vector of the results.
+    ///
+    /// This is a fallback for lane-wise binary operations that have no vector form.  The
+    /// other input and the output may be any vector types with the same number of lanes.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let a = SimdU32::<4>::from_array([1, 2, 3, 4]);
+    /// let b = SimdU8::<4>::from_array([4, 3, 2, 1]);
+    /// let powers: SimdU32<4> = a.zip_map(b, |x, y| x.pow(y.into()));
+    /// assert_eq!(powers.to_array(), [1, 8, 9, 4]);
+    /// ```
+    #[inline]
+    pub fn zip_map<S, W, U, V, F>(self, other: W, mut f: F) -> V
+    where
+        S: Copy,
+        W: Vector<Scalar = S, Array = [S; LANES]>,
+        V: Vector<Scalar = U, Array = [U; LANES]>,
+        F: FnMut(T, S) -> U,
+    {
+        let other = other.as_slice();
+        let mut output = V::splat(f(self.0[0], other[0]));
+        for (output, (a, b)) in output
+            .as_mut_slice()
+            .iter_mut()
+            .zip(self.as_slice().iter().zip(other))
+            .skip(1)
+        {
+            *output = f(*a, *b);
+        }
+        output
+    }
+}
+
+impl<T, const LANES: usize> Copy for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+impl<T, const LANES: usize> Clone for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const LANES: usize> Default for Simd<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::splat(T::default())
+    }
+}
+
+impl<T, const LANES: usize> PartialEq for Simd<T, LANES>
+where
+    T: SimdElement + PartialEq,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // TODO use SIMD equality
+        self.to_array() == other.to_array()
+    }
+}
+
+/// Vectors are ordered lexicographically by lane, starting at lane 0.
+impl<T, const LANES: usize> PartialOrd for Simd<T, LANES>
+where
+    T: SimdElement + PartialOrd,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        // TODO use SIMD equalitya
+        self.to_array().partial_cmp(other.as_ref())
+    }
+}
+
+// array references
+impl<T, const LANES: usize> AsRef<[T; LANES]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn as_ref(&self) -> &[T; LANES] {
+        &self.0
+    }
+}
+
+impl<T, const LANES: usize> AsMut<[T; LANES]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T; LANES] {
+        &mut self.0
+    }
+}
+
+// slice references
+impl<T, const LANES: usize> AsRef<[T]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const LANES: usize> AsMut<[T]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+// vector/array conversion
+impl<T, const LANES: usize> From<[T; LANES]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn from(array: [T; LANES]) -> Self {
+        Self(array)
+    }
+}
+
+// scalar/vector conversion, splatting the scalar to all lanes
+impl<T, const LANES: usize> From<T> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::splat(value)
+    }
+}
+
+impl<T, const LANES: usize> Sealed for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+/// A representation of a vector as an array of `LANES` scalars, implemented by every vector type.
+///
+/// This trait allows writing a single generic implementation over all vector types:
+/// ```
+/// # use core_simd::*;
+/// fn first_and_last<V: Vector>(v: V) -> (V::Scalar, V::Scalar) {
+///     let slice = v.as_slice();
+///     (slice[0], slice[V::LANES - 1])
+/// }
+///
+/// assert_eq!(first_and_last(SimdU8::<4>::from_array([1, 2, 3, 4])), (1, 4));
+/// assert_eq!(first_and_last(SimdF32::<2>::splat(1.0)), (1.0, 1.0));
+/// ```
+pub trait Vector: Sealed + Copy {
+    /// The scalar type in every lane of this vector type.
+    type Scalar: Copy + Sized;
+
+    /// The array representation of this vector, `[Self::Scalar; Self::LANES]`.
+    type Array: Copy + AsRef<[Self::Scalar]> + AsMut<[Self::Scalar]>;
+
+    /// The number of lanes for this vector.
+    const LANES: usize;
+
+    /// Construct a SIMD vector by setting all lanes to the given value.
+    fn splat(value: Self::Scalar) -> Self;
+
+    /// Converts an array to a SIMD vector.
+    fn from_array(array: Self::Array) -> Self;
+
+    /// Converts a SIMD vector to an array.
+    fn to_array(self) -> Self::Array;
+
+    /// Returns a slice containing the entire SIMD vector.
+    fn as_slice(&self) -> &[Self::Scalar];
+
+    /// Returns a mutable slice containing the entire SIMD vector.
+    fn as_mut_slice(&mut self) -> &mut [Self::Scalar];
+}
+
+impl<T, const LANES: usize> Vector for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Scalar = T;
+    type Array = [T; LANES];
+    const LANES: usize = LANES;
+
+    #[inline]
+    fn splat(value: Self::Scalar) -> Self {
+        Self::splat(value)
+    }
+
+    #[inline]
+    fn from_array(array: Self::Array) -> Self {
+        Self::from_array(array)
+    }
+
+    #[inline]
+    fn to_array(self) -> Self::Array {
+        self.to_array()
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[Self::Scalar] {
+        self.as_slice()
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [Self::Scalar] {
+        self.as_mut_slice()
+    }
+}
@@ -10,8 +10,7 @@ macro_rules! impl_float_vector {
 
         impl<const LANES: usize> $name<LANES>
         where
-            Self: crate::LanesAtMost32,
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
             /// Raw transmutation to an unsigned integer vector type with the
             /// same size and number of lanes.
@@ -48,6 +47,24 @@ macro_rules! impl_float_vector {
                 unsafe { crate::intrinsics::simd_fma(self, a, b) }
             }
 
+            /// Computes the remainder of each lane, the same as the `%` operator.
+            ///
+            /// The result has the sign of `self`, and is `NaN` if the lane of `rhs` is zero or the
+            /// lane of `self` is infinite.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([5., -5., 5.5, 1.]);")]
+            #[doc = concat!("let y = ", stringify!($name), "::from_array([3., 3., -2., 0.]);")]
+            /// let r = x.rem(y);
+            /// assert_eq!(&r.to_array()[..3], &[2., -2., 1.5]);
+            /// assert!(r[3].is_nan());
+            /// ```
+            #[inline]
+            pub fn rem(self, rhs: Self) -> Self {
+                self % rhs
+            }
+
             /// Produces a vector where every lane has the square root value
             /// of the equivalently-indexed lane in `self`
             #[inline]
@@ -78,10 +95,7 @@ macro_rules! impl_float_vector {
 
         impl<const LANES: usize> $name<LANES>
         where
-            Self: crate::LanesAtMost32,
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
-            crate::$mask_impl_ty<LANES>: crate::LanesAtMost32,
-            crate::$mask_ty<LANES>: crate::Mask,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
             /// Returns true for each lane if it has a positive sign, including
             /// `+0.0`, `NaN`s with positive sign bit and positive infinity.
@@ -174,7 +188,7 @@ macro_rules! impl_float_vector {
             }
 
             /// Restrict each lane to a certain interval unless it is NaN.
-            /// 
+            ///
             /// For each lane in `self`, returns the corresponding lane in `max` if the lane is
             /// greater than `max`, and the corresponding lane in `min` if the lane is less
             /// than `min`.  Otherwise returns the lane in `self`.
@@ -194,28 +208,32 @@ macro_rules! impl_float_vector {
 }
 
 /// A SIMD vector of containing `LANES` `f32` values.
-#[repr(simd)]
-pub struct SimdF32<const LANES: usize>([f32; LANES])
-where
-    Self: crate::LanesAtMost32;
+///
+/// The `%` operator computes the remainder of each lane with the same semantics as `%` on `f32`:
+/// the result has the sign of the dividend, and is `NaN` if the divisor is zero or the dividend is
+/// infinite.  Most targets have no vector remainder instruction, in which case it is computed a
+/// lane at a time with `fmod`.
+pub type SimdF32<const LANES: usize> = crate::Simd<f32, LANES>;
 
 impl_float_vector! { SimdF32, f32, SimdU32, Mask32, SimdI32 }
 
 from_transmute_x86! { unsafe f32x4 => __m128 }
 from_transmute_x86! { unsafe f32x8 => __m256 }
-//from_transmute_x86! { unsafe f32x16 => __m512 }
+from_transmute_x86! { unsafe f32x16 => __m512 }
 
 /// A SIMD vector of containing `LANES` `f64` values.
-#[repr(simd)]
-pub struct SimdF64<const LANES: usize>([f64; LANES])
-where
-    Self: crate::LanesAtMost32;
+///
+/// The `%` operator computes the remainder of each lane with the same semantics as `%` on `f64`:
+/// the result has the sign of the dividend, and is `NaN` if the divisor is zero or the dividend is
+/// infinite.  Most targets have no vector remainder instruction, in which case it is computed a
+/// lane at a time with `fmod`.
+pub type SimdF64<const LANES: usize> = crate::Simd<f64, LANES>;
 
 impl_float_vector! { SimdF64, f64, SimdU64, Mask64, SimdI64 }
 
 from_transmute_x86! { unsafe f64x2 => __m128d }
 from_transmute_x86! { unsafe f64x4 => __m256d }
-//from_transmute_x86! { unsafe f64x8 => __m512d }
+from_transmute_x86! { unsafe f64x8 => __m512d }
 
 /// Vector of two `f32` values
 pub type f32x2 = SimdF32<2>;
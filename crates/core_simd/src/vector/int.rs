@@ -6,9 +6,9 @@ macro_rules! impl_integer_vector {
         impl_vector! { $name, $type }
         impl_integer_reductions! { $name, $type }
 
-        impl<const LANES: usize> Eq for $name<LANES> where Self: crate::LanesAtMost32 {}
+        impl<const LANES: usize> Eq for $name<LANES> where crate::LaneCount<LANES>: crate::SupportedLaneCount {}
 
-        impl<const LANES: usize> Ord for $name<LANES> where Self: crate::LanesAtMost32 {
+        impl<const LANES: usize> Ord for $name<LANES> where crate::LaneCount<LANES>: crate::SupportedLaneCount {
             #[inline]
             fn cmp(&self, other: &Self) -> core::cmp::Ordering {
                 // TODO use SIMD cmp
@@ -16,7 +16,7 @@ macro_rules! impl_integer_vector {
             }
         }
 
-        impl<const LANES: usize> core::hash::Hash for $name<LANES> where Self: crate::LanesAtMost32 {
+        impl<const LANES: usize> core::hash::Hash for $name<LANES> where crate::LaneCount<LANES>: crate::SupportedLaneCount {
             #[inline]
             fn hash<H>(&self, state: &mut H)
             where
@@ -28,9 +28,7 @@ macro_rules! impl_integer_vector {
 
         impl<const LANES: usize> $name<LANES>
         where
-            Self: crate::LanesAtMost32,
-            crate::$mask_impl_ty<LANES>: crate::LanesAtMost32,
-            crate::$mask_ty<LANES>: crate::Mask,
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
             /// Returns true for each positive lane and false if it is zero or negative.
             #[inline]
@@ -60,10 +58,7 @@ macro_rules! impl_integer_vector {
 }
 
 /// A SIMD vector of containing `LANES` `isize` values.
-#[repr(simd)]
-pub struct SimdIsize<const LANES: usize>([isize; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdIsize<const LANES: usize> = crate::Simd<isize, LANES>;
 
 impl_integer_vector! { SimdIsize, isize, MaskSize, SimdIsize }
 
@@ -71,61 +66,51 @@ impl_integer_vector! { SimdIsize, isize, MaskSize, SimdIsize }
 from_transmute_x86! { unsafe isizex4 => __m128i }
 #[cfg(target_pointer_width = "32")]
 from_transmute_x86! { unsafe isizex8 => __m256i }
+#[cfg(target_pointer_width = "32")]
+from_transmute_x86! { unsafe SimdIsize<16> => __m512i }
 
 #[cfg(target_pointer_width = "64")]
 from_transmute_x86! { unsafe isizex2 => __m128i }
 #[cfg(target_pointer_width = "64")]
 from_transmute_x86! { unsafe isizex4 => __m256i }
-//#[cfg(target_pointer_width = "64")]
-//from_transmute_x86! { unsafe isizex8 => __m512i }
+#[cfg(target_pointer_width = "64")]
+from_transmute_x86! { unsafe isizex8 => __m512i }
 
 /// A SIMD vector of containing `LANES` `i16` values.
-#[repr(simd)]
-pub struct SimdI16<const LANES: usize>([i16; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdI16<const LANES: usize> = crate::Simd<i16, LANES>;
 
 impl_integer_vector! { SimdI16, i16, Mask16, SimdI16 }
 
 from_transmute_x86! { unsafe i16x8 => __m128i }
 from_transmute_x86! { unsafe i16x16 => __m256i }
-//from_transmute_x86! { unsafe i16x32 => __m512i }
+from_transmute_x86! { unsafe i16x32 => __m512i }
 
 /// A SIMD vector of containing `LANES` `i32` values.
-#[repr(simd)]
-pub struct SimdI32<const LANES: usize>([i32; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdI32<const LANES: usize> = crate::Simd<i32, LANES>;
 
 impl_integer_vector! { SimdI32, i32, Mask32, SimdI32 }
 
 from_transmute_x86! { unsafe i32x4 => __m128i }
 from_transmute_x86! { unsafe i32x8 => __m256i }
-//from_transmute_x86! { unsafe i32x16 => __m512i }
+from_transmute_x86! { unsafe i32x16 => __m512i }
 
 /// A SIMD vector of containing `LANES` `i64` values.
-#[repr(simd)]
-pub struct SimdI64<const LANES: usize>([i64; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdI64<const LANES: usize> = crate::Simd<i64, LANES>;
 
 impl_integer_vector! { SimdI64, i64, Mask64, SimdI64 }
 
 from_transmute_x86! { unsafe i64x2 => __m128i }
 from_transmute_x86! { unsafe i64x4 => __m256i }
-//from_transmute_x86! { unsafe i64x8 => __m512i }
+from_transmute_x86! { unsafe i64x8 => __m512i }
 
 /// A SIMD vector of containing `LANES` `i8` values.
-#[repr(simd)]
-pub struct SimdI8<const LANES: usize>([i8; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdI8<const LANES: usize> = crate::Simd<i8, LANES>;
 
 impl_integer_vector! { SimdI8, i8, Mask8, SimdI8 }
 
 from_transmute_x86! { unsafe i8x16 => __m128i }
 from_transmute_x86! { unsafe i8x32 => __m256i }
-//from_transmute_x86! { unsafe i8x64 => __m512i }
+from_transmute_x86! { unsafe i8x64 => __m512i }
 
 /// Vector of two `isize` values
 pub type isizex2 = SimdIsize<2>;
@@ -6,9 +6,9 @@ macro_rules! impl_unsigned_vector {
         impl_vector! { $name, $type }
         impl_integer_reductions! { $name, $type }
 
-        impl<const LANES: usize> Eq for $name<LANES> where Self: crate::LanesAtMost32 {}
+        impl<const LANES: usize> Eq for $name<LANES> where crate::LaneCount<LANES>: crate::SupportedLaneCount {}
 
-        impl<const LANES: usize> Ord for $name<LANES> where Self: crate::LanesAtMost32 {
+        impl<const LANES: usize> Ord for $name<LANES> where crate::LaneCount<LANES>: crate::SupportedLaneCount {
             #[inline]
             fn cmp(&self, other: &Self) -> core::cmp::Ordering {
                 // TODO use SIMD cmp
@@ -16,7 +16,7 @@ macro_rules! impl_unsigned_vector {
             }
         }
 
-        impl<const LANES: usize> core::hash::Hash for $name<LANES> where Self: crate::LanesAtMost32 {
+        impl<const LANES: usize> core::hash::Hash for $name<LANES> where crate::LaneCount<LANES>: crate::SupportedLaneCount {
             #[inline]
             fn hash<H>(&self, state: &mut H)
             where
@@ -29,10 +29,7 @@ macro_rules! impl_unsigned_vector {
 }
 
 /// A SIMD vector of containing `LANES` `usize` values.
-#[repr(simd)]
-pub struct SimdUsize<const LANES: usize>([usize; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdUsize<const LANES: usize> = crate::Simd<usize, LANES>;
 
 impl_unsigned_vector! { SimdUsize, usize }
 
@@ -40,61 +37,107 @@ impl_unsigned_vector! { SimdUsize, usize }
 from_transmute_x86! { unsafe usizex4 => __m128i }
 #[cfg(target_pointer_width = "32")]
 from_transmute_x86! { unsafe usizex8 => __m256i }
+#[cfg(target_pointer_width = "32")]
+from_transmute_x86! { unsafe SimdUsize<16> => __m512i }
 
 #[cfg(target_pointer_width = "64")]
 from_transmute_x86! { unsafe usizex2 => __m128i }
 #[cfg(target_pointer_width = "64")]
 from_transmute_x86! { unsafe usizex4 => __m256i }
-//#[cfg(target_pointer_width = "64")]
-//from_transmute_x86! { unsafe usizex8 => __m512i }
+#[cfg(target_pointer_width = "64")]
+from_transmute_x86! { unsafe usizex8 => __m512i }
 
 /// A SIMD vector of containing `LANES` `u16` values.
-#[repr(simd)]
-pub struct SimdU16<const LANES: usize>([u16; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdU16<const LANES: usize> = crate::Simd<u16, LANES>;
 
 impl_unsigned_vector! { SimdU16, u16 }
 
 from_transmute_x86! { unsafe u16x8 => __m128i }
 from_transmute_x86! { unsafe u16x16 => __m256i }
-//from_transmute_x86! { unsafe u16x32 => __m512i }
+from_transmute_x86! { unsafe u16x32 => __m512i }
 
 /// A SIMD vector of containing `LANES` `u32` values.
-#[repr(simd)]
-pub struct SimdU32<const LANES: usize>([u32; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdU32<const LANES: usize> = crate::Simd<u32, LANES>;
 
 impl_unsigned_vector! { SimdU32, u32 }
 
 from_transmute_x86! { unsafe u32x4 => __m128i }
 from_transmute_x86! { unsafe u32x8 => __m256i }
-//from_transmute_x86! { unsafe u32x16 => __m512i }
+from_transmute_x86! { unsafe u32x16 => __m512i }
 
 /// A SIMD vector of containing `LANES` `u64` values.
-#[repr(simd)]
-pub struct SimdU64<const LANES: usize>([u64; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdU64<const LANES: usize> = crate::Simd<u64, LANES>;
 
 impl_unsigned_vector! { SimdU64, u64 }
 
 from_transmute_x86! { unsafe u64x2 => __m128i }
 from_transmute_x86! { unsafe u64x4 => __m256i }
-//from_transmute_x86! { unsafe u64x8 => __m512i }
+from_transmute_x86! { unsafe u64x8 => __m512i }
 
 /// A SIMD vector of containing `LANES` `u8` values.
-#[repr(simd)]
-pub struct SimdU8<const LANES: usize>([u8; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdU8<const LANES: usize> = crate::Simd<u8, LANES>;
 
 impl_unsigned_vector! { SimdU8, u8 }
 
 from_transmute_x86! { unsafe u8x16 => __m128i }
 from_transmute_x86! { unsafe u8x32 => __m256i }
-//from_transmute_x86! { unsafe u8x64 => __m512i }
+from_transmute_x86! { unsafe u8x64 => __m512i }
+
+impl<const LANES: usize> SimdU8<LANES>
+where
+    crate::LaneCount<LANES>: crate::SupportedLaneCount,
+{
+    /// Looks up each lane of `self` in a table formed by concatenating the vectors of `table`.
+    ///
+    /// Lane `i` of the table is lane `i % LANES` of vector `i / LANES`.  Indices past the end of
+    /// the table produce 0, matching the behavior of `tbl` on AArch64.  To instead wrap indices
+    /// around like `vpermi2b` on x86, mask the indices to the size of the table first.
+    ///
+    /// This is useful for small lookup tables of 32 or 64 entries, such as color maps or the
+    /// translation tables used by base64 codecs.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let table = [
+    ///     u8x8::from_array([0, 1, 2, 3, 4, 5, 6, 7]),
+    ///     u8x8::from_array([10, 11, 12, 13, 14, 15, 16, 17]),
+    /// ];
+    /// let indices = u8x8::from_array([0, 15, 3, 8, 16, 255, 9, 7]);
+    /// assert_eq!(indices.lookup(&table).to_array(), [0, 17, 3, 10, 0, 0, 11, 7]);
+    /// ```
+    #[inline]
+    pub fn lookup<const N: usize>(self, table: &[Self; N]) -> Self {
+        let mut output = [0; LANES];
+        for (output, index) in output.iter_mut().zip(self.as_slice()) {
+            let index = *index as usize;
+            if index < LANES * N {
+                *output = table[index / LANES].0[index % LANES];
+            }
+        }
+        Self::from_array(output)
+    }
+
+    /// Looks up the low 4 bits of each lane of `self` in a 16-entry table.
+    ///
+    /// This is the portable form of the byte shuffles `pshufb` on x86 and `tbl` on AArch64, when
+    /// used to classify bytes by nibble.  The high 4 bits of each lane are ignored.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let hex = *b"0123456789abcdef";
+    /// let bytes = u8x8::from_array([0x00, 0x09, 0x0a, 0x0f, 0x10, 0x7b, 0xff, 0xa5]);
+    /// assert_eq!(&bytes.lookup_nibbles(&hex).to_array(), b"09af0bf5");
+    /// assert_eq!(&(bytes >> 4).lookup_nibbles(&hex).to_array(), b"000017fa");
+    /// ```
+    #[inline]
+    pub fn lookup_nibbles(self, table: &[u8; 16]) -> Self {
+        let mut output = [0; LANES];
+        for (output, index) in output.iter_mut().zip(self.as_slice()) {
+            *output = table[(*index & 0xf) as usize];
+        }
+        Self::from_array(output)
+    }
+}
 
 /// Vector of two `usize` values
 pub type usizex2 = SimdUsize<2>;
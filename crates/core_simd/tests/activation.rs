@@ -0,0 +1,89 @@
+use core_simd::{SimdF32, SimdF64};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn sigmoid(x: f64) -> f64 {
+    1. / (1. + (-x).exp())
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn tanh_fast_error() {
+    for i in -1000..=1000 {
+        let x = f64::from(i) / 100.;
+        let [y64] = SimdF64::<1>::splat(x).tanh_fast().to_array();
+        let [y32] = SimdF32::<1>::splat(x as f32).tanh_fast().to_array();
+        assert!((y64 - x.tanh()).abs() < 1e-4, "tanh_fast({}) = {}", x, y64);
+        assert!(
+            (f64::from(y32) - x.tanh()).abs() < 1e-4,
+            "tanh_fast({}) = {}",
+            x,
+            y32
+        );
+        assert!(y64.abs() <= 1. && y32.abs() <= 1.);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sigmoid_fast_error() {
+    for i in -2000..=2000 {
+        let x = f64::from(i) / 100.;
+        let [y64] = SimdF64::<1>::splat(x).sigmoid_fast().to_array();
+        let [y32] = SimdF32::<1>::splat(x as f32).sigmoid_fast().to_array();
+        assert!(
+            (y64 - sigmoid(x)).abs() < 5e-5,
+            "sigmoid_fast({}) = {}",
+            x,
+            y64
+        );
+        assert!(
+            (f64::from(y32) - sigmoid(x)).abs() < 5e-5,
+            "sigmoid_fast({}) = {}",
+            x,
+            y32
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn activation_nan() {
+    let x = SimdF32::<2>::from_array([f32::NAN, f32::NAN]);
+    assert!(x.tanh_fast().is_nan().all());
+    assert!(x.sigmoid_fast().is_nan().all());
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn softmax() {
+    let x = SimdF64::<4>::from_array([1., 2., 3., 4.]);
+    let (exp, sum) = x.exp_sub_max();
+    let expected = [(-3f64).exp(), (-2f64).exp(), (-1f64).exp(), 1.];
+    for (actual, expected) in exp.to_array().iter().zip(expected.iter()) {
+        assert!((actual - expected).abs() < 1e-12);
+    }
+    assert!((sum - expected.iter().sum::<f64>()).abs() < 1e-12);
+
+    let softmax = x.softmax().to_array();
+    for (actual, expected) in softmax.iter().zip(expected.iter()) {
+        assert!((actual - expected / sum).abs() < 1e-12);
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn softmax_large() {
+    let x = SimdF32::<4>::from_array([1000., -1000., 1000., 0.]);
+    let softmax = x.softmax().to_array();
+    assert!(softmax.iter().all(|x| x.is_finite()));
+    assert!((softmax[0] - 0.5).abs() < 1e-6);
+    assert!((softmax[2] - 0.5).abs() < 1e-6);
+    assert!(softmax[1] < 1e-6 && softmax[3] < 1e-6);
+}
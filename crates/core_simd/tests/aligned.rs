@@ -0,0 +1,40 @@
+#![cfg(feature = "alloc")]
+
+use core_simd::aligned::AlignedBox;
+use core_simd::{SimdI32, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn aligned_to_vector() {
+    for len in 0..70 {
+        let buffer = AlignedBox::<SimdU8<32>>::new(len, 7);
+        assert_eq!(
+            buffer.as_ptr() as usize % core::mem::align_of::<SimdU8<32>>(),
+            0
+        );
+        assert_eq!(buffer.len(), len);
+        assert!(buffer.iter().all(|x| *x == 7));
+        assert_eq!(buffer.as_vectors().len(), (len + 31) / 32);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn from_slice_pads_with_zero() {
+    let values: Vec<i32> = (1..=7).collect();
+    let mut buffer = AlignedBox::<SimdI32<4>>::from_slice(&values);
+    assert_eq!(&buffer[..], &values[..]);
+    assert_eq!(buffer.as_vectors()[1].to_array(), [5, 6, 7, 0]);
+
+    for v in buffer.as_vectors_mut() {
+        *v *= 2;
+    }
+    assert_eq!(buffer[6], 14);
+    assert_eq!(buffer.into_vectors().len(), 2);
+}
@@ -0,0 +1,53 @@
+use core_simd::{SimdF32, SimdF64};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ulps() {
+    let one = SimdF32::<4>::splat(1.);
+    let next = |x: f32, n: u32| f32::from_bits(x.to_bits() + n);
+    let v = SimdF32::from_array([next(1., 1), next(1., 4), next(1., 5), 1.]);
+    assert_eq!(
+        v.lanes_approx_eq(one, 4, 0.).to_array(),
+        [true, true, false, true]
+    );
+    assert!(!v.all_approx_eq(one, 4, 0.));
+    assert!(v.all_approx_eq(one, 5, 0.));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn signs_and_zero() {
+    let a = SimdF64::<4>::from_array([0., -0., -1e-300, 1.]);
+    let b = SimdF64::<4>::from_array([-0., 0., 1e-300, -1.]);
+    // opposite signs are never within any number of ULPs
+    assert_eq!(
+        a.lanes_approx_eq(b, u64::MAX, 0.).to_array(),
+        [true, true, false, false]
+    );
+    // but small values are within epsilon
+    assert_eq!(
+        a.lanes_approx_eq(b, 0, 1e-200).to_array(),
+        [true, true, true, false]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn non_finite() {
+    let a = SimdF32::<4>::from_array([f32::NAN, f32::NAN, f32::INFINITY, f32::INFINITY]);
+    let b = SimdF32::<4>::from_array([f32::NAN, 0., f32::INFINITY, f32::MAX]);
+    assert_eq!(
+        a.lanes_approx_eq(b, 1, 1.).to_array(),
+        [false, false, true, true]
+    );
+    assert_eq!(
+        a.lanes_approx_eq(b, 0, 1.).to_array(),
+        [false, false, true, false]
+    );
+}
@@ -0,0 +1,39 @@
+use core_simd::{SimdF32, SimdF64, SimdI16, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn accelerated_iff_fits_register() {
+    const NATIVE: usize = SimdF32::<4>::native_lanes();
+    assert_eq!(SimdF32::<1>::is_hardware_accelerated(), NATIVE >= 1);
+    assert_eq!(SimdF32::<16>::is_hardware_accelerated(), NATIVE >= 16);
+    assert_eq!(
+        SimdU8::<32>::is_hardware_accelerated(),
+        SimdU8::<32>::native_lanes() >= 32
+    );
+    assert_eq!(SimdI16::<8>::native_lanes(), SimdI16::<32>::native_lanes());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg(all(target_arch = "x86_64", not(target_feature = "avx")))]
+fn x86_64_baseline() {
+    assert_eq!(SimdF32::<4>::native_lanes(), 4);
+    assert_eq!(SimdF64::<2>::native_lanes(), 2);
+    assert_eq!(SimdU8::<16>::native_lanes(), 16);
+    assert!(SimdF32::<4>::is_hardware_accelerated());
+    assert!(!SimdF32::<8>::is_hardware_accelerated());
+    assert!(!SimdF32::<16>::is_hardware_accelerated());
+}
+
+#[test]
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+fn x86_64_avx512() {
+    assert!(SimdF32::<16>::is_hardware_accelerated());
+    assert!(SimdF64::<8>::is_hardware_accelerated());
+}
@@ -0,0 +1,136 @@
+use core_simd::base64::{self, DecodeError};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn encode_scalar(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    for chunk in input.chunks(3) {
+        let mut bytes = [0; 3];
+        bytes[..chunk.len()].copy_from_slice(chunk);
+        let n = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 63]);
+            } else {
+                output.push(b'=');
+            }
+        }
+    }
+    output
+}
+
+fn encode(input: &[u8]) -> Vec<u8> {
+    let mut output = vec![0; base64::encoded_len(input.len())];
+    let len = base64::encode(input, &mut output);
+    assert_eq!(len, output.len());
+    output
+}
+
+fn decode(input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let mut output = vec![0; base64::decoded_len(input.len())];
+    let len = base64::decode(input, &mut output)?;
+    output.truncate(len);
+    Ok(output)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rfc4648_vectors() {
+    let vectors: [(&[u8], &[u8]); 7] = [
+        (b"", b""),
+        (b"f", b"Zg=="),
+        (b"fo", b"Zm8="),
+        (b"foo", b"Zm9v"),
+        (b"foob", b"Zm9vYg=="),
+        (b"fooba", b"Zm9vYmE="),
+        (b"foobar", b"Zm9vYmFy"),
+    ];
+    for (plain, encoded) in vectors.iter() {
+        assert_eq!(encode(plain), *encoded);
+        assert_eq!(decode(encoded).unwrap(), *plain);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn round_trip_all_lengths() {
+    let data: Vec<u8> = (0..200u32).map(|i| (i * 37 + 11) as u8).collect();
+    for len in 0..data.len() {
+        let input = &data[..len];
+        let encoded = encode(input);
+        assert_eq!(encoded, encode_scalar(input), "length {}", len);
+        assert_eq!(decode(&encoded).unwrap(), input, "length {}", len);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn encode_oversized_output() {
+    let data: Vec<u8> = (0..40u8).collect();
+    for len in 0..data.len() {
+        let mut output = [0xaa; 80];
+        let written = base64::encode(&data[..len], &mut output);
+        assert_eq!(&output[..written], &encode_scalar(&data[..len])[..]);
+        assert!(
+            output[written..].iter().all(|x| *x == 0xaa),
+            "length {}",
+            len
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn round_trip_random() {
+    test_helpers::test_1(&|x: [u8; 32]| {
+        let encoded = encode(&x);
+        proptest::prop_assert_eq!(&encoded, &encode_scalar(&x));
+        proptest::prop_assert_eq!(decode(&encoded).unwrap(), x.to_vec());
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn translate_all_values() {
+    let mut values = [0; 32];
+    for (i, v) in values.iter_mut().enumerate() {
+        *v = i as u8;
+    }
+    for offset in [0u8, 32].iter() {
+        let v = core_simd::SimdU8::<32>::from_array(values) + *offset;
+        let chars = base64::encode_6bit(v);
+        let expected = &ALPHABET[*offset as usize..*offset as usize + 32];
+        assert_eq!(&chars.to_array()[..], expected);
+
+        let (decoded, valid) = base64::decode_6bit(chars);
+        assert!(valid.all());
+        assert_eq!(decoded, v);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn invalid_input() {
+    assert_eq!(decode(b"Zm9"), Err(DecodeError::InvalidLength));
+    assert_eq!(decode(b"Zm-v"), Err(DecodeError::InvalidByte(2)));
+    assert_eq!(decode(b"Z==="), Err(DecodeError::InvalidByte(1)));
+    assert_eq!(decode(b"Zm=v"), Err(DecodeError::InvalidByte(2)));
+
+    let mut long = encode(&[0xff; 30]);
+    long[21] = b'\n';
+    assert_eq!(decode(&long), Err(DecodeError::InvalidByte(21)));
+
+    // every byte outside the alphabet is rejected
+    for c in 0..=255u8 {
+        let input = [b'A', b'A', b'A', c];
+        let valid = ALPHABET.contains(&c) || c == b'=';
+        assert_eq!(decode(&input).is_ok(), valid, "byte {}", c);
+    }
+}
@@ -0,0 +1,86 @@
+use core_simd::{bitpack, SimdU32};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+// Covers whole groups of values and a partial group
+const LEN: usize = 29;
+
+fn pack_scalar(values: &[u32], bits: u32) -> Vec<u8> {
+    let mut out = vec![0; bitpack::packed_len(values.len(), bits)];
+    for (i, value) in values.iter().enumerate() {
+        for bit in 0..bits as usize {
+            if value >> bit & 1 == 1 {
+                let offset = i * bits as usize + bit;
+                out[offset / 8] |= 1 << (offset % 8);
+            }
+        }
+    }
+    out
+}
+
+fn truncate(value: u32, bits: u32) -> u32 {
+    if bits == 32 {
+        value
+    } else {
+        value & ((1 << bits) - 1)
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pack_unpack_slice() {
+    test_helpers::test_1(&|values: [u32; LEN]| {
+        for bits in 0..=32 {
+            for len in [0, 1, 7, 8, 9, LEN].iter().copied() {
+                let values = &values[..len];
+                let expected = pack_scalar(values, bits);
+                let mut packed = vec![0xaa; expected.len() + 1];
+                bitpack::pack_slice(values, bits, &mut packed);
+                proptest::prop_assert_eq!(&packed[..expected.len()], &expected[..]);
+                proptest::prop_assert_eq!(packed[expected.len()], 0xaa);
+
+                let mut unpacked = vec![0; len];
+                bitpack::unpack_slice(&expected, bits, &mut unpacked);
+                for (unpacked, value) in unpacked.iter().zip(values) {
+                    proptest::prop_assert_eq!(*unpacked, truncate(*value, bits));
+                }
+            }
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pack_unpack_vector() {
+    test_helpers::test_1(&|values: [u32; 4]| {
+        for bits in 0..=32 {
+            let vector = SimdU32::from_array(values);
+            let mut packed = [0xff; 16];
+            bitpack::pack(vector, bits, &mut packed);
+            let len = bitpack::packed_len(4, bits);
+            proptest::prop_assert_eq!(&packed[..len], &pack_scalar(&values, bits)[..]);
+            let unpacked: SimdU32<4> = bitpack::unpack(&packed[..len], bits);
+            proptest::prop_assert_eq!(unpacked, vector & truncate(u32::MAX, bits));
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[should_panic]
+fn pack_buffer_too_small() {
+    let mut out = [0; 2];
+    bitpack::pack(SimdU32::<8>::splat(1), 3, &mut out);
+}
+
+#[test]
+#[should_panic]
+fn width_too_large() {
+    let mut out = [0; 64];
+    bitpack::pack_slice(&[1], 33, &mut out);
+}
@@ -0,0 +1,75 @@
+use core_simd::{SimdBitSet, SimdU64};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn bits_of(words: &[u64]) -> Vec<usize> {
+    let mut bits = Vec::new();
+    for (lane, word) in words.iter().enumerate() {
+        for bit in 0..64 {
+            if word >> bit & 1 == 1 {
+                bits.push(lane * 64 + bit);
+            }
+        }
+    }
+    bits
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn set_clear_test() {
+    let mut set = SimdBitSet::<8>::new();
+    assert!(set.is_empty());
+    for bit in [0, 63, 64, 300, 511].iter().copied() {
+        set.set(bit);
+        assert!(set.test(bit));
+    }
+    assert!(!set.test(1));
+    assert_eq!(set.count_ones(), 5);
+    set.clear(300);
+    assert!(!set.test(300));
+    assert_eq!(set.iter().collect::<Vec<_>>(), [0, 63, 64, 511]);
+    assert_eq!(format!("{:?}", set), "{0, 63, 64, 511}");
+    assert_eq!(SimdBitSet::<8>::full().count_ones(), 512);
+}
+
+#[test]
+#[should_panic]
+fn set_out_of_range() {
+    let mut set = SimdBitSet::<4>::new();
+    set.set(SimdBitSet::<4>::BITS);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn set_operations() {
+    test_helpers::test_2(&|a: [u64; 4], b: [u64; 4]| {
+        let (x, y) = (
+            SimdBitSet::from_vector(SimdU64::from_array(a)),
+            SimdBitSet::from_vector(SimdU64::from_array(b)),
+        );
+        let expected = |f: &dyn Fn(u64, u64) -> u64| {
+            let mut words = [0; 4];
+            for i in 0..4 {
+                words[i] = f(a[i], b[i]);
+            }
+            words
+        };
+        proptest::prop_assert_eq!((x | y).to_vector().to_array(), expected(&|a, b| a | b));
+        proptest::prop_assert_eq!((x & y).to_vector().to_array(), expected(&|a, b| a & b));
+        proptest::prop_assert_eq!((x ^ y).to_vector().to_array(), expected(&|a, b| a ^ b));
+        proptest::prop_assert_eq!((x - y).to_vector().to_array(), expected(&|a, b| a & !b));
+        proptest::prop_assert_eq!((!x).to_vector().to_array(), expected(&|a, _| !a));
+        proptest::prop_assert!((x & y).is_subset(x));
+        proptest::prop_assert_eq!(
+            x.count_ones(),
+            a.iter().map(|w| w.count_ones()).sum::<u32>()
+        );
+        proptest::prop_assert_eq!(x.iter().collect::<Vec<_>>(), bits_of(&a));
+        proptest::prop_assert_eq!(x.iter().collect::<SimdBitSet<4>>(), x);
+        Ok(())
+    });
+}
@@ -0,0 +1,58 @@
+use core_simd::{Checked, SimdI32, SimdU16};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn no_overflow() {
+    let mut x = Checked(SimdU16::from_array([0, 1, u16::MAX - 2, 100]));
+    x += Checked(SimdU16::splat(2));
+    assert_eq!(x.0.to_array(), [2, 3, u16::MAX, 102]);
+    x -= Checked(SimdU16::splat(2));
+    assert_eq!(x.0.to_array(), [0, 1, u16::MAX - 2, 100]);
+    x *= Checked(SimdU16::from_array([7, 7, 1, 655]));
+    assert_eq!(x.0.to_array(), [0, 7, u16::MAX - 2, 65500]);
+
+    let y = Checked(SimdI32::from_array([i32::MIN + 1, -1, 0, i32::MAX]));
+    assert_eq!((-y).0.to_array(), [i32::MAX, 1, 0, -i32::MAX]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "attempt to add with overflow")]
+fn add_overflow() {
+    let _ = Checked(SimdU16::from_array([0, u16::MAX])) + Checked(SimdU16::splat(1));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "attempt to subtract with overflow")]
+fn sub_overflow() {
+    let _ = Checked(SimdI32::from_array([0, i32::MIN])) - Checked(SimdI32::splat(1));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn mul_overflow() {
+    let _ = Checked(SimdI32::from_array([2, 1 << 30])) * Checked(SimdI32::splat(2));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "attempt to negate with overflow")]
+fn neg_overflow() {
+    let _ = -Checked(SimdI32::from_array([0, i32::MIN]));
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn wraps_without_debug_assertions() {
+    let x = Checked(SimdU16::from_array([0, u16::MAX])) + Checked(SimdU16::splat(1));
+    assert_eq!(x.0.to_array(), [1, 0]);
+}
@@ -0,0 +1,50 @@
+use core_simd::{SimdF32, SimdF64};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! cmul_add_tests {
+    { $name:ident, $vector:ident, $scalar:ty } => {
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_3(&|a: [$scalar; 8], b: [$scalar; 8], acc: [$scalar; 8]| {
+                let result = $vector::from_array(a)
+                    .cmul_add($vector::from_array(b), $vector::from_array(acc))
+                    .to_array();
+                for i in (0..8).step_by(2) {
+                    let re = (-a[i + 1]).mul_add(b[i + 1], a[i].mul_add(b[i], acc[i]));
+                    let im = a[i + 1].mul_add(b[i], a[i].mul_add(b[i + 1], acc[i + 1]));
+                    for (actual, expected) in result[i..i + 2].iter().zip([re, im].iter()) {
+                        proptest::prop_assert!(
+                            actual.to_bits() == expected.to_bits()
+                                || (actual.is_nan() && expected.is_nan())
+                        );
+                    }
+                }
+                Ok(())
+            });
+        }
+    }
+}
+
+cmul_add_tests! { cmul_add_f32, SimdF32, f32 }
+cmul_add_tests! { cmul_add_f64, SimdF64, f64 }
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cmul_add_accumulates() {
+    // rotate by 90 degrees four times, accumulating each step
+    let i = SimdF64::from_array([0., 1.]);
+    let mut z = SimdF64::from_array([3., 4.]);
+    let mut sum = SimdF64::splat(0.);
+    for _ in 0..4 {
+        z = z.cmul_add(i, SimdF64::splat(0.));
+        sum = z.cmul_add(SimdF64::from_array([1., 0.]), sum);
+    }
+    assert_eq!(z, SimdF64::from_array([3., 4.]));
+    assert_eq!(sum, SimdF64::splat(0.));
+}
@@ -0,0 +1,52 @@
+use core_simd::{SimdF32, SimdI16, SimdU32, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn const_int_ops() {
+    const A: SimdU32<4> = SimdU32::from_array([1, 2, 3, u32::MAX]);
+    const B: SimdU32<4> = SimdU32::splat(3);
+    const SUM: SimdU32<4> = A.const_add(B);
+    const DIFFERENCE: SimdU32<4> = A.const_sub(B);
+    const PRODUCT: SimdU32<4> = A.const_mul(B);
+    assert_eq!(SUM, A + B);
+    assert_eq!(DIFFERENCE, A - B);
+    assert_eq!(PRODUCT, A * B);
+
+    const X: SimdU8<4> = SimdU8::from_array([0b1100, 0b1010, 0, 0xff]);
+    const Y: SimdU8<4> = SimdU8::splat(0b0110);
+    const AND: SimdU8<4> = X.const_bitand(Y);
+    const OR: SimdU8<4> = X.const_bitor(Y);
+    const XOR: SimdU8<4> = X.const_bitxor(Y);
+    const NOT: SimdU8<4> = X.const_not();
+    assert_eq!(AND, X & Y);
+    assert_eq!(OR, X | Y);
+    assert_eq!(XOR, X ^ Y);
+    assert_eq!(NOT, !X);
+
+    const NEGATIVE: SimdI16<4> =
+        SimdI16::splat(0).const_sub(SimdI16::from_array([1, -2, i16::MIN, 0]));
+    assert_eq!(NEGATIVE.to_array(), [-1, 2, i16::MIN, 0]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn const_float_ops() {
+    // coefficients of a cubic, derived from other constants at compile time
+    const SCALE: SimdF32<4> = SimdF32::splat(0.5);
+    const BASE: SimdF32<4> = SimdF32::from_array([1., -2., 4., 8.]);
+    const COEFFICIENTS: SimdF32<4> = BASE.const_mul(SCALE).const_add(SimdF32::splat(1.));
+    assert_eq!(COEFFICIENTS, BASE * SCALE + SimdF32::splat(1.));
+
+    const DIFFERENCE: SimdF32<4> = BASE.const_sub(SCALE);
+    const QUOTIENT: SimdF32<4> = BASE.const_div(SCALE);
+    const NEGATED: SimdF32<4> = BASE.const_neg();
+    assert_eq!(DIFFERENCE, BASE - SCALE);
+    assert_eq!(QUOTIENT, BASE / SCALE);
+    assert_eq!(NEGATED, -BASE);
+}
@@ -0,0 +1,49 @@
+use core_simd::{denormals, SimdF32, SimdF64};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Hides a value from constant folding.
+fn opaque<T: Copy>(value: T) -> T {
+    unsafe { core::ptr::read_volatile(&value) }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn flush_denormals() {
+    let tiny = opaque(SimdF32::<4>::splat(f32::MIN_POSITIVE));
+    let denormal = opaque(SimdF64::<2>::splat(f64::MIN_POSITIVE / 4.));
+    let (flushed, input_zeroed) = unsafe {
+        denormals::flush_denormals(|| (tiny * opaque(0.5), denormal * opaque(SimdF64::splat(4.))))
+    };
+    if denormals::is_supported() {
+        assert_eq!(flushed, SimdF32::splat(0.));
+        assert_eq!(input_zeroed, SimdF64::splat(0.));
+    }
+
+    // the default handling is restored
+    assert_eq!(tiny * opaque(0.5), SimdF32::splat(f32::MIN_POSITIVE / 2.));
+    assert_eq!(
+        denormal * opaque(SimdF64::splat(4.)),
+        SimdF64::splat(f64::MIN_POSITIVE)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn flush_denormals_nested() {
+    let tiny = opaque(SimdF32::<4>::splat(f32::MIN_POSITIVE));
+    let inner = unsafe {
+        denormals::flush_denormals(|| {
+            let inner = denormals::flush_denormals(|| tiny * opaque(0.5));
+            // the outer setting is still in effect
+            assert_eq!(inner.to_bits(), (tiny * opaque(0.5)).to_bits());
+            inner
+        })
+    };
+    assert_eq!(unsafe { denormals::flush_denormals(|| 1 + 2) }, 3);
+    assert_eq!(inner.to_bits(), (inner * opaque(1.)).to_bits());
+}
@@ -0,0 +1,81 @@
+use core_simd::{SimdF32, SimdF64};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! div_fast_tests {
+    { $name:ident, $vector:ident, $scalar:ty, $error:expr } => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn within_error_bound() {
+                test_helpers::test_2(&|a: [$scalar; 4], b: [$scalar; 4]| {
+                    let quotient = $vector::from_array(a).div_fast($vector::from_array(b));
+                    let exact = $vector::from_array(a) / $vector::from_array(b);
+                    for lane in 0..4 {
+                        if exact[lane].is_finite() && exact[lane].abs() >= <$scalar>::MIN_POSITIVE {
+                            let error = (quotient[lane] / exact[lane] - 1.).abs();
+                            proptest::prop_assert!(
+                                error <= $error,
+                                "{:e} / {:e} = {:e}, expected {:e}",
+                                a[lane],
+                                b[lane],
+                                quotient[lane],
+                                exact[lane]
+                            );
+                        }
+                    }
+                    Ok(())
+                });
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn special_divisors_are_exact() {
+                let a = $vector::from_array([1., -1., 1., 1., 2., 1., 3., 0.]);
+                let b = $vector::from_array([
+                    0.,
+                    -0.,
+                    <$scalar>::INFINITY,
+                    <$scalar>::NAN,
+                    <$scalar>::MIN_POSITIVE / 2.,
+                    <$scalar>::MAX,
+                    3.,
+                    0.,
+                ]);
+                let quotient = a.div_fast(b);
+                let exact = a / b;
+                for lane in 0..6 {
+                    assert_eq!(quotient[lane].to_bits(), exact[lane].to_bits());
+                }
+                assert!(quotient[7].is_nan());
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn large_quotients_are_finite() {
+                let a = $vector::from_array([
+                    <$scalar>::MAX,
+                    -<$scalar>::MAX,
+                    <$scalar>::MAX / 2.,
+                    <$scalar>::INFINITY,
+                ]);
+                let b = $vector::from_array([1., 1., 0.5, 1.]);
+                let quotient = a.div_fast(b);
+                let exact = a / b;
+                for lane in 0..3 {
+                    assert!((quotient[lane] / exact[lane] - 1.).abs() <= $error);
+                }
+                assert_eq!(quotient[3], <$scalar>::INFINITY);
+            }
+        }
+    }
+}
+
+div_fast_tests! { f32, SimdF32, f32, 2f32.powi(-18) }
+div_fast_tests! { f64, SimdF64, f64, 2f64.powi(-37) }
@@ -0,0 +1,86 @@
+use core_simd::{DivisorU16, DivisorU32, DivisorU8, SimdU16, SimdU32, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_divisor {
+    { $name:ident, $divisor:ident, $vector:ident, $scalar:ident } => {
+        mod $name {
+            use super::*;
+
+            fn check(x: [$scalar; 8], d: $scalar) -> proptest::test_runner::TestCaseResult {
+                let divisor = $divisor::new(d);
+                let v = $vector::from_array(x);
+                let (quotients, remainders) = ((v / divisor).to_array(), (v % divisor).to_array());
+                for i in 0..8 {
+                    proptest::prop_assert_eq!(quotients[i], x[i] / d, "{} / {}", x[i], d);
+                    proptest::prop_assert_eq!(remainders[i], x[i] % d, "{} % {}", x[i], d);
+                }
+                Ok(())
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn random() {
+                test_helpers::test_2(&|x: [$scalar; 8], d: $scalar| check(x, d.max(1)));
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn edge_cases() {
+                let half = 1 << ($scalar::BITS - 1);
+                let dividends = [0, 1, 2, 3, half - 1, half, $scalar::MAX - 1, $scalar::MAX];
+                let divisors = [1, 2, 3, 5, 7, 10, half - 1, half, half + 1, $scalar::MAX - 1, $scalar::MAX];
+                for d in divisors.iter() {
+                    check(dividends, *d).unwrap();
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn assign() {
+                let divisor = $divisor::new(3);
+                let mut v = $vector::<4>::from_array([9, 10, 11, 12]);
+                v /= divisor;
+                assert_eq!(v.to_array(), [3, 3, 3, 4]);
+                v %= divisor;
+                assert_eq!(v.to_array(), [0, 0, 0, 1]);
+                assert_eq!(divisor.divisor(), 3);
+            }
+
+            #[test]
+            #[should_panic]
+            fn zero_panics() {
+                $divisor::new(0);
+            }
+        }
+    }
+}
+
+test_divisor! { divisor_u8, DivisorU8, SimdU8, u8 }
+test_divisor! { divisor_u16, DivisorU16, SimdU16, u16 }
+test_divisor! { divisor_u32, DivisorU32, SimdU32, u32 }
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn exhaustive_u8() {
+    let dividends: [u8; 256] = {
+        let mut all = [0; 256];
+        for (i, x) in all.iter_mut().enumerate() {
+            *x = i as u8;
+        }
+        all
+    };
+    for d in 1..=u8::MAX {
+        let divisor = DivisorU8::new(d);
+        for chunk in dividends.chunks(32) {
+            let q = SimdU8::<32>::from_slice(chunk) / divisor;
+            for (x, q) in chunk.iter().zip(q.to_array().iter()) {
+                assert_eq!(*q, x / d, "{} / {}", x, d);
+            }
+        }
+    }
+}
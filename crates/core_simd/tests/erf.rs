@@ -0,0 +1,80 @@
+#![cfg(feature = "std")]
+
+use core_simd::{SimdF32, SimdF64};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+// (x, erf(x), erfc(x))
+const REFERENCE: [(f64, f64, f64); 8] = [
+    (1e-10, 1.1283791670955126e-10, 0.999999999887162),
+    (0.1, 0.1124629160182849, 0.8875370839817152),
+    (0.49, 0.511668261188523, 0.4883317388114769),
+    (0.5, 0.5204998778130465, 0.4795001221869535),
+    (1., 0.8427007929497149, 0.15729920705028513),
+    (2., 0.9953222650189527, 0.004677734981047266),
+    (3., 0.9999779095030014, 2.209049699858544e-5),
+    (5., 0.9999999999984626, 1.5374597944280351e-12),
+];
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    let error = ((actual - expected) / expected).abs();
+    assert!(
+        error < tolerance,
+        "expected {}, got {} (relative error {})",
+        expected,
+        actual,
+        error
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn erf_f64() {
+    for &(x, erf, erfc) in REFERENCE.iter() {
+        let v = SimdF64::<2>::from_array([x, -x]);
+        let [pos, neg] = v.erf().to_array();
+        assert_close(pos, erf, 1.2e-7);
+        assert_close(neg, -erf, 1.2e-7);
+        let [pos, neg] = v.erfc().to_array();
+        assert_close(pos, erfc, 1.2e-7);
+        assert_close(neg, 2. - erfc, 1.2e-7);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn erf_f32() {
+    for &(x, erf, erfc) in REFERENCE.iter() {
+        let v = SimdF32::<2>::from_array([x as f32, -x as f32]);
+        let [pos, neg] = v.erf().to_array();
+        // allow for rounding of the input and output
+        assert_close(pos.into(), erf, 3e-7);
+        assert_close(neg.into(), -erf, 3e-7);
+        // erfc loses precision for large inputs due to rounding of `x * x`
+        let [pos, neg] = v.erfc().to_array();
+        assert_close(pos.into(), erfc, 1e-5);
+        assert_close(neg.into(), 2. - erfc, 3e-7);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn erf_special_values() {
+    let v = SimdF64::<4>::from_array([f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 30.]);
+    let erf = v.erf().to_array();
+    assert!(erf[0].is_nan());
+    assert_eq!(
+        erf[1..].iter().map(|x| *x as i32).collect::<Vec<_>>(),
+        [1, -1, 1]
+    );
+    let erfc = v.erfc().to_array();
+    assert!(erfc[0].is_nan());
+    assert_eq!(
+        erfc[1..].iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+        [0f64.to_bits(), 2f64.to_bits(), 0f64.to_bits()]
+    );
+}
@@ -0,0 +1,45 @@
+use core_simd::{SimdI16, SimdI32};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_mul_high {
+    { $name:ident, $vector:ident, $scalar:ident, $wide:ident, $lanes:literal } => {
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            fn reference(a: $scalar, b: $scalar) -> $scalar {
+                let bits = $scalar::BITS;
+                let wide = (2 * a as $wide * b as $wide + (1 << (bits - 1))) >> bits;
+                wide.min($scalar::MAX as $wide) as $scalar
+            }
+
+            test_helpers::test_2(&|a: [$scalar; $lanes], b: [$scalar; $lanes]| {
+                let actual = $vector::from_array(a).saturating_rounding_doubling_mul_high($vector::from_array(b));
+                for ((a, b), actual) in a.iter().zip(b.iter()).zip(actual.to_array().iter()) {
+                    proptest::prop_assert_eq!(*actual, reference(*a, *b));
+                }
+                Ok(())
+            });
+
+            let edge = [$scalar::MIN, $scalar::MIN + 1, -1, 0, 1, $scalar::MAX - 1, $scalar::MAX, $scalar::MIN];
+            for b in edge.iter() {
+                for chunk in edge.chunks($lanes) {
+                    let mut a = [0; $lanes];
+                    a.copy_from_slice(chunk);
+                    let actual = $vector::from_array(a).saturating_rounding_doubling_mul_high($vector::splat(*b));
+                    for (a, actual) in a.iter().zip(actual.to_array().iter()) {
+                        assert_eq!(*actual, reference(*a, *b));
+                    }
+                }
+            }
+        }
+    }
+}
+
+test_mul_high! { mul_high_i16, SimdI16, i16, i64, 8 }
+test_mul_high! { mul_high_i32, SimdI32, i32, i128, 8 }
+test_mul_high! { mul_high_i32x4, SimdI32, i32, i128, 4 }
@@ -0,0 +1,36 @@
+use core_simd::{SimdF32, SimdF64, SimdI32, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn display_integers() {
+    assert_eq!(
+        format!("{}", SimdI32::from_array([1, -2, 3, 4])),
+        "1, -2, 3, 4"
+    );
+    assert_eq!(format!("{}", SimdU8::<1>::splat(255)), "255");
+    assert_eq!(format!("{:>3}", SimdU8::from_array([1, 20])), "  1,  20");
+    assert_eq!(format!("{:+}", SimdI32::from_array([0, -1])), "+0, -1");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn display_floats() {
+    assert_eq!(
+        format!("{}", SimdF32::from_array([1.5, -0.25])),
+        "1.5, -0.25"
+    );
+    assert_eq!(
+        format!("{:.2}", SimdF64::from_array([1., 2.5, 1. / 3., -4.])),
+        "1.00, 2.50, 0.33, -4.00"
+    );
+    assert_eq!(
+        format!("{:6.1}", SimdF32::from_array([1., -10.])),
+        "   1.0,  -10.0"
+    );
+}
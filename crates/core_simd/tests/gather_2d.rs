@@ -0,0 +1,89 @@
+use core_simd::{SimdArray, SimdU16, SimdUsize};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn gather_2d_matches_scalar() {
+    let data: Vec<u16> = (0..100).collect();
+    test_helpers::test_3(&|x: [u8; 8], y: [u8; 8], stride: u8| {
+        let (len, stride) = (
+            data.len() - usize::from(stride % 7),
+            usize::from(stride % 16) + 1,
+        );
+        let mut xs = [0; 8];
+        let mut ys = [0; 8];
+        for lane in 0..8 {
+            xs[lane] = usize::from(x[lane] % 20);
+            ys[lane] = usize::from(y[lane] % 120);
+        }
+        let (x, y) = (SimdUsize::from_array(xs), SimdUsize::from_array(ys));
+        let gathered = SimdU16::<8>::gather_2d_or(&data[..len], stride, x, y, SimdU16::splat(999));
+        for lane in 0..8 {
+            let expected = if x[lane] < stride {
+                data[..len]
+                    .get(y[lane] * stride + x[lane])
+                    .copied()
+                    .unwrap_or(999)
+            } else {
+                999
+            };
+            proptest::prop_assert_eq!(gathered[lane], expected);
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn gather_2d_rejects_overflowing_indices() {
+    let data = [1u16, 2, 3, 4];
+    // y * stride + x wraps around to a valid index
+    let x = SimdUsize::<2>::from_array([1, 0]);
+    let y = SimdUsize::<2>::from_array([usize::MAX / 2 + 1, 1]);
+    let gathered = SimdU16::gather_2d_or_default(&data, 2, x, y);
+    assert_eq!(gathered.to_array(), [0, 3]);
+}
+
+#[test]
+#[should_panic]
+fn gather_2d_zero_stride() {
+    let _ =
+        SimdU16::<4>::gather_2d_or_default(&[1, 2, 3], 0, SimdUsize::splat(0), SimdUsize::splat(0));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn scatter_2d_transposed_matches_scalar() {
+    let mut columns = [SimdU16::<4>::splat(0); 3];
+    for (k, column) in columns.iter_mut().enumerate() {
+        for lane in 0..4 {
+            column[lane] = (10 * lane + k) as u16 + 1;
+        }
+    }
+    for stride in 1..6 {
+        for len in 0..20 {
+            let mut data = vec![0; len];
+            SimdU16::scatter_2d_transposed(columns, &mut data, stride);
+            for (i, x) in data.iter().enumerate() {
+                let (row, col) = (i / stride, i % stride);
+                let expected = if row < 4 && col < 3 {
+                    columns[col][row]
+                } else {
+                    0
+                };
+                assert_eq!(*x, expected);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn scatter_2d_transposed_zero_stride() {
+    SimdU16::<4>::scatter_2d_transposed([SimdU16::splat(1)], &mut [0; 4], 0);
+}
@@ -0,0 +1,109 @@
+use core_simd::{
+    LaneCount, Mask, Mask32, Select, Simd, SimdElement, SimdF32, SimdI16, SimdU8,
+    SupportedLaneCount,
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn rotate_left_one<T: SimdElement>(v: Simd<T, 4>) -> Simd<T, 4> {
+    v.shuffle::<{ [1, 2, 3, 0] }>(v)
+}
+
+fn sum_pairs<T: SimdElement>(a: Simd<T, 4>, b: Simd<T, 4>) -> Simd<T, 4>
+where
+    Simd<T, 4>: core::ops::Add<Output = Simd<T, 4>>,
+{
+    a + b
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generic_over_element() {
+    assert_eq!(
+        rotate_left_one(SimdU8::from_array([1, 2, 3, 4])).to_array(),
+        [2, 3, 4, 1]
+    );
+    assert_eq!(
+        rotate_left_one(SimdF32::from_array([1., 2., 3., 4.])).to_array(),
+        [2., 3., 4., 1.]
+    );
+    assert_eq!(
+        sum_pairs(SimdI16::splat(-1), SimdI16::from_array([1, 2, 3, 4])).to_array(),
+        [0, 1, 2, 3]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn aliases_are_simd() {
+    let v: Simd<u8, 4> = SimdU8::<4>::splat(7);
+    assert_eq!(v, Simd::splat(7u8));
+    assert_eq!(<[u8; 4]>::from(v), [7; 4]);
+}
+
+fn count_positive<const LANES: usize>(v: SimdF32<LANES>) -> usize
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mask: Mask32<LANES> = v.lanes_gt(SimdF32::splat(0.));
+    mask.to_array().iter().filter(|x| **x).count()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generic_over_lanes() {
+    assert_eq!(count_positive(SimdF32::from_array([1., -1.])), 1);
+    assert_eq!(count_positive(SimdF32::<8>::splat(2.)), 8);
+    assert_eq!(count_positive(SimdF32::<32>::splat(-2.)), 0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn non_power_of_two_lanes() {
+    let a = Simd::<f32, 3>::from_array([1., 2., 3.]);
+    let b = Simd::<f32, 3>::from_array([4., 5., 6.]);
+    assert_eq!((a * b).to_array(), [4., 10., 18.]);
+    assert_eq!((a * b).horizontal_sum(), 32.);
+    assert_eq!(b.horizontal_max(), 6.);
+    assert_eq!(count_positive(a - SimdF32::splat(2.)), 1);
+
+    let c = Simd::<f32, 6>::from_array([1., -2., 3., -4., 5., -6.]);
+    assert_eq!(c.abs().to_array(), [1., 2., 3., 4., 5., 6.]);
+    assert_eq!(c.horizontal_sum(), -3.);
+    assert_eq!(c.horizontal_min(), -6.);
+    assert_eq!(count_positive(c), 3);
+
+    let mut values = [0.; 8];
+    c.copy_to_slice(&mut values[1..]);
+    assert_eq!(values, [0., 1., -2., 3., -4., 5., -6., 0.]);
+    assert_eq!(Simd::<f32, 6>::from_slice(&values[1..]), c);
+}
+
+fn clear_first<T: SimdElement, const N: usize>(v: Simd<T, N>, zero: T) -> Simd<T, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+    Simd<T, N>: Select<Mask<T::Mask, N>>,
+{
+    Mask::<T::Mask, N>::from_len(1).select(Simd::splat(zero), v)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generic_mask() {
+    assert_eq!(
+        clear_first(SimdU8::from_array([1, 2, 3, 4]), 0).to_array(),
+        [0, 2, 3, 4]
+    );
+    assert_eq!(
+        clear_first(SimdF32::from_array([1., 2.]), 0.).to_array(),
+        [0., 2.]
+    );
+    assert_eq!(
+        clear_first(Simd::<i16, 3>::splat(-1), 0).to_array(),
+        [0, -1, -1]
+    );
+}
@@ -0,0 +1,69 @@
+use core_simd::{SimdF32, SimdF64};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn dot3_ignores_w() {
+    let a = SimdF32::<4>::from_array([1., -2., 3., f32::NAN]);
+    let b = SimdF32::<4>::from_array([4., 5., -6., f32::INFINITY]);
+    assert_eq!(a.dot3(b).to_bits(), (-24f32).to_bits());
+    assert_eq!(a.length_squared().to_bits(), 14f32.to_bits());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cross3() {
+    let x = SimdF64::<4>::from_array([1., 0., 0., 1.]);
+    let y = SimdF64::<4>::from_array([0., 1., 0., 1.]);
+    let z = SimdF64::<4>::from_array([0., 0., 1., 1.]);
+    let to_bits = |v: SimdF64<4>| v.to_bits().to_array();
+    assert_eq!(
+        to_bits(x.cross3(y)),
+        to_bits(SimdF64::from_array([0., 0., 1., 0.]))
+    );
+    assert_eq!(
+        to_bits(y.cross3(z)),
+        to_bits(SimdF64::from_array([1., 0., 0., 0.]))
+    );
+    assert_eq!(
+        to_bits(z.cross3(x)),
+        to_bits(SimdF64::from_array([0., 1., 0., 0.]))
+    );
+
+    // the cross product is perpendicular to both inputs
+    let lhs = SimdF64::<4>::from_array([1., 2., 3., 0.]);
+    let rhs = SimdF64::<4>::from_array([-4., 5., 7., 0.]);
+    let cross = lhs.cross3(rhs);
+    assert_eq!(cross.dot3(lhs).to_bits(), 0f64.to_bits());
+    assert_eq!(cross.dot3(rhs).to_bits(), 0f64.to_bits());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg(feature = "std")]
+fn length_and_normalize() {
+    let v = SimdF32::<4>::from_array([1., 4., 8., 2.]);
+    assert_eq!(v.length().to_bits(), 9f32.to_bits());
+    let n = v.normalize();
+    assert!((n.length() - 1.).abs() < 1e-6);
+    assert_eq!(n.to_array()[3].to_bits(), (2f32 / 9.).to_bits());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn three_lanes() {
+    let a = SimdF32::<3>::from_array([1., -2., 3.]);
+    let b = SimdF32::<3>::from_array([4., 5., -6.]);
+    assert_eq!(a.dot3(b).to_bits(), (-24f32).to_bits());
+    assert_eq!(a.length_squared().to_bits(), 14f32.to_bits());
+
+    let a4 = SimdF32::<4>::from_array([1., -2., 3., 0.]);
+    let b4 = SimdF32::<4>::from_array([4., 5., -6., 0.]);
+    let cross = a4.cross3(b4).to_array();
+    assert_eq!(a.cross3(b).to_array(), [cross[0], cross[1], cross[2]]);
+}
@@ -0,0 +1,32 @@
+use core_simd::SimdU16;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn get() {
+    test_helpers::test_2(&|x: [u16; 8], lane: usize| {
+        let v = SimdU16::from_array(x);
+        proptest::prop_assert_eq!(v.get(lane), x.get(lane).copied());
+        proptest::prop_assert_eq!(v.get(lane % 8), Some(x[lane % 8]));
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn get_mut() {
+    let mut v = SimdU16::<4>::splat(1);
+    for lane in 0..8 {
+        if let Some(x) = v.get_mut(lane) {
+            *x = lane as u16;
+        } else {
+            assert!(lane >= 4);
+        }
+    }
+    assert_eq!(v.to_array(), [0, 1, 2, 3]);
+}
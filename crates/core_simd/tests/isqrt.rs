@@ -0,0 +1,43 @@
+use core_simd::{SimdU32, SimdU64};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_isqrt {
+    { $name:ident, $vector:ident, $scalar:ident, $wide:ident } => {
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            fn is_root(n: $scalar, r: $scalar) -> bool {
+                let (n, r) = (n as $wide, r as $wide);
+                r * r <= n && n < (r + 1) * (r + 1)
+            }
+
+            test_helpers::test_1(&|x: [$scalar; 8]| {
+                let roots = $vector::from_array(x).isqrt().to_array();
+                for (n, r) in x.iter().zip(roots.iter()) {
+                    proptest::prop_assert!(is_root(*n, *r), "isqrt({}) = {}", n, r);
+                }
+                Ok(())
+            });
+
+            // perfect squares and their neighbors are the most sensitive to rounding
+            let max_root: $scalar = (1 << ($scalar::BITS / 2)) - 1;
+            for root in [1, 2, 3, 1000, 65535, max_root - 1, max_root].iter() {
+                let square = root * root;
+                let x = $vector::from_array([square - 1, square, square + 1, square + root]);
+                for (n, r) in x.to_array().iter().zip(x.isqrt().to_array().iter()) {
+                    assert!(is_root(*n, *r), "isqrt({}) = {}", n, r);
+                }
+            }
+            let x = $vector::from_array([0, 1, $scalar::MAX - 1, $scalar::MAX]);
+            assert_eq!(x.isqrt().to_array(), [0, 1, max_root, max_root]);
+        }
+    }
+}
+
+test_isqrt! { isqrt_u32, SimdU32, u32, u64 }
+test_isqrt! { isqrt_u64, SimdU64, u64, u128 }
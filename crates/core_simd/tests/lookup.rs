@@ -0,0 +1,41 @@
+use core_simd::SimdU8;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn lookup_two_vectors() {
+    test_helpers::test_3(&|a: [u8; 16], b: [u8; 16], indices: [u8; 16]| {
+        let table = [SimdU8::from_array(a), SimdU8::from_array(b)];
+        let result = SimdU8::from_array(indices).lookup(&table).to_array();
+        for (result, index) in result.iter().zip(indices.iter()) {
+            let expected = a.iter().chain(b.iter()).nth(*index as usize).copied();
+            proptest::prop_assert_eq!(*result, expected.unwrap_or(0));
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn lookup_four_vectors() {
+    let table = [
+        SimdU8::<32>::splat(1),
+        SimdU8::splat(2),
+        SimdU8::splat(3),
+        SimdU8::splat(4),
+    ];
+    let mut indices = [0u8; 32];
+    for (i, index) in indices.iter_mut().enumerate() {
+        *index = (i * 5) as u8;
+    }
+    let result = SimdU8::from_array(indices).lookup(&table).to_array();
+    for (result, index) in result.iter().zip(indices.iter()) {
+        let expected = if *index < 128 { *index / 32 + 1 } else { 0 };
+        assert_eq!(*result, expected);
+    }
+}
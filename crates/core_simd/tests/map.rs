@@ -0,0 +1,50 @@
+use core_simd::{SimdF64, SimdI16, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn map() {
+    test_helpers::test_1(&|x: [i16; 8]| {
+        let v = SimdI16::from_array(x);
+        let same: SimdI16<8> = v.map(i16::wrapping_abs);
+        let other: SimdU8<8> = v.map(|x| x as u8);
+        for (i, x) in x.iter().enumerate() {
+            proptest::prop_assert_eq!(same.to_array()[i], x.wrapping_abs());
+            proptest::prop_assert_eq!(other.to_array()[i], *x as u8);
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn map_visits_lanes_in_order() {
+    let mut visited = Vec::new();
+    let v = SimdF64::<4>::from_array([1., 2., 3., 4.]);
+    let _: SimdF64<4> = v.map(|x| {
+        visited.push(x as i32);
+        x
+    });
+    assert_eq!(visited, [1, 2, 3, 4]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn zip_map() {
+    test_helpers::test_2(&|a: [u8; 16], b: [i16; 16]| {
+        let a = SimdU8::from_array(a);
+        let b = SimdI16::from_array(b);
+        let sums: SimdI16<16> = a.zip_map(b, |a, b| b.wrapping_add(a.into()));
+        let products: SimdI16<16> = b.zip_map(b, i16::wrapping_mul);
+        for (i, (a, b)) in a.to_array().iter().zip(b.to_array().iter()).enumerate() {
+            proptest::prop_assert_eq!(sums.to_array()[i], b.wrapping_add((*a).into()));
+            proptest::prop_assert_eq!(products.to_array()[i], b.wrapping_mul(*b));
+        }
+        Ok(())
+    });
+}
@@ -0,0 +1,42 @@
+use core_simd::{SimdF32, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn covers_slice_with_padding() {
+    let data: Vec<u8> = (1..=37).collect();
+    for len in 0..data.len() {
+        let chunks: Vec<_> = SimdU8::<8>::masked_chunks(&data[..len]).collect();
+        assert_eq!(chunks.len(), (len + 7) / 8);
+        let mut seen = Vec::new();
+        for (i, (v, mask)) in chunks.iter().enumerate() {
+            for lane in 0..8 {
+                if mask.test(lane) {
+                    seen.push(v[lane]);
+                } else {
+                    // only the final vector is padded
+                    assert_eq!(i, chunks.len() - 1);
+                    assert_eq!(v[lane], 0);
+                }
+            }
+        }
+        assert_eq!(seen, &data[..len]);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn exact_size() {
+    let data = [0.5f32; 10];
+    let mut chunks = SimdF32::<4>::masked_chunks(&data);
+    assert_eq!(chunks.len(), 3);
+    chunks.next();
+    assert_eq!(chunks.len(), 2);
+    let (_, mask) = chunks.last().unwrap();
+    assert_eq!(mask.to_array(), [true, true, false, false]);
+}
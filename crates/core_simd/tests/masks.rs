@@ -39,6 +39,53 @@ macro_rules! test_mask_api {
                 let _ = mask.test(8);
             }
 
+            #[test]
+            fn const_splat() {
+                const ALL: core_simd::$name<8> = core_simd::$name::<8>::splat(true);
+                const MASKS: [core_simd::$name<4>; 2] =
+                    [core_simd::$name::NONE, core_simd::$name::ALL];
+                assert!(ALL.all());
+                assert_eq!(ALL, core_simd::$name::ALL);
+                assert_eq!(MASKS[0].to_array(), [false; 4]);
+                assert_eq!(MASKS[1].to_array(), [true; 4]);
+                assert_eq!(core_simd::$name::<16>::ALL.to_bitmask(), [u8::MAX; 2]);
+            }
+
+            #[test]
+            fn bitmask_vector() {
+                let mask = core_simd::$name::<32>::from_bitmask([0x12, 0x34, 0x56, 0x78]);
+                let bytes = mask.to_bitmask_vector();
+                assert_eq!(bytes.to_array(), [0x12, 0x34, 0x56, 0x78]);
+                assert_eq!(core_simd::$name::from_bitmask_vector(bytes), mask);
+
+                // combine the bitmasks of two masks with one vector operation
+                let a = core_simd::$name::<8>::from_bitmask([0b0101]).to_bitmask_vector();
+                let b = core_simd::$name::<8>::from_bitmask([0b0011]).to_bitmask_vector();
+                assert_eq!(
+                    core_simd::$name::<8>::from_bitmask_vector(a & b).to_bitmask(),
+                    [0b0001]
+                );
+            }
+
+            #[test]
+            fn checked_from_bitmask() {
+                for bitmask in 0..=u8::MAX {
+                    let checked = core_simd::$name::<4>::checked_from_bitmask([bitmask]);
+                    if bitmask < 0b1_0000 {
+                        assert_eq!(checked, Some(core_simd::$name::from_bitmask([bitmask])));
+                    } else {
+                        assert_eq!(checked, None);
+                        // the unchecked conversion ignores the stray bits
+                        assert_eq!(
+                            core_simd::$name::<4>::from_bitmask([bitmask]).to_bitmask(),
+                            [bitmask & 0b1111]
+                        );
+                    }
+                }
+                assert!(core_simd::$name::<8>::checked_from_bitmask([u8::MAX]).is_some());
+                assert!(core_simd::$name::<32>::checked_from_bitmask([u8::MAX; 4]).is_some());
+            }
+
             #[test]
             fn any() {
                 assert!(!core_simd::$name::<8>::splat(false).any());
@@ -57,6 +104,131 @@ macro_rules! test_mask_api {
                 assert!(!v.all());
             }
 
+            #[test]
+            fn horizontal_reductions() {
+                for bitmask in 0..=u8::MAX {
+                    let mask = core_simd::$name::<8>::from_bitmask([bitmask]);
+                    assert_eq!(mask.horizontal_and(), bitmask == u8::MAX);
+                    assert_eq!(mask.horizontal_or(), bitmask != 0);
+                    assert_eq!(mask.horizontal_xor(), bitmask.count_ones() % 2 == 1);
+                }
+            }
+
+            #[test]
+            fn lanes_eq_ne() {
+                let a = core_simd::$name::<8>::from_bitmask([0b01010011]);
+                let b = core_simd::$name::<8>::from_bitmask([0b00110101]);
+                assert_eq!(a.lanes_eq(b).to_bitmask(), [0b10011001]);
+                assert_eq!(a.lanes_ne(b).to_bitmask(), [0b01100110]);
+                assert!(a.lanes_eq(a).all());
+                assert!(!a.lanes_ne(a).any());
+            }
+
+            #[test]
+            fn ref_ops() {
+                let a = core_simd::$name::<8>::from_bitmask([0b01010011]);
+                let b = core_simd::$name::<8>::from_bitmask([0b00110101]);
+                assert_eq!(&a & &b, a & b);
+                assert_eq!(a | &b, a | b);
+                assert_eq!(&a ^ b, a ^ b);
+                assert_eq!(&a & &true, a);
+                assert_eq!(&false | &a, a);
+                assert_eq!(!&a, !a);
+                let mut c = a;
+                c ^= &b;
+                c |= &false;
+                assert_eq!(c, a ^ b);
+            }
+
+            #[test]
+            fn first_n_from_len() {
+                for n in 0..=8 {
+                    let mask = core_simd::$name::<8>::first_n(n);
+                    for lane in 0..8 {
+                        assert_eq!(mask.test(lane), lane < n);
+                    }
+                    assert_eq!(core_simd::$name::<8>::from_len(n), mask);
+                }
+                assert!(core_simd::$name::<32>::from_len(32).all());
+                assert!(core_simd::$name::<32>::from_len(usize::MAX).all());
+                assert!(!core_simd::$name::<1>::from_len(0).any());
+            }
+
+            #[test]
+            #[should_panic]
+            fn first_n_too_many() {
+                let _ = core_simd::$name::<8>::first_n(9);
+            }
+
+            #[test]
+            fn all_from_until() {
+                for lane in 0..=8 {
+                    let until = core_simd::$name::<8>::all_until(lane);
+                    let from = core_simd::$name::<8>::all_from(lane);
+                    for i in 0..8 {
+                        assert_eq!(until.test(i), i < lane);
+                        assert_eq!(from.test(i), i >= lane);
+                    }
+                }
+            }
+
+            #[test]
+            #[should_panic]
+            fn all_from_out_of_range() {
+                let _ = core_simd::$name::<8>::all_from(9);
+            }
+
+            #[test]
+            fn lane_shifts() {
+                let values = [true, false, false, true, true, false, true, false];
+                let mask = core_simd::$name::<8>::from_array(values);
+                let left = mask.shift_lanes_left::<3>().to_array();
+                let right = mask.shift_lanes_right::<3>().to_array();
+                let rotate_left = mask.rotate_lanes_left::<3>().to_array();
+                let rotate_right = mask.rotate_lanes_right::<3>().to_array();
+                for i in 0..8 {
+                    assert_eq!(left[i], i + 3 < 8 && values[i + 3]);
+                    assert_eq!(right[i], i >= 3 && values[i - 3]);
+                    assert_eq!(rotate_left[i], values[(i + 3) % 8]);
+                    assert_eq!(rotate_right[(i + 3) % 8], values[i]);
+                }
+                assert_eq!(mask.shift_lanes_left::<0>(), mask);
+                assert!(!mask.shift_lanes_left::<8>().any());
+                assert!(!mask.shift_lanes_right::<100>().any());
+                assert_eq!(mask.rotate_lanes_left::<11>(), mask.rotate_lanes_left::<3>());
+                assert_eq!(mask.rotate_lanes_right::<8>(), mask);
+            }
+
+            #[test]
+            fn lane_shifts_carry() {
+                // find lanes following a set lane, across a sequence of masks
+                let values = [false, true, false, true, true, false, false, true, false, false, true, false];
+                let mut previous = core_simd::$name::<4>::splat(false);
+                for (i, chunk) in values.chunks(4).enumerate() {
+                    let mask = core_simd::$name::<4>::from_array([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                    let follows = mask.shift_lanes_right_from::<1>(previous).to_array();
+                    for lane in 0..4 {
+                        let index = i * 4 + lane;
+                        assert_eq!(follows[lane], index > 0 && values[index - 1]);
+                    }
+                    assert_eq!(mask.shift_lanes_right_from::<4>(previous), previous);
+                    previous = mask;
+                }
+            }
+
+            #[test]
+            fn lexicographic_ordering() {
+                let from_array = core_simd::$name::<4>::from_array;
+                let none = core_simd::$name::<4>::splat(false);
+                let all = core_simd::$name::<4>::splat(true);
+                let first = from_array([true, false, false, false]);
+                let last = from_array([false, false, false, true]);
+                assert!(none < last && last < first && first < all);
+                assert!(from_array([true, false, true, true]) < from_array([true, true, false, false]));
+                assert_eq!(first.cmp(&first), core::cmp::Ordering::Equal);
+                assert_eq!(all.partial_cmp(&none), Some(core::cmp::Ordering::Greater));
+            }
+
             #[test]
             fn roundtrip_int_conversion() {
                 let values = [true, false, false, true, false, false, true, false];
@@ -66,6 +238,19 @@ macro_rules! test_mask_api {
                 assert_eq!(core_simd::$name::<8>::from_int(int), mask);
             }
 
+            #[test]
+            fn roundtrip_bool_int_conversion() {
+                let values = [true, false, false, true, false, false, true, false];
+                let mask = core_simd::$name::<8>::from_array(values);
+                let bytes = mask.to_bool_int();
+                assert_eq!(bytes.to_array(), [1, 0, 0, 1, 0, 0, 1, 0]);
+                assert_eq!(core_simd::$name::<8>::from_bool_int(bytes), mask);
+                let bytes = core_simd::SimdU8::from_array([0, 2, 0, 255, 0, 0, 128, 0]);
+                assert_eq!(core_simd::$name::<8>::from_bool_int(bytes).to_array(), [
+                    false, true, false, true, false, false, true, false,
+                ]);
+            }
+
             #[test]
             fn roundtrip_bitmask_conversion() {
                 let values = [
@@ -77,12 +262,99 @@ macro_rules! test_mask_api {
                 assert_eq!(bitmask, [0b01001001, 0b10000011]);
                 assert_eq!(core_simd::$name::<16>::from_bitmask(bitmask), mask);
             }
+
+            #[test]
+            fn bitmask_64_lanes() {
+                let bitmask = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef];
+                let mask = core_simd::$name::<64>::from_bitmask(bitmask);
+                for lane in 0..64 {
+                    assert_eq!(mask.test(lane), (bitmask[lane / 8] >> (lane % 8)) & 1 == 1);
+                }
+                assert_eq!(mask.to_bitmask(), bitmask);
+                let mut inverted = bitmask;
+                for x in &mut inverted {
+                    *x = !*x;
+                }
+                assert_eq!((!mask).to_bitmask(), inverted);
+                assert!(core_simd::$name::<64>::ALL.all());
+                assert!(!core_simd::$name::<64>::from_len(63).all());
+                assert!(mask.any() && !mask.horizontal_and());
+            }
+
+            #[test]
+            fn bitmask_odd_lanes() {
+                let mask = core_simd::$name::<3>::from_array([true, false, true]);
+                assert_eq!(mask.to_bitmask(), [0b101]);
+                assert_eq!((!mask).to_bitmask(), [0b010]);
+                assert!(core_simd::$name::<3>::splat(true).all());
+                assert!((mask | !mask).all());
+
+                let mask = core_simd::$name::<7>::from_len(2);
+                assert_eq!(mask.to_bitmask(), [0b0000011]);
+                assert_eq!((!mask).to_bitmask(), [0b1111100]);
+                assert_eq!(core_simd::$name::<7>::from_bitmask([0xff]), !core_simd::$name::<7>::splat(false));
+                assert_eq!(!!mask, mask);
+                assert!((mask | !mask).all());
+            }
+
+            #[test]
+            fn bitmask_ops() {
+                let values = [
+                    true, false, false, true, false, false, true, false,
+                    true, true, false, false, false, false, false, true,
+                ];
+                let mask = core_simd::$name::<16>::from_array(values);
+                let bits = [0b11110000, 0b00001111];
+                let other = core_simd::$name::<16>::from_bitmask(bits);
+                assert_eq!(mask.and_bitmask(bits), mask & other);
+                assert_eq!(mask.or_bitmask(bits), mask | other);
+                assert_eq!(mask.xor_bitmask(bits), mask ^ other);
+                assert_eq!(mask.and_bitmask(bits).to_bitmask(), [0b01000000, 0b00000011]);
+            }
+
+            #[test]
+            fn vector_mask_repr() {
+                let values = [true, false, false, true, false, false, true, false];
+                let mask = core_simd::$name::<8>::from_array(values);
+                let mut vector = core_simd::VectorMask::from(mask);
+                assert_eq!(vector.to_int().to_array(), [-1, 0, 0, -1, 0, 0, -1, 0]);
+                assert!(vector.any() && !vector.all());
+                assert_eq!(core_simd::$name::from(!vector), !mask);
+                vector.set(1, true);
+                assert!(vector.test(1));
+                assert_eq!(vector | !vector, core_simd::VectorMask::<core_simd::$name<8>>::splat(true));
+                assert_eq!(core_simd::BitMask::from(vector).to_bitmask(), [0b01001011]);
+            }
+
+            #[test]
+            fn bit_mask_repr() {
+                let values = [true, false, false, true];
+                let mask = core_simd::$name::<4>::from_array(values);
+                let mut bits = core_simd::BitMask::from(mask);
+                assert_eq!(bits.to_bitmask(), [0b1001]);
+                assert!(bits.any() && !bits.all());
+                assert_eq!(core_simd::$name::from(!bits), !mask);
+                assert_eq!((!bits).to_bitmask(), [0b0110]);
+                bits.set(1, true);
+                assert!(bits.test(1));
+                assert!((bits | !bits).all());
+                assert_eq!(core_simd::BitMask::<core_simd::$name<4>>::from_bitmask([0xff]).to_bitmask(), [0b1111]);
+                assert_eq!(core_simd::VectorMask::from(bits).to_int().to_array(), [-1, -1, 0, -1]);
+            }
+
+            #[test]
+            #[should_panic]
+            fn bit_mask_invalid_lane() {
+                let mask = core_simd::BitMask::<core_simd::$name<4>>::splat(false);
+                let _ = mask.test(4);
+            }
         }
     }
 }
 
 mod mask_api {
     test_mask_api! { Mask8 }
+    test_mask_api! { Mask32 }
 }
 
 #[test]
@@ -93,3 +365,24 @@ fn convert() {
         core_simd::Mask32::from_array(values).into()
     );
 }
+
+#[test]
+fn generic_element() {
+    fn first_and_last<T: core_simd::MaskElement>(mask: core_simd::Mask<T, 4>) -> (bool, bool) {
+        (mask.test(0), mask.test(3))
+    }
+
+    let values = [true, false, false, false];
+    assert_eq!(
+        first_and_last(core_simd::Mask8::from_array(values)),
+        (true, false)
+    );
+    assert_eq!(
+        first_and_last(core_simd::Mask64::from_array(values)),
+        (true, false)
+    );
+    assert_eq!(
+        core_simd::Mask::<i16, 4>::from_array(values),
+        core_simd::Mask16::from_array(values)
+    );
+}
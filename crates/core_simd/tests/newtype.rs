@@ -0,0 +1,80 @@
+use core_simd::{simd_newtype, SimdF32, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+simd_newtype! {
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    struct Meters(SimdF32<4>);
+    impl Add, Sub, Mul, Div, Rem, Neg;
+}
+
+simd_newtype! {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct Flags(pub SimdU8<4>);
+    impl BitAnd, BitOr, BitXor, Shl, Shr, Not;
+}
+
+simd_newtype! {
+    #[derive(Copy, Clone)]
+    struct Opaque(SimdU8<4>);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn arithmetic() {
+    let a = Meters::from(SimdF32::from_array([1., 2., 3., 4.]));
+    let b = Meters::from(SimdF32::splat(2.));
+    assert_eq!(SimdF32::from(a + b), SimdF32::from_array([3., 4., 5., 6.]));
+    assert_eq!(SimdF32::from(a - b), SimdF32::from_array([-1., 0., 1., 2.]));
+    assert_eq!(SimdF32::from(a * b), SimdF32::from_array([2., 4., 6., 8.]));
+    assert_eq!(
+        SimdF32::from(a / b),
+        SimdF32::from_array([0.5, 1., 1.5, 2.])
+    );
+    assert_eq!(SimdF32::from(a % b), SimdF32::from_array([1., 0., 1., 0.]));
+    assert_eq!(SimdF32::from(-a), SimdF32::from_array([-1., -2., -3., -4.]));
+
+    let mut c = a;
+    c += b;
+    c -= a;
+    c *= b;
+    c /= b;
+    c %= Meters(SimdF32::splat(3.));
+    assert_eq!(c, Meters(SimdF32::splat(2.)));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn bitwise() {
+    let a = Flags(SimdU8::from_array([0b1100, 0b1010, 0, 0xff]));
+    let b = Flags(SimdU8::splat(0b0110));
+    assert_eq!((a & b).0.to_array(), [0b0100, 0b0010, 0, 0b0110]);
+    assert_eq!((a | b).0.to_array(), [0b1110, 0b1110, 0b0110, 0xff]);
+    assert_eq!((a ^ b).0.to_array(), [0b1010, 0b1100, 0b0110, 0xf9]);
+    assert_eq!((!a).0.to_array(), [0xf3, 0xf5, 0xff, 0]);
+
+    let one = Flags(SimdU8::splat(1));
+    assert_eq!((a << one).0.to_array(), [0b11000, 0b10100, 0, 0xfe]);
+    assert_eq!((a >> one).0.to_array(), [0b110, 0b101, 0, 0x7f]);
+
+    let mut c = a;
+    c &= b;
+    c |= one;
+    c ^= one;
+    c <<= one;
+    c >>= one;
+    assert_eq!(c, a & b);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deref() {
+    let mut a = Opaque(SimdU8::from_array([1, 2, 3, 4]));
+    assert_eq!(a.horizontal_sum(), 10);
+    a[0] = 5;
+    assert_eq!(a.to_array(), [5, 2, 3, 4]);
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "num-traits")]
+
+use core_simd::{SimdF32, SimdI16, SimdU8};
+use num_traits::{Bounded, Inv, One, Saturating, WrappingAdd, Zero};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn sum_all<T: Zero + Copy>(values: &[T]) -> T {
+    values.iter().fold(T::zero(), |acc, x| acc + *x)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn zero_one() {
+    let values = [SimdI16::<4>::one(), SimdI16::from_array([1, 2, 3, 4])];
+    assert_eq!(sum_all(&values).to_array(), [2, 3, 4, 5]);
+    assert!(SimdI16::<4>::zero().is_zero());
+    assert!(!SimdI16::<4>::one().is_zero());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn integer_traits() {
+    let max = <SimdU8<4> as Bounded>::max_value();
+    assert_eq!(max.to_array(), [u8::MAX; 4]);
+    assert_eq!(Saturating::saturating_add(max, SimdU8::one()), max);
+    assert_eq!(WrappingAdd::wrapping_add(&max, &SimdU8::one()), SimdU8::zero());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn float_traits() {
+    let x = SimdF32::<4>::from_array([1., 2., 4., 8.]);
+    assert_eq!(Inv::inv(x), SimdF32::from_array([1., 0.5, 0.25, 0.125]));
+}
@@ -140,6 +140,85 @@ macro_rules! impl_binary_checked_op_test {
 macro_rules! impl_common_integer_tests {
     { $vector:ident, $scalar:ident } => {
         test_helpers::test_lanes! {
+            fn parity<const LANES: usize>() {
+                test_helpers::test_unary_mask_elementwise(
+                    &$vector::<LANES>::parity,
+                    &|x: $scalar| x.count_ones() % 2 == 1,
+                    &|_| true,
+                );
+            }
+
+            fn from_scalar<const LANES: usize>() {
+                test_helpers::test_1(&|x: $scalar| {
+                    proptest::prop_assert_eq!($vector::<LANES>::from(x), $vector::splat(x));
+                    let v: $vector<LANES> = x.into();
+                    proptest::prop_assert_eq!(v.to_array(), [x; LANES]);
+                    Ok(())
+                });
+            }
+
+            fn lanes_in_range<const LANES: usize>() {
+                test_helpers::test_3(&|x: [$scalar; LANES], lo: [$scalar; LANES], hi: [$scalar; LANES]| {
+                    let v = $vector::<LANES>::from_array(x);
+                    let (lo_v, hi_v) = ($vector::from_array(lo), $vector::from_array(hi));
+                    let exclusive = v.lanes_in_range(lo_v, hi_v).to_array();
+                    let inclusive = v.lanes_in_range_inclusive(lo_v, hi_v).to_array();
+                    for i in 0..LANES {
+                        proptest::prop_assert_eq!(exclusive[i], lo[i] <= x[i] && x[i] < hi[i]);
+                        proptest::prop_assert_eq!(inclusive[i], lo[i] <= x[i] && x[i] <= hi[i]);
+                    }
+                    Ok(())
+                });
+            }
+
+            fn lanes_cmp<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let cmp = $vector::<LANES>::from_array(x).lanes_cmp($vector::from_array(y)).to_array();
+                    for i in 0..LANES {
+                        let expected = match x[i].partial_cmp(&y[i]) {
+                            Some(core::cmp::Ordering::Less) => -1,
+                            Some(core::cmp::Ordering::Greater) => 1,
+                            _ => 0,
+                        };
+                        proptest::prop_assert_eq!(cmp[i], expected);
+                    }
+                    Ok(())
+                });
+            }
+
+            fn wrapping_pow<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], exp: u32| {
+                    let exp = exp % 70;
+                    let mut expected = x;
+                    for x in expected.iter_mut() {
+                        *x = x.wrapping_pow(exp);
+                    }
+                    test_helpers::prop_assert_biteq!(
+                        $vector::<LANES>::from_array(x).wrapping_pow(exp).to_array(),
+                        expected
+                    );
+                    Ok(())
+                });
+            }
+
+            fn checked_pow<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], exp: u32| {
+                    let exp = exp % 70;
+                    let mut expected = Some(x);
+                    for (lane, x) in x.iter().enumerate() {
+                        match (x.checked_pow(exp), expected.as_mut()) {
+                            (Some(pow), Some(expected)) => expected[lane] = pow,
+                            _ => expected = None,
+                        }
+                    }
+                    proptest::prop_assert_eq!(
+                        $vector::<LANES>::from_array(x).checked_pow(exp).map(|v| v.to_array()),
+                        expected
+                    );
+                    Ok(())
+                });
+            }
+
             fn horizontal_sum<const LANES: usize>() {
                 test_helpers::test_1(&|x| {
                     test_helpers::prop_assert_biteq! (
@@ -256,6 +335,49 @@ macro_rules! impl_signed_tests {
                     )
                 }
 
+                fn leading_sign_bits<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::leading_sign_bits,
+                        &|x| {
+                            let sign_bits = if x < 0 { x.leading_ones() } else { x.leading_zeros() };
+                            (sign_bits - 1) as Scalar
+                        },
+                        &|_| true,
+                    )
+                }
+
+                fn wrapping_neg<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::wrapping_neg,
+                        &Scalar::wrapping_neg,
+                        &|_| true,
+                    );
+                }
+
+                fn overflowing_neg<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &|x: Vector::<LANES>| x.overflowing_neg().0,
+                        &|x: Scalar| x.overflowing_neg().0,
+                        &|_| true,
+                    );
+                    test_helpers::test_unary_mask_elementwise(
+                        &|x: Vector::<LANES>| x.overflowing_neg().1,
+                        &|x: Scalar| x.overflowing_neg().1,
+                        &|_| true,
+                    );
+                }
+
+                fn checked_neg<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let expected = x.iter().map(|x| x.checked_neg()).collect::<Option<Vec<_>>>();
+                        let actual = Vector::<LANES>::from_array(x)
+                            .checked_neg()
+                            .map(|v| v.to_array().to_vec());
+                        proptest::prop_assert_eq!(actual, expected);
+                        Ok(())
+                    });
+                }
+
             }
 
             test_helpers::test_lanes_panic! {
@@ -313,6 +435,40 @@ macro_rules! impl_signed_tests {
             impl_binary_checked_op_test!(Vector<LANES>, Scalar, Div::div, DivAssign::div_assign, Scalar::wrapping_div, |x, y| y != 0 && !(x == Scalar::MIN && y == -1));
             impl_binary_checked_op_test!(Vector<LANES>, Scalar, Rem::rem, RemAssign::rem_assign, Scalar::wrapping_rem, |x, y| y != 0 && !(x == Scalar::MIN && y == -1));
 
+            test_helpers::test_lanes! {
+                fn div_unchecked<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &|x: Vector<LANES>, y| unsafe { x.div_unchecked(y) },
+                        &Scalar::wrapping_div,
+                        &|x, y| x.iter().zip(y.iter()).all(|(x, y)| *y != 0 && !(*x == Scalar::MIN && *y == -1)),
+                    );
+                }
+
+                fn rem_unchecked<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &|x: Vector<LANES>, y| unsafe { x.rem_unchecked(y) },
+                        &Scalar::wrapping_rem,
+                        &|x, y| x.iter().zip(y.iter()).all(|(x, y)| *y != 0 && !(*x == Scalar::MIN && *y == -1)),
+                    );
+                }
+
+                fn wrapping_div<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::wrapping_div,
+                        &Scalar::wrapping_div,
+                        &|_, y| y.iter().all(|y| *y != 0),
+                    );
+                }
+
+                fn wrapping_rem<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::wrapping_rem,
+                        &Scalar::wrapping_rem,
+                        &|_, y| y.iter().all(|y| *y != 0),
+                    );
+                }
+            }
+
             impl_unary_op_test!(Vector<LANES>, Scalar, Not::not);
             impl_binary_op_test!(Vector<LANES>, Scalar, BitAnd::bitand, BitAndAssign::bitand_assign);
             impl_binary_op_test!(Vector<LANES>, Scalar, BitOr::bitor, BitOrAssign::bitor_assign);
@@ -337,6 +493,10 @@ macro_rules! impl_unsigned_tests {
                     let b = Vector::<LANES>::splat(0);
                     let _ = a % b;
                 }
+
+                fn ilog2_zero_panic<const LANES: usize>() {
+                    let _ = Vector::<LANES>::splat(0).ilog2();
+                }
             }
 
             impl_binary_op_test!(Vector<LANES>, Scalar, Add::add, AddAssign::add_assign, Scalar::wrapping_add);
@@ -347,6 +507,179 @@ macro_rules! impl_unsigned_tests {
             impl_binary_checked_op_test!(Vector<LANES>, Scalar, Div::div, DivAssign::div_assign, Scalar::wrapping_div, |_, y| y != 0);
             impl_binary_checked_op_test!(Vector<LANES>, Scalar, Rem::rem, RemAssign::rem_assign, Scalar::wrapping_rem, |_, y| y != 0);
 
+            test_helpers::test_lanes! {
+                fn leading_zeros<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::leading_zeros,
+                        &|x| x.leading_zeros() as Scalar,
+                        &|_| true,
+                    );
+                }
+
+                fn ilog2<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::ilog2,
+                        &|x| (Scalar::BITS - 1 - x.leading_zeros()) as Scalar,
+                        &|x| !x.contains(&0),
+                    );
+                }
+
+                fn ilog10<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::ilog10,
+                        &|mut x| {
+                            let mut log = 0;
+                            while x >= 10 {
+                                x /= 10;
+                                log += 1;
+                            }
+                            log
+                        },
+                        &|x| !x.contains(&0),
+                    );
+                }
+
+                fn is_power_of_two<const LANES: usize>() {
+                    test_helpers::test_unary_mask_elementwise(
+                        &Vector::<LANES>::is_power_of_two,
+                        &Scalar::is_power_of_two,
+                        &|_| true,
+                    );
+                }
+
+                fn next_power_of_two<const LANES: usize>() {
+                    test_helpers::test_1(&|mut x: [Scalar; LANES]| {
+                        // avoid overflow, which panics in debug builds
+                        for x in x.iter_mut() {
+                            *x >>= 1;
+                        }
+                        let mut expected = x;
+                        for x in expected.iter_mut() {
+                            *x = x.next_power_of_two();
+                        }
+                        test_helpers::prop_assert_biteq!(
+                            Vector::<LANES>::from_array(x).next_power_of_two().to_array(),
+                            expected
+                        );
+                        Ok(())
+                    });
+                }
+
+                fn wrapping_next_power_of_two<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::wrapping_next_power_of_two,
+                        &|x| x.checked_next_power_of_two().unwrap_or(0),
+                        &|_| true,
+                    );
+                }
+
+                fn checked_next_power_of_two<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let mut expected = Some(x);
+                        for (lane, x) in x.iter().enumerate() {
+                            match (x.checked_next_power_of_two(), expected.as_mut()) {
+                                (Some(pow), Some(expected)) => expected[lane] = pow,
+                                _ => expected = None,
+                            }
+                        }
+                        proptest::prop_assert_eq!(
+                            Vector::<LANES>::from_array(x).checked_next_power_of_two().map(|v| v.to_array()),
+                            expected
+                        );
+                        Ok(())
+                    });
+                }
+
+                fn checked_ilog_zero<const LANES: usize>() {
+                    let mut x = Vector::<LANES>::splat(100);
+                    let (log2, zero2) = x.checked_ilog2();
+                    let (log10, zero10) = x.checked_ilog10();
+                    assert_eq!(log2, Vector::<LANES>::splat(6));
+                    assert_eq!(log10, Vector::<LANES>::splat(2));
+                    assert!(!zero2.any() && !zero10.any());
+
+                    // zero lanes are reported without affecting the other lanes
+                    x[LANES - 1] = 0;
+                    let (log2, zero2) = x.checked_ilog2();
+                    let (log10, zero10) = x.checked_ilog10();
+                    for lane in 0..LANES - 1 {
+                        assert_eq!(log2[lane], 6);
+                        assert_eq!(log10[lane], 2);
+                        assert!(!zero2.test(lane) && !zero10.test(lane));
+                    }
+                    assert_eq!(log2[LANES - 1], 0);
+                    assert_eq!(log10[LANES - 1], 0);
+                    assert!(zero2.test(LANES - 1) && zero10.test(LANES - 1));
+                }
+
+                fn funnel_shl<const LANES: usize>() {
+                    test_helpers::test_ternary_elementwise(
+                        &Vector::<LANES>::funnel_shl,
+                        &|high: Scalar, low: Scalar, shift: Scalar| {
+                            let shift = (shift % Scalar::BITS as Scalar) as u32;
+                            if shift == 0 { high } else { (high << shift) | (low >> (Scalar::BITS - shift)) }
+                        },
+                        &|_, _, _| true,
+                    );
+                }
+
+                fn funnel_shr<const LANES: usize>() {
+                    test_helpers::test_ternary_elementwise(
+                        &Vector::<LANES>::funnel_shr,
+                        &|high: Scalar, low: Scalar, shift: Scalar| {
+                            let shift = (shift % Scalar::BITS as Scalar) as u32;
+                            if shift == 0 { low } else { (high << (Scalar::BITS - shift)) | (low >> shift) }
+                        },
+                        &|_, _, _| true,
+                    );
+                }
+
+                fn funnel_shift_rotates<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &|x: Vector<LANES>, shift| x.funnel_shl(x, shift),
+                        &|x: Scalar, shift| x.rotate_left((shift % Scalar::BITS as Scalar) as u32),
+                        &|_, _| true,
+                    );
+                    test_helpers::test_binary_elementwise(
+                        &|x: Vector<LANES>, shift| x.funnel_shr(x, shift),
+                        &|x: Scalar, shift| x.rotate_right((shift % Scalar::BITS as Scalar) as u32),
+                        &|_, _| true,
+                    );
+                }
+
+                fn div_unchecked<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &|x: Vector<LANES>, y| unsafe { x.div_unchecked(y) },
+                        &Scalar::wrapping_div,
+                        &|_, y| y.iter().all(|y| *y != 0),
+                    );
+                }
+
+                fn rem_unchecked<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &|x: Vector<LANES>, y| unsafe { x.rem_unchecked(y) },
+                        &Scalar::wrapping_rem,
+                        &|_, y| y.iter().all(|y| *y != 0),
+                    );
+                }
+
+                fn wrapping_div<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::wrapping_div,
+                        &Scalar::wrapping_div,
+                        &|_, y| y.iter().all(|y| *y != 0),
+                    );
+                }
+
+                fn wrapping_rem<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::wrapping_rem,
+                        &Scalar::wrapping_rem,
+                        &|_, y| y.iter().all(|y| *y != 0),
+                    );
+                }
+            }
+
             impl_unary_op_test!(Vector<LANES>, Scalar, Not::not);
             impl_binary_op_test!(Vector<LANES>, Scalar, BitAnd::bitand, BitAndAssign::bitand_assign);
             impl_binary_op_test!(Vector<LANES>, Scalar, BitOr::bitor, BitOrAssign::bitor_assign);
@@ -370,6 +703,26 @@ macro_rules! impl_float_tests {
             impl_binary_op_test!(Vector<LANES>, Scalar, Div::div, DivAssign::div_assign);
             impl_binary_op_test!(Vector<LANES>, Scalar, Rem::rem, RemAssign::rem_assign);
 
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+            fn rem_special_values() {
+                let x = [5.5, -5.5, 5.5, -0., 1., Scalar::INFINITY, 3., Scalar::NAN];
+                let y = [2., 2., -2., 1., 0., 1., Scalar::INFINITY, 1.];
+                let rem = Vector::<8>::from_array(x) % Vector::<8>::from_array(y);
+                assert_eq!(
+                    Vector::<8>::from_array(x).rem(Vector::<8>::from_array(y)).to_bits(),
+                    rem.to_bits()
+                );
+                for ((x, y), rem) in x.iter().zip(y.iter()).zip(rem.to_array().iter()) {
+                    let expected = x % y;
+                    if expected.is_nan() {
+                        assert!(rem.is_nan());
+                    } else {
+                        assert_eq!(rem.to_bits(), expected.to_bits());
+                    }
+                }
+            }
+
             test_helpers::test_lanes! {
                 fn is_sign_positive<const LANES: usize>() {
                     test_helpers::test_unary_mask_elementwise(
@@ -411,6 +764,43 @@ macro_rules! impl_float_tests {
                     );
                 }
 
+                fn from_scalar<const LANES: usize>() {
+                    test_helpers::test_1(&|x: Scalar| {
+                        let v: Vector<LANES> = x.into();
+                        proptest::prop_assert_eq!(v.to_bits(), Vector::<LANES>::splat(x).to_bits());
+                        Ok(())
+                    });
+                }
+
+                fn lanes_in_range<const LANES: usize>() {
+                    test_helpers::test_3(&|x: [Scalar; LANES], lo: [Scalar; LANES], hi: [Scalar; LANES]| {
+                        let v = Vector::<LANES>::from_array(x);
+                        let (lo_v, hi_v) = (Vector::from_array(lo), Vector::from_array(hi));
+                        let exclusive = v.lanes_in_range(lo_v, hi_v).to_array();
+                        let inclusive = v.lanes_in_range_inclusive(lo_v, hi_v).to_array();
+                        for i in 0..LANES {
+                            proptest::prop_assert_eq!(exclusive[i], lo[i] <= x[i] && x[i] < hi[i]);
+                            proptest::prop_assert_eq!(inclusive[i], lo[i] <= x[i] && x[i] <= hi[i]);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn lanes_cmp<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        let cmp = Vector::<LANES>::from_array(x).lanes_cmp(Vector::from_array(y)).to_array();
+                        for i in 0..LANES {
+                            let expected = match x[i].partial_cmp(&y[i]) {
+                                Some(core::cmp::Ordering::Less) => -1,
+                                Some(core::cmp::Ordering::Greater) => 1,
+                                _ => 0,
+                            };
+                            proptest::prop_assert_eq!(cmp[i], expected);
+                        }
+                        Ok(())
+                    });
+                }
+
                 fn is_normal<const LANES: usize>() {
                     test_helpers::test_unary_mask_elementwise(
                         &Vector::<LANES>::is_normal,
@@ -0,0 +1,92 @@
+use core_simd::{SimdF32, SimdI16, SimdI32, SimdU16, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pairwise_integers() {
+    test_helpers::test_1(&|x: [i32; 8]| {
+        let v = SimdI32::from_array(x);
+        for i in 0..4 {
+            proptest::prop_assert_eq!(v.pairwise_min()[i], x[2 * i].min(x[2 * i + 1]));
+            proptest::prop_assert_eq!(v.pairwise_max()[i], x[2 * i].max(x[2 * i + 1]));
+        }
+        Ok(())
+    });
+    test_helpers::test_1(&|x: [u8; 32]| {
+        let v = SimdU8::from_array(x);
+        for i in 0..16 {
+            proptest::prop_assert_eq!(v.pairwise_min()[i], x[2 * i].min(x[2 * i + 1]));
+            proptest::prop_assert_eq!(v.pairwise_max()[i], x[2 * i].max(x[2 * i + 1]));
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pairwise_floats() {
+    // the sign of a zero result is unspecified
+    fn same(a: f32, b: f32) -> bool {
+        a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan()) || (a == 0. && b == 0.)
+    }
+    test_helpers::test_1(&|x: [f32; 16]| {
+        let v = SimdF32::from_array(x);
+        for i in 0..8 {
+            let (a, b) = (x[2 * i], x[2 * i + 1]);
+            proptest::prop_assert!(same(v.pairwise_min()[i], a.min(b)));
+            proptest::prop_assert!(same(v.pairwise_max()[i], a.max(b)));
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pairwise_tournament() {
+    let v = SimdU16::from_array([5, 9, 1, 7]);
+    assert_eq!(v.pairwise_max().to_array(), [9, 7]);
+    assert_eq!(v.pairwise_max().pairwise_max().to_array(), [9]);
+    assert_eq!(v.pairwise_min().pairwise_min().to_array(), [1]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn widening_pairwise_add() {
+    test_helpers::test_1(&|x: [u8; 32]| {
+        let sums = SimdU8::from_array(x).widening_pairwise_add();
+        for i in 0..16 {
+            proptest::prop_assert_eq!(sums[i], u16::from(x[2 * i]) + u16::from(x[2 * i + 1]));
+        }
+        Ok(())
+    });
+    test_helpers::test_1(&|x: [i16; 8]| {
+        let sums = SimdI16::from_array(x).widening_pairwise_add();
+        for i in 0..4 {
+            proptest::prop_assert_eq!(sums[i], i32::from(x[2 * i]) + i32::from(x[2 * i + 1]));
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn widening_pairwise_mul_add() {
+    test_helpers::test_2(&|x: [i16; 8], y: [i16; 8]| {
+        let sums = SimdI16::from_array(x).widening_pairwise_mul_add(SimdI16::from_array(y));
+        for i in 0..4 {
+            let even = i32::from(x[2 * i]) * i32::from(y[2 * i]);
+            let odd = i32::from(x[2 * i + 1]) * i32::from(y[2 * i + 1]);
+            proptest::prop_assert_eq!(sums[i], even.wrapping_add(odd));
+        }
+        Ok(())
+    });
+
+    // matches pmaddwd, which wraps when every input is i16::MIN
+    let min = SimdI16::<2>::splat(i16::MIN);
+    assert_eq!(min.widening_pairwise_mul_add(min).to_array(), [i32::MIN]);
+}
@@ -0,0 +1,56 @@
+use core_simd::{ParseVectorError, SimdF64, SimdI16, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn from_str() {
+    let v: SimdI16<4> = " [1, -2,3 ,  4] ".parse().unwrap();
+    assert_eq!(v.to_array(), [1, -2, 3, 4]);
+
+    let v: SimdF64<2> = "[0.5, -1e3]".parse().unwrap();
+    assert_eq!(v, SimdF64::from_array([0.5, -1000.]));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn round_trip() {
+    let v = SimdU8::<8>::from_array([0, 1, 2, 3, 250, 251, 254, 255]);
+    assert_eq!(format!("{:?}", v).parse::<SimdU8<8>>(), Ok(v));
+    assert_eq!(SimdU8::<8>::parse_lanes(&format!("{}", v)), Ok(v));
+
+    let v = SimdF64::<4>::from_array([0.1, -2.5, 1e-300, 123456.789]);
+    assert_eq!(format!("{:?}", v).parse::<SimdF64<4>>().unwrap(), v);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn errors() {
+    assert_eq!(
+        "1, 2".parse::<SimdU8<2>>(),
+        Err(ParseVectorError::MissingBrackets)
+    );
+    assert_eq!(
+        "[1, 2, 3]".parse::<SimdU8<2>>(),
+        Err(ParseVectorError::WrongLaneCount {
+            expected: 2,
+            found: 3
+        })
+    );
+    assert!(matches!(
+        "[1, 256]".parse::<SimdU8<2>>(),
+        Err(ParseVectorError::Lane { index: 1, .. })
+    ));
+    assert!(matches!(
+        "[]".parse::<SimdU8<1>>(),
+        Err(ParseVectorError::Lane { index: 0, .. })
+    ));
+    assert_eq!(
+        "[1, 2, 3]".parse::<SimdU8<2>>().unwrap_err().to_string(),
+        "expected 2 lanes, found 3"
+    );
+}
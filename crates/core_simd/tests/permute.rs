@@ -33,3 +33,64 @@ fn interleave() {
     assert_eq!(even, a);
     assert_eq!(odd, b);
 }
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rotate_lanes() {
+    let a = SimdU32::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(a.rotate_lanes_left::<0>(), a);
+    assert_eq!(
+        a.rotate_lanes_left::<3>().to_array(),
+        [3, 4, 5, 6, 7, 0, 1, 2]
+    );
+    assert_eq!(
+        a.rotate_lanes_right::<3>().to_array(),
+        [5, 6, 7, 0, 1, 2, 3, 4]
+    );
+    assert_eq!(a.rotate_lanes_left::<11>(), a.rotate_lanes_left::<3>());
+    assert_eq!(a.rotate_lanes_right::<8>(), a);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn broadcast_lane() {
+    let a = SimdU32::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(a.broadcast_lane::<0>(), SimdU32::splat(0));
+    assert_eq!(a.broadcast_lane::<7>(), SimdU32::splat(7));
+}
+
+#[test]
+#[should_panic]
+fn broadcast_invalid_lane() {
+    let a = SimdU32::from_array([0, 1, 2, 3]);
+    let _ = a.broadcast_lane::<4>();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn swizzle_macro() {
+    let a = SimdU32::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    let b = SimdU32::from_array([8, 9, 10, 11, 12, 13, 14, 15]);
+    assert_eq!(
+        core_simd::simd_swizzle!(a, [7, 6, 5, 4, 0, 0, 1, 1]).to_array(),
+        [7, 6, 5, 4, 0, 0, 1, 1]
+    );
+    assert_eq!(
+        core_simd::simd_swizzle!(a, b, [0, 8, 15, 7, 1, 9, 14, 6]).to_array(),
+        [0, 8, 15, 7, 1, 9, 14, 6]
+    );
+    assert_eq!(core_simd::simd_swizzle!(a, reverse), a.reverse());
+    assert_eq!(
+        core_simd::simd_swizzle!(a, rotate<2>).to_array(),
+        [2, 3, 4, 5, 6, 7, 0, 1]
+    );
+    assert_eq!(core_simd::simd_swizzle!(a, broadcast<5>), SimdU32::splat(5));
+    assert_eq!(
+        core_simd::simd_swizzle!(a, b, interleave_lo),
+        a.interleave(b).0
+    );
+    assert_eq!(
+        core_simd::simd_swizzle!(a, b, interleave_hi),
+        a.interleave(b).1
+    );
+}
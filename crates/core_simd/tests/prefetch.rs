@@ -0,0 +1,40 @@
+use core_simd::prefetch::{prefetch_gather, prefetch_read, prefetch_write, Locality};
+use core_simd::SimdUsize;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+const LOCALITIES: [Locality; 4] = [
+    Locality::NonTemporal,
+    Locality::Low,
+    Locality::Moderate,
+    Locality::High,
+];
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn prefetch_any_address() {
+    let data = [1u64; 16];
+    for locality in LOCALITIES.iter().copied() {
+        prefetch_read(data.as_ptr(), locality);
+        prefetch_write(data[8..].as_ptr(), locality);
+        // prefetching never faults, even for invalid addresses
+        prefetch_read(core::ptr::null::<u8>(), locality);
+        prefetch_write(usize::MAX as *const u8, locality);
+    }
+    assert_eq!(data, [1; 16]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn prefetch_gather_ignores_out_of_bounds() {
+    let data = [1u8; 16];
+    let idxs = SimdUsize::<4>::from_array([0, 15, 16, usize::MAX]);
+    for locality in LOCALITIES.iter().copied() {
+        prefetch_gather(&data, idxs, locality);
+    }
+    prefetch_gather::<u8, 4>(&[], idxs, Locality::High);
+}
@@ -0,0 +1,43 @@
+use core_simd::{SimdF32, SimdI8, SimdReduce, SimdReduceBits, SimdU32};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn sum_and_range<V: SimdReduce>(v: V) -> (V::Scalar, V::Scalar, V::Scalar) {
+    (v.reduce_sum(), v.reduce_min(), v.reduce_max())
+}
+
+fn all_bits<V: SimdReduceBits>(v: V) -> (V::Scalar, V::Scalar, V::Scalar) {
+    (v.reduce_and(), v.reduce_or(), v.reduce_xor())
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generic_reductions() {
+    let a = SimdU32::from_array([3, 1, 4, 1]);
+    assert_eq!(sum_and_range(a), (9, 1, 4));
+    assert_eq!(a.reduce_product(), a.horizontal_product());
+
+    let b = SimdI8::from_array([100, 100, -7, 2]);
+    assert_eq!(sum_and_range(b), (-61, -7, 100));
+
+    let c = SimdF32::from_array([0.5, -2., 1.5, 4.]);
+    let (sum, min, max) = sum_and_range(c);
+    assert_eq!(sum.to_bits(), 4f32.to_bits());
+    assert_eq!(min.to_bits(), (-2f32).to_bits());
+    assert_eq!(max.to_bits(), 4f32.to_bits());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generic_bit_reductions() {
+    let a = SimdU32::from_array([0b1100, 0b1010]);
+    assert_eq!(all_bits(a), (0b1000, 0b1110, 0b0110));
+    assert_eq!(
+        all_bits(a),
+        (a.horizontal_and(), a.horizontal_or(), a.horizontal_xor())
+    );
+}
@@ -0,0 +1,56 @@
+use core::ops::{Add, BitAnd, Mul, Neg};
+use core_simd::{Mask32, SimdF32, SimdI32};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn add_refs<T>(a: &T, b: &T) -> T
+where
+    for<'a> &'a T: Add<&'a T, Output = T>,
+{
+    a + b
+}
+
+fn mul_add_refs<T>(a: &T, b: &T, c: T) -> T
+where
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+    T: for<'a> Add<&'a T, Output = T>,
+{
+    a * b + &c
+}
+
+fn and_refs<T>(a: &T, b: &T) -> T
+where
+    for<'a> &'a T: BitAnd<&'a T, Output = T>,
+{
+    a & b
+}
+
+fn neg_ref<T>(a: &T) -> T
+where
+    for<'a> &'a T: Neg<Output = T>,
+{
+    -a
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generic_over_references() {
+    let a = SimdI32::<4>::from_array([1, 2, 3, 4]);
+    let b = SimdI32::<4>::splat(2);
+    assert_eq!(add_refs(&a, &b), a + b);
+    assert_eq!(mul_add_refs(&a, &b, a), a * b + a);
+    assert_eq!(and_refs(&a, &b), a & b);
+    assert_eq!(neg_ref(&a), -a);
+
+    let floats = SimdF32::<4>::from_array([1., 2., 3., 4.]);
+    assert_eq!(add_refs(&floats, &floats), floats + floats);
+    assert_eq!(neg_ref(&floats), -floats);
+
+    let mask_a = Mask32::<4>::from_array([true, false, true, false]);
+    let mask_b = Mask32::<4>::from_array([true, true, false, false]);
+    assert_eq!(and_refs(&mask_a, &mask_b), mask_a & mask_b);
+}
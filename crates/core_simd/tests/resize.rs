@@ -0,0 +1,59 @@
+use core_simd::{Mask16, Mask8, SimdF64, SimdI16, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn vector_resize() {
+    let a = SimdI16::from_array([1, 2, 3, 4]);
+    assert_eq!(a.resize::<1>(0).to_array(), [1]);
+    assert_eq!(a.resize::<4>(0), a);
+    assert_eq!(a.resize::<8>(-1).to_array(), [1, 2, 3, 4, -1, -1, -1, -1]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn vector_split_concat() {
+    test_helpers::test_1(&|x: [u8; 32]| {
+        let v = SimdU8::from_array(x);
+        let (lo, hi) = v.split();
+        proptest::prop_assert_eq!(lo.as_slice(), &x[..16]);
+        proptest::prop_assert_eq!(hi.as_slice(), &x[16..]);
+        proptest::prop_assert_eq!(lo.concat(hi), v);
+
+        let (lo, hi) = SimdU8::from_array([x[0], x[1]]).split();
+        proptest::prop_assert_eq!((lo[0], hi[0]), (x[0], x[1]));
+        proptest::prop_assert_eq!(lo.concat(hi).to_array(), [x[0], x[1]]);
+        Ok(())
+    });
+
+    let (lo, hi) = SimdF64::from_array([1., 2., 3., 4.]).split();
+    assert_eq!(hi.concat(lo), SimdF64::from_array([3., 4., 1., 2.]));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mask_resize_split_concat() {
+    let values = [true, false, false, true, true, true, false, true];
+    let mask = Mask8::from_array(values);
+    assert_eq!(mask.resize::<4>(false).to_array(), values[..4]);
+    assert_eq!(
+        mask.resize::<16>(true).to_array(),
+        [
+            true, false, false, true, true, true, false, true, true, true, true, true, true, true,
+            true, true
+        ]
+    );
+
+    let (lo, hi) = mask.split();
+    assert_eq!(lo.to_array(), values[..4]);
+    assert_eq!(hi.to_array(), values[4..]);
+    assert_eq!(lo.concat(hi), mask);
+
+    let (lo, hi) = Mask16::<2>::from_array([false, true]).split();
+    assert_eq!((lo.test(0), hi.test(0)), (false, true));
+}
@@ -0,0 +1,84 @@
+use core_simd::{rng::Xoshiro256PlusPlus, SimdU64};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// The scalar reference implementation of xoshiro256++.
+fn next(s: &mut [u64; 4]) -> u64 {
+    let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+    let t = s[1] << 17;
+    s[2] ^= s[0];
+    s[3] ^= s[1];
+    s[1] ^= s[2];
+    s[0] ^= s[3];
+    s[2] ^= t;
+    s[3] = s[3].rotate_left(45);
+    result
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn matches_scalar() {
+    let mut scalar = [[1, 2, 3, 4], [5, 6, 7, 8]];
+    let mut rng = Xoshiro256PlusPlus::from_state([
+        SimdU64::from_array([1, 5]),
+        SimdU64::from_array([2, 6]),
+        SimdU64::from_array([3, 7]),
+        SimdU64::from_array([4, 8]),
+    ]);
+    for _ in 0..100 {
+        let expected = [next(&mut scalar[0]), next(&mut scalar[1])];
+        assert_eq!(rng.next_u64().to_array(), expected);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seeded_lanes_differ() {
+    let mut rng = Xoshiro256PlusPlus::<4>::seed_from_u64(0);
+    let x = rng.next_u64().to_array();
+    for i in 1..4 {
+        assert!(!x[..i].contains(&x[i]));
+    }
+    let mut again = Xoshiro256PlusPlus::<4>::seed_from_u64(0);
+    assert_eq!(again.next_u64().to_array(), x);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn floats_in_unit_interval() {
+    let mut rng = Xoshiro256PlusPlus::<8>::seed_from_u64(1234);
+    let mut sum = 0.;
+    for _ in 0..1000 {
+        let x = rng.next_f64();
+        let y = rng.next_f32();
+        assert!(x.to_array().iter().all(|x| (0. ..1.).contains(x)));
+        assert!(y.to_array().iter().all(|y| (0. ..1.).contains(y)));
+        sum += x.horizontal_sum();
+    }
+    let mean = sum / 8000.;
+    assert!((mean - 0.5).abs() < 0.02, "mean {}", mean);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn next_u32_is_upper_half() {
+    let mut a = Xoshiro256PlusPlus::<4>::seed_from_u64(7);
+    let mut b = a.clone();
+    let wide = a.next_u64().to_array();
+    let narrow = b.next_u32().to_array();
+    for (wide, narrow) in wide.iter().zip(narrow.iter()) {
+        assert_eq!((wide >> 32) as u32, *narrow);
+    }
+}
+
+#[test]
+#[should_panic]
+fn zero_state() {
+    let zero = SimdU64::<2>::splat(0);
+    let one = SimdU64::from_array([1, 0]);
+    let _ = Xoshiro256PlusPlus::from_state([one, zero, zero, zero]);
+}
@@ -0,0 +1,56 @@
+use core_simd::{SimdF32, SimdF64};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! rsqrt_tests {
+    { $name:ident, $vector:ident, $scalar:ty, $error:expr } => {
+        mod $name {
+            use super::*;
+
+            fn max_error(x: $vector<4>, y: $vector<4>) -> f64 {
+                let mut max = 0f64;
+                for lane in 0..4 {
+                    let exact = 1. / (x[lane] as f64).sqrt();
+                    max = max.max((y[lane] as f64 / exact - 1.).abs());
+                }
+                max
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn within_error_bounds() {
+                test_helpers::test_1(&|x: [$scalar; 4]| {
+                    let x = $vector::from_array(x).abs();
+                    if x.lanes_lt($vector::splat(<$scalar>::MIN_POSITIVE)).any()
+                        || !x.is_finite().all()
+                    {
+                        return Ok(());
+                    }
+                    let estimate = x.rsqrt_estimate();
+                    proptest::prop_assert!(max_error(x, estimate) <= 0.035);
+                    proptest::prop_assert!(max_error(x, x.rsqrt_refine(estimate)) <= 0.0018);
+                    proptest::prop_assert!(max_error(x, x.rsqrt_refine2(estimate)) <= 0.000005);
+                    proptest::prop_assert!(max_error(x, x.rsqrt_fast()) <= $error);
+                    Ok(())
+                });
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn refine_improves_any_estimate() {
+                let x = $vector::from_array([2., 3., 1e10, 1e-10]);
+                let rough = x.rsqrt_estimate() * $vector::splat(1.05);
+                let refined = x.rsqrt_refine(rough);
+                assert!(max_error(x, refined) < max_error(x, rough) / 5.);
+                assert!(max_error(x, x.rsqrt_refine2(rough)) < max_error(x, refined) / 5.);
+            }
+        }
+    }
+}
+
+rsqrt_tests! { f32, SimdF32, f32, 2f64.powi(-17) }
+rsqrt_tests! { f64, SimdF64, f64, 2f64.powi(-34) }
@@ -0,0 +1,32 @@
+use core_simd::{Saturating, SimdI32, SimdU16};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unsigned() {
+    let mut x = Saturating(SimdU16::from_array([0, 1, u16::MAX - 1, u16::MAX]));
+    x += Saturating(SimdU16::splat(2));
+    assert_eq!(x.0.to_array(), [2, 3, u16::MAX, u16::MAX]);
+    x -= Saturating(SimdU16::splat(3));
+    assert_eq!(x.0.to_array(), [0, 0, u16::MAX - 3, u16::MAX - 3]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn signed() {
+    let x = Saturating(SimdI32::from_array([i32::MIN, -1, 0, i32::MAX]));
+    assert_eq!((-x).0.to_array(), [i32::MAX, 1, 0, -i32::MAX]);
+    assert_eq!(
+        (x + Saturating(SimdI32::splat(1))).0.to_array(),
+        [i32::MIN + 1, 0, 1, i32::MAX]
+    );
+    assert_eq!(
+        (x - Saturating(SimdI32::splat(1))).0.to_array(),
+        [i32::MIN, -2, -1, i32::MAX - 1]
+    );
+}
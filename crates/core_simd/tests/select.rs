@@ -0,0 +1,115 @@
+use core_simd::{Mask16, Mask64, Mask8, MaskSize, SimdF64, SimdI16, SimdU32, SimdU8, SimdUsize};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn select_bitmask() {
+    test_helpers::test_3(&|a: [u8; 32], b: [u8; 32], bitmask: u32| {
+        let selected = SimdU8::select_bitmask(bitmask, a.into(), b.into()).to_array();
+        for (lane, selected) in selected.iter().enumerate() {
+            let expected = if bitmask & (1 << lane) != 0 {
+                a[lane]
+            } else {
+                b[lane]
+            };
+            proptest::prop_assert_eq!(*selected, expected);
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn select_bitmask_narrow() {
+    let a = SimdF64::from_array([1., 2.]);
+    let b = SimdF64::from_array([3., 4.]);
+    let to_bits = |v: SimdF64<2>| v.to_bits().to_array();
+    assert_eq!(
+        to_bits(SimdF64::select_bitmask(0b01, a, b)),
+        [1f64.to_bits(), 4f64.to_bits()]
+    );
+    assert_eq!(
+        to_bits(SimdF64::select_bitmask(0b10, a, b)),
+        [3f64.to_bits(), 2f64.to_bits()]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn bitwise_select() {
+    test_helpers::test_3(&|m: [u32; 8], a: [u32; 8], b: [u32; 8]| {
+        let selected = SimdU32::bitwise_select(m.into(), a.into(), b.into()).to_array();
+        for lane in 0..8 {
+            proptest::prop_assert_eq!(selected[lane], (a[lane] & m[lane]) | (b[lane] & !m[lane]));
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn bitwise_select_signed() {
+    let m = SimdI16::from_array([-1, 0, 0x00ff, i16::MIN]);
+    let a = SimdI16::splat(0x1234);
+    let b = SimdI16::splat(-1);
+    assert_eq!(
+        SimdI16::bitwise_select(m, a, b).to_array(),
+        [0x1234, -1, -256 | 0x34, 0x7fff]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn select_or() {
+    test_helpers::test_3(&|a: [i16; 8], bitmask: u8, b: i16| {
+        let mask = Mask16::from_bitmask([bitmask]);
+        let selected = mask.select_or(SimdI16::from_array(a), b).to_array();
+        let zeroed = mask.select_or_default(SimdI16::from_array(a)).to_array();
+        for lane in 0..8 {
+            proptest::prop_assert_eq!(selected[lane], if mask.test(lane) { a[lane] } else { b });
+            proptest::prop_assert_eq!(zeroed[lane], if mask.test(lane) { a[lane] } else { 0 });
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mask_dot() {
+    test_helpers::test_2(&|bitmask: u16, values: [i16; 16]| {
+        let expected = values
+            .iter()
+            .enumerate()
+            .filter(|(lane, _)| bitmask & (1 << lane) != 0)
+            .fold(0i16, |sum, (_, value)| sum.wrapping_add(*value));
+        let mask = Mask16::<16>::from_bitmask(bitmask.to_le_bytes());
+        proptest::prop_assert_eq!(mask.dot(SimdI16::from_array(values)), expected);
+        Ok(())
+    });
+    let values = SimdF64::from_array([0.5, f64::NAN]);
+    let sum = Mask64::from_array([true, false]).dot(values);
+    assert_eq!(sum.to_bits(), 0.5f64.to_bits());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn incr_decr_where() {
+    test_helpers::test_2(&|bitmask: u8, values: [u8; 8]| {
+        let mask = Mask8::<8>::from_bitmask([bitmask]);
+        let incremented = SimdU8::from_array(values).incr_where(mask).to_array();
+        let decremented = SimdU8::from_array(values).decr_where(mask).to_array();
+        for lane in 0..8 {
+            let set = u8::from(bitmask & (1 << lane) != 0);
+            proptest::prop_assert_eq!(incremented[lane], values[lane].wrapping_add(set));
+            proptest::prop_assert_eq!(decremented[lane], values[lane].wrapping_sub(set));
+        }
+        Ok(())
+    });
+    let counts = SimdUsize::<2>::splat(usize::MAX).incr_where(MaskSize::from_array([true, false]));
+    assert_eq!(counts.to_array(), [0, usize::MAX]);
+}
@@ -0,0 +1,213 @@
+use core_simd::{slice_ops, SimdF64, SimdI32, SimdU16, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+// Long enough to contain several unrolled iterations, an unaligned head, and a tail
+const LEN: usize = 137;
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn integer_reductions() {
+    test_helpers::test_1(&|values: [i32; LEN]| {
+        for start in 0..8 {
+            let slice = &values[start..];
+            let sum = slice.iter().fold(0i32, |acc, x| acc.wrapping_add(*x));
+            let min = slice.iter().copied().min();
+            let max = slice.iter().copied().max();
+            proptest::prop_assert_eq!(slice_ops::sum(slice), sum);
+            proptest::prop_assert_eq!(slice_ops::min(slice), min);
+            proptest::prop_assert_eq!(slice_ops::max(slice), max);
+            proptest::prop_assert_eq!(slice_ops::min_max(slice), min.zip(max));
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn small_integer_reductions() {
+    test_helpers::test_1(&|values: [u8; LEN]| {
+        for end in 0..LEN {
+            let slice = &values[..end];
+            let sum = slice.iter().fold(0u8, |acc, x| acc.wrapping_add(*x));
+            proptest::prop_assert_eq!(slice_ops::sum(slice), sum);
+            proptest::prop_assert_eq!(slice_ops::min(slice), slice.iter().copied().min());
+            proptest::prop_assert_eq!(slice_ops::max(slice), slice.iter().copied().max());
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn float_reductions() {
+    // small integers sum exactly, regardless of order
+    let values: Vec<f64> = (0..LEN).map(|x| (x % 17) as f64 - 8.).collect();
+    for start in 0..4 {
+        let slice = &values[start..];
+        let sum: f64 = slice.iter().sum();
+        assert_eq!(slice_ops::sum(slice) as i64, sum as i64);
+        assert_eq!(
+            slice_ops::min_max(slice).map(|(min, max)| (min as i64, max as i64)),
+            Some((-8, 8))
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn float_nan() {
+    let mut values = [f32::NAN; LEN];
+    assert!(slice_ops::min(&values).unwrap().is_nan());
+    assert!(slice_ops::max(&values).unwrap().is_nan());
+    values[100] = 1.;
+    values[3] = -1.;
+    assert_eq!(slice_ops::min(&values).map(|x| x as i32), Some(-1));
+    assert_eq!(slice_ops::max(&values).map(|x| x as i32), Some(1));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn empty() {
+    assert_eq!(slice_ops::sum::<i64>(&[]), 0);
+    assert_eq!(slice_ops::min::<u16>(&[]), None);
+    assert_eq!(slice_ops::max::<i8>(&[]), None);
+    assert!(slice_ops::min_max::<f32>(&[]).is_none());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn vector_reductions() {
+    test_helpers::test_1(&|values: [i32; LEN]| {
+        let vectors: Vec<SimdI32<4>> = values.chunks_exact(4).map(SimdI32::from_slice).collect();
+        for count in 0..=vectors.len() {
+            let lanes = &values[..count * 4];
+            let sum = lanes.iter().fold(0i32, |acc, x| acc.wrapping_add(*x));
+            let vectors = &vectors[..count];
+            proptest::prop_assert_eq!(slice_ops::sum_vectors(vectors), sum);
+            proptest::prop_assert_eq!(slice_ops::min_vectors(vectors), lanes.iter().copied().min());
+            proptest::prop_assert_eq!(slice_ops::max_vectors(vectors), lanes.iter().copied().max());
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn float_vector_reductions() {
+    // small integers sum exactly, regardless of order
+    let mut vectors = [SimdF64::<2>::splat(0.); 9];
+    for (i, v) in vectors.iter_mut().enumerate() {
+        *v = SimdF64::from_array([i as f64, -(i as f64) / 2.]);
+    }
+    assert_eq!(slice_ops::sum_vectors(&vectors), 18.);
+    assert_eq!(slice_ops::min_vectors(&vectors), Some(-4.));
+    assert_eq!(slice_ops::max_vectors(&vectors), Some(8.));
+    assert_eq!(slice_ops::sum_vectors::<f64, 2>(&[]), 0.);
+    assert_eq!(slice_ops::min_vectors::<f64, 2>(&[]), None);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn dot() {
+    // small integers multiply and sum exactly, regardless of order
+    let a: Vec<f32> = (0..LEN).map(|x| (x % 7) as f32 - 3.).collect();
+    let b: Vec<f32> = (0..LEN).map(|x| (x % 5) as f32 - 2.).collect();
+    for start in 0..LEN {
+        let (a, b) = (&a[start..], &b[..LEN - start]);
+        let expected: f32 = a.iter().zip(b).map(|(a, b)| a * b).sum();
+        assert_eq!(slice_ops::dot(a, b) as i32, expected as i32);
+    }
+}
+
+#[test]
+#[should_panic]
+fn dot_length_mismatch() {
+    slice_ops::dot(&[1f64, 2.], &[1.]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn byte_eq_cmp() {
+    test_helpers::test_2(&|a: [u8; LEN], b: [u8; LEN]| {
+        for end in 0..LEN {
+            proptest::prop_assert_eq!(slice_ops::eq(&a, &b[..end]), a[..] == b[..end]);
+            proptest::prop_assert_eq!(slice_ops::cmp(&a, &b[..end]), a[..].cmp(&b[..end]));
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn byte_mismatch_position() {
+    let a = [7u8; LEN];
+    for i in 0..LEN {
+        let mut b = a;
+        b[i] = 8;
+        assert!(slice_ops::eq(&a, &a));
+        assert!(!slice_ops::eq(&a, &b));
+        assert_eq!(slice_ops::cmp(&a, &b), core::cmp::Ordering::Less);
+        assert_eq!(
+            slice_ops::cmp(&b[i..], &a[i..]),
+            core::cmp::Ordering::Greater
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn position() {
+    test_helpers::test_1(&|values: [u8; LEN]| {
+        for start in 0..8 {
+            let slice = &values[start..];
+            for needle in [0u8, 1, 255, slice[slice.len() - 1]].iter().copied() {
+                proptest::prop_assert_eq!(
+                    slice_ops::position(slice, |v| v.lanes_eq(SimdU8::splat(needle))),
+                    slice.iter().position(|x| *x == needle)
+                );
+            }
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn position_ignores_padding() {
+    // the padding is zero, so a predicate matching zero must not match past the end
+    let values = [1u8; LEN];
+    for start in 0..8 {
+        for end in start..LEN {
+            let slice = &values[start..end];
+            assert_eq!(
+                slice_ops::position(slice, |v| v.lanes_eq(SimdU8::splat(0))),
+                None
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn map_in_place() {
+    test_helpers::test_1(&|values: [u16; LEN]| {
+        for start in 0..8 {
+            let mut mapped = values;
+            slice_ops::map_in_place(
+                &mut mapped[start..],
+                |v: SimdU16<8>| v.saturating_add(SimdU16::splat(1000)),
+                |x| x.saturating_add(1000),
+            );
+            proptest::prop_assert_eq!(&mapped[..start], &values[..start]);
+            for (mapped, value) in mapped[start..].iter().zip(values[start..].iter()) {
+                proptest::prop_assert_eq!(*mapped, value.saturating_add(1000));
+            }
+        }
+        Ok(())
+    });
+}
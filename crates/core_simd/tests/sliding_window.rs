@@ -0,0 +1,42 @@
+use core_simd::SimdI32;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sliding_window() {
+    test_helpers::test_2(&|a: [i32; 8], b: [i32; 8]| {
+        let joined: Vec<i32> = a.iter().chain(b.iter()).copied().collect();
+        let (a, b) = (SimdI32::from_array(a), SimdI32::from_array(b));
+        for offset in 0..=8 {
+            proptest::prop_assert_eq!(
+                a.sliding_window(b, offset).to_array(),
+                &joined[offset..offset + 8]
+            );
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sliding_windows() {
+    let a = SimdI32::<4>::from_array([0, 1, 2, 3]);
+    let b = SimdI32::<4>::from_array([4, 5, 6, 7]);
+    let windows = a.sliding_windows::<5>(b);
+    for (offset, window) in windows.iter().enumerate() {
+        assert_eq!(*window, a.sliding_window(b, offset));
+    }
+    assert_eq!(a.sliding_windows::<0>(b), []);
+}
+
+#[test]
+#[should_panic]
+fn sliding_window_out_of_bounds() {
+    let a = SimdI32::<4>::splat(0);
+    let _ = a.sliding_window(a, 5);
+}
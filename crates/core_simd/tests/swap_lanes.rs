@@ -0,0 +1,53 @@
+use core_simd::{SimdF32, SimdI8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn swap_lanes() {
+    test_helpers::test_3(&|x: [i8; 16], a: usize, b: usize| {
+        let (a, b) = (a % 16, b % 16);
+        let mut expected = x;
+        expected.swap(a, b);
+        proptest::prop_assert_eq!(SimdI8::from_array(x).swap_lanes(a, b).to_array(), expected);
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn swap_lane_ranges() {
+    let v = SimdI8::<8>::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(
+        v.swap_lane_ranges(6, 0, 2).to_array(),
+        [6, 7, 2, 3, 4, 5, 0, 1]
+    );
+    assert_eq!(
+        v.swap_lane_ranges(2, 3, 1).to_array(),
+        [0, 1, 3, 2, 4, 5, 6, 7]
+    );
+    assert_eq!(v.swap_lane_ranges(5, 5, 0), v);
+    assert_eq!(v.swap_lane_ranges(8, 0, 0), v);
+}
+
+#[test]
+#[should_panic]
+fn swap_lanes_out_of_bounds() {
+    let _ = SimdF32::<4>::splat(0.).swap_lanes(0, 4);
+}
+
+#[test]
+#[should_panic]
+fn swap_lane_ranges_overlap() {
+    let _ = SimdI8::<8>::splat(0).swap_lane_ranges(0, 2, 3);
+}
+
+#[test]
+#[should_panic]
+fn swap_lane_ranges_out_of_bounds() {
+    let _ = SimdI8::<8>::splat(0).swap_lane_ranges(0, 6, 3);
+}
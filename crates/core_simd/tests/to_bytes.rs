@@ -1,4 +1,4 @@
-use core_simd::SimdU32;
+use core_simd::{SimdI16, SimdU16, SimdU32, SimdU64, SimdU8};
 
 #[test]
 fn byte_convert() {
@@ -8,3 +8,97 @@ fn byte_convert() {
     assert_eq!(int[1].to_ne_bytes(), bytes[4..]);
     assert_eq!(SimdU32::from_ne_bytes(bytes), int);
 }
+
+#[test]
+fn reverse_bytes() {
+    let int = SimdU32::from_array([0x01020304, 0x05060708]);
+    assert_eq!(int.reverse_bytes().to_array(), [0x08070605, 0x04030201]);
+    assert_eq!(int.reverse_bytes().reverse_bytes(), int);
+
+    let bytes = int.to_ne_bytes().to_array();
+    let mut reversed = bytes;
+    reversed.reverse();
+    assert_eq!(int.reverse_bytes().to_ne_bytes().to_array(), reversed);
+}
+
+#[test]
+fn reverse_byte_groups() {
+    test_helpers::test_1(&|bytes: [u8; 32]| {
+        let bytes = SimdU8::from_array(bytes);
+        let mut swap16 = SimdU16::<16>::from_ne_bytes(bytes);
+        let mut swap32 = SimdU32::<8>::from_ne_bytes(bytes);
+        let mut swap64 = SimdU64::<4>::from_ne_bytes(bytes);
+        swap16
+            .as_mut_slice()
+            .iter_mut()
+            .for_each(|x| *x = x.swap_bytes());
+        swap32
+            .as_mut_slice()
+            .iter_mut()
+            .for_each(|x| *x = x.swap_bytes());
+        swap64
+            .as_mut_slice()
+            .iter_mut()
+            .for_each(|x| *x = x.swap_bytes());
+        proptest::prop_assert_eq!(bytes.reverse_byte_groups::<2>(), swap16.to_ne_bytes());
+        proptest::prop_assert_eq!(bytes.reverse_byte_groups::<4>(), swap32.to_ne_bytes());
+        proptest::prop_assert_eq!(bytes.reverse_byte_groups::<8>(), swap64.to_ne_bytes());
+        Ok(())
+    });
+}
+
+#[test]
+fn from_endian_slice() {
+    test_helpers::test_1(&|bytes: [u8; 33]| {
+        // load from an odd offset, which is never aligned
+        let slice = &bytes[1..];
+        let mut le = [0u32; 8];
+        let mut be = [0u32; 8];
+        for (i, chunk) in slice.chunks_exact(4).enumerate() {
+            le[i] = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            be[i] = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        proptest::prop_assert_eq!(SimdU32::<8>::from_le_slice(slice).to_array(), le);
+        proptest::prop_assert_eq!(SimdU32::<8>::from_be_slice(slice).to_array(), be);
+        Ok(())
+    });
+}
+
+#[test]
+fn from_endian_slice_widths() {
+    let bytes = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0xff];
+    assert_eq!(
+        SimdU16::<4>::from_be_slice(&bytes).to_array(),
+        [0x0102, 0x0304, 0x0506, 0x0708]
+    );
+    assert_eq!(
+        SimdI16::<4>::from_le_slice(&bytes).to_array(),
+        [0x0201, 0x0403, 0x0605, 0x0807]
+    );
+    assert_eq!(
+        SimdU64::<1>::from_be_slice(&bytes).to_array(),
+        [0x0102030405060708]
+    );
+    assert_eq!(
+        SimdU16::<3>::from_be_slice(&bytes).to_array(),
+        [0x0102, 0x0304, 0x0506]
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_endian_slice_too_short() {
+    let _ = SimdU32::<4>::from_le_slice(&[0; 15]);
+}
+
+#[test]
+fn byte_convert_64_lanes() {
+    let mut array = [0; 64];
+    for (i, x) in array.iter_mut().enumerate() {
+        *x = i as u8;
+    }
+    let bytes = SimdU8::<64>::from_array(array);
+    let ints = SimdU64::<8>::from_ne_bytes(bytes);
+    assert_eq!(ints.to_ne_bytes(), bytes);
+    assert_eq!(ints[1].to_ne_bytes(), [8, 9, 10, 11, 12, 13, 14, 15]);
+}
@@ -0,0 +1,89 @@
+use core_simd::SimdF32;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Returns the distance between two floats in ULPs, or 0 if both are NaN.
+fn ulps(a: f32, b: f32) -> u32 {
+    if a.is_nan() && b.is_nan() {
+        return 0;
+    }
+    // map the bits to a monotonic integer ordering
+    let ordered = |x: f32| {
+        let bits = x.to_bits() as i32;
+        if bits < 0 {
+            i32::MIN - bits
+        } else {
+            bits
+        }
+    };
+    (ordered(a) as i64 - ordered(b) as i64).abs() as u32
+}
+
+fn check(x: [f32; 4]) -> proptest::test_runner::TestCaseResult {
+    let (sin, cos) = SimdF32::from_array(x).sin_cos();
+    for lane in 0..4 {
+        let expected_sin = (x[lane] as f64).sin() as f32;
+        let expected_cos = (x[lane] as f64).cos() as f32;
+        proptest::prop_assert!(
+            ulps(sin[lane], expected_sin) <= 1,
+            "sin({:e}) = {:e}, expected {:e}",
+            x[lane],
+            sin[lane],
+            expected_sin
+        );
+        proptest::prop_assert!(
+            ulps(cos[lane], expected_cos) <= 1,
+            "cos({:e}) = {:e}, expected {:e}",
+            x[lane],
+            cos[lane],
+            expected_cos
+        );
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sin_cos_within_1_ulp() {
+    test_helpers::test_1(&check);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sin_cos_large() {
+    // every binade up to the largest finite value, near the limit of Cody-Waite reduction, and
+    // near multiples of pi/2
+    let mut values = Vec::new();
+    for exponent in -10..=127 {
+        let x = 2f32.powi(exponent);
+        values.extend([x, x * 1.234_567, f32::from_bits(x.to_bits() - 1)].iter());
+    }
+    values.extend([f32::MAX, 268_435_456., 268_435_440., 421_657_440.].iter());
+    for n in [1., 2., 3., 100., 1e4, 1e6, 1e8].iter() {
+        let x = (n * core::f64::consts::FRAC_PI_2) as f32;
+        values.extend([x, f32::from_bits(x.to_bits() + 1)].iter());
+    }
+    let negated: Vec<f32> = values.iter().map(|x| -x).collect();
+    values.extend(negated);
+    for chunk in values.chunks_exact(4) {
+        check([chunk[0], chunk[1], chunk[2], chunk[3]]).unwrap();
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sin_cos_special() {
+    let x = SimdF32::from_array([0., -0., f32::INFINITY, f32::NAN]);
+    let (sin, cos) = x.sin_cos();
+    assert_eq!(sin[0].to_bits(), 0f32.to_bits());
+    assert_eq!(sin[1].to_bits(), (-0f32).to_bits());
+    assert_eq!(cos.to_bits().to_array()[..2], [1f32.to_bits(); 2]);
+    assert!(sin[2].is_nan() && cos[2].is_nan());
+    assert!(sin[3].is_nan() && cos[3].is_nan());
+    assert_eq!(x.sin().to_bits(), sin.to_bits());
+    assert_eq!(x.cos().to_bits(), cos.to_bits());
+}
@@ -0,0 +1,85 @@
+use core_simd::{SimdI64, SimdU32};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn read_unaligned() {
+    let values: [u32; 9] = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+    // offset by one element, which can't be aligned to the vector
+    let v = unsafe { SimdU32::<8>::read_unaligned(values[1..].as_ptr()) };
+    assert_eq!(v.to_array(), [1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn write_unaligned() {
+    let mut values = [0i64; 5];
+    let v = SimdI64::<4>::from_array([1, 2, 3, 4]);
+    unsafe { v.write_unaligned(values[1..].as_mut_ptr()) };
+    assert_eq!(values, [0, 1, 2, 3, 4]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn write_nontemporal() {
+    let mut values = [SimdU32::<8>::splat(0); 3];
+    for (i, v) in values.iter_mut().enumerate() {
+        unsafe { SimdU32::splat(i as u32 + 1).write_nontemporal(v) };
+    }
+    #[cfg(target_arch = "x86_64")]
+    unsafe {
+        core::arch::x86_64::_mm_sfence()
+    };
+    assert_eq!(values[0], SimdU32::splat(1));
+    assert_eq!(values[2], SimdU32::splat(3));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn fill_uninit() {
+    // fill lanes from a source shorter than the vector, then pad
+    let source = [3i64, 1, 4];
+    let mut v = SimdI64::<4>::uninit();
+    let lanes = SimdI64::uninit_lanes(&mut v);
+    for (lane, value) in lanes.iter_mut().zip(source.iter()) {
+        *lane = core::mem::MaybeUninit::new(*value);
+    }
+    for lane in lanes[source.len()..].iter_mut() {
+        *lane = core::mem::MaybeUninit::new(-1);
+    }
+    let v = unsafe { v.assume_init() };
+    assert_eq!(v.to_array(), [3, 1, 4, -1]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn slice_round_trip() {
+    let values: [u32; 9] = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+    let v = SimdU32::<8>::from_slice(&values[1..]);
+    assert_eq!(v.to_array(), [1, 2, 3, 4, 5, 6, 7, 8]);
+
+    let mut out = [0u32; 10];
+    v.copy_to_slice(&mut out[2..]);
+    assert_eq!(out, [0, 0, 1, 2, 3, 4, 5, 6, 7, 8]);
+
+    let mut out = [0i64; 4];
+    unsafe { SimdI64::<4>::splat(-1).copy_to_slice_unchecked(&mut out) };
+    assert_eq!(out, [-1; 4]);
+}
+
+#[test]
+#[should_panic]
+fn from_slice_too_short() {
+    SimdU32::<4>::from_slice(&[1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn copy_to_slice_too_short() {
+    SimdU32::<4>::splat(1).copy_to_slice(&mut [0; 3]);
+}
@@ -0,0 +1,93 @@
+use core_simd::{utf16, SimdU16, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn widen_narrow() {
+    test_helpers::test_1(&|x: [u8; 16]| {
+        let bytes = SimdU8::from_array(x);
+        let units = utf16::widen(bytes);
+        for (unit, byte) in units.to_array().iter().zip(x.iter()) {
+            proptest::prop_assert_eq!(*unit, *byte as u16);
+        }
+        proptest::prop_assert!(utf16::is_latin1(units).all());
+        proptest::prop_assert_eq!(utf16::narrow(units), bytes);
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn classify() {
+    test_helpers::test_1(&|x: [u16; 16]| {
+        let units = SimdU16::from_array(x);
+        let ascii = utf16::is_ascii(units).to_array();
+        let latin1 = utf16::is_latin1(units).to_array();
+        let surrogate = utf16::is_surrogate(units).to_array();
+        let high = utf16::is_high_surrogate(units).to_array();
+        let low = utf16::is_low_surrogate(units).to_array();
+        let lengths = utf16::utf8_lengths(units).to_array();
+        for i in 0..16 {
+            let c = char::from_u32(x[i] as u32);
+            proptest::prop_assert_eq!(ascii[i], x[i] < 0x80);
+            proptest::prop_assert_eq!(latin1[i], x[i] < 0x100);
+            proptest::prop_assert_eq!(surrogate[i], c.is_none());
+            proptest::prop_assert_eq!(high[i], (0xd800..0xdc00).contains(&x[i]));
+            proptest::prop_assert_eq!(low[i], (0xdc00..0xe000).contains(&x[i]));
+            proptest::prop_assert_eq!(lengths[i] as usize, c.map_or(2, char::len_utf8));
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unpaired_surrogates() {
+    fn unpaired(units: &[u16]) -> bool {
+        let mut previous = SimdU16::<4>::splat(0);
+        let mut found = false;
+        for chunk in units.chunks(4) {
+            let mut padded = [0; 4];
+            padded[..chunk.len()].copy_from_slice(chunk);
+            let input = SimdU16::from_array(padded);
+            found |= utf16::unpaired_surrogates(previous, input).any();
+            previous = input;
+        }
+        // a high surrogate at the end of the input is unpaired
+        found || utf16::is_high_surrogate(previous).to_array()[3]
+    }
+
+    let cases: [&[u16]; 8] = [
+        &[0x61, 0x62, 0x63],
+        &[0x61, 0x62, 0x63, 0xd83e, 0xdd80, 0x64],
+        &[0xd83e, 0xdd80, 0xd83e, 0xdd80, 0xd83e, 0xdd80],
+        &[0x61, 0xdd80],
+        &[0xd83e, 0x61],
+        &[0x61, 0x62, 0x63, 0xd83e],
+        &[0x61, 0x62, 0x63, 0xd83e, 0xd83e, 0xdd80],
+        &[0xdd80, 0xd83e],
+    ];
+    for case in cases.iter() {
+        let expected = char::decode_utf16(case.iter().copied()).any(|c| c.is_err());
+        assert_eq!(unpaired(case), expected, "{:x?}", case);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn utf8_len() {
+    let text = "ASCII, ünïcödé, 日本語, 🦀🎉, and the edges: \u{7f}\u{80}\u{7ff}\u{800}\u{ffff}\u{10000}\u{10ffff}";
+    let units: Vec<u16> = text.encode_utf16().collect();
+    for len in 0..=units.len() {
+        let prefix = &units[..len];
+        let expected: usize = char::decode_utf16(prefix.iter().copied())
+            .map(|c| c.map_or(2, char::len_utf8))
+            .sum();
+        assert_eq!(utf16::utf8_len(prefix), expected, "length {}", len);
+    }
+}
@@ -0,0 +1,119 @@
+use core_simd::utf8::{self, Utf8Validator};
+use core_simd::SimdU8;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn validate_with<const LANES: usize>(bytes: &[u8]) -> bool
+where
+    core_simd::LaneCount<LANES>: core_simd::SupportedLaneCount,
+{
+    let mut validator = Utf8Validator::<LANES>::new();
+    for chunk in bytes.chunks(LANES) {
+        let mut block = [0; LANES];
+        block[..chunk.len()].copy_from_slice(chunk);
+        validator.push(SimdU8::from_array(block));
+    }
+    validator.finish()
+}
+
+fn check(bytes: &[u8]) {
+    let expected = core::str::from_utf8(bytes).is_ok();
+    assert_eq!(utf8::validate(bytes), expected, "{:x?}", bytes);
+    assert_eq!(validate_with::<4>(bytes), expected, "{:x?}", bytes);
+    assert_eq!(validate_with::<16>(bytes), expected, "{:x?}", bytes);
+}
+
+/// Checks `bytes` at every offset, so sequences cross every vector boundary.
+fn check_all_offsets(bytes: &[u8]) {
+    for offset in 0..32 {
+        let mut padded = vec![b'x'; offset];
+        padded.extend_from_slice(bytes);
+        check(&padded);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn valid_sequences() {
+    let text = "ASCII, ünïcödé, 日本語, 🦀🎉, and the edges: \u{7f}\u{80}\u{7ff}\u{800}\u{d7ff}\u{e000}\u{fffd}\u{ffff}\u{10000}\u{10ffff}";
+    check_all_offsets(text.as_bytes());
+    check_all_offsets(b"");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn invalid_sequences() {
+    let cases: [&[u8]; 16] = [
+        b"\x80",                 // lone continuation
+        b"\xbf\x80",             // two continuations
+        b"\xc3",                 // truncated 2 byte sequence
+        b"\xc3\x28",             // lead followed by ASCII
+        b"\xc0\x80",             // overlong 2 byte
+        b"\xc1\xbf",             // overlong 2 byte
+        b"\xe0\x80\x80",         // overlong 3 byte
+        b"\xe0\x9f\xbf",         // overlong 3 byte
+        b"\xed\xa0\x80",         // surrogate
+        b"\xed\xbf\xbf",         // surrogate
+        b"\xe2\x82",             // truncated 3 byte sequence
+        b"\xf0\x8f\xbf\xbf",     // overlong 4 byte
+        b"\xf4\x90\x80\x80",     // too large
+        b"\xf5\x80\x80\x80",     // invalid lead
+        b"\xf0\x9f\x98\x80\x80", // extra continuation
+        b"\xff",                 // invalid byte
+    ];
+    for case in cases.iter() {
+        check_all_offsets(case);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn all_pairs_and_triples() {
+    for a in 0x80..=0xffu8 {
+        for b in 0..=0xffu8 {
+            check(&[a, b]);
+            check(&[a, b, 0x80]);
+            check(&[a, b, 0x80, 0x80]);
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn random_text() {
+    test_helpers::test_2(&|chars: [u32; 16], corrupt: [u8; 2]| {
+        let mut bytes = Vec::new();
+        for c in chars.iter() {
+            // bias toward multibyte characters
+            let c = char::from_u32(c % 0x11_0000).unwrap_or('\u{fffd}');
+            let mut buf = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+        }
+        proptest::prop_assert!(utf8::validate(&bytes));
+
+        // replacing a byte usually breaks the text, but not always
+        let index = corrupt[0] as usize % bytes.len();
+        bytes[index] = corrupt[1];
+        let expected = core::str::from_utf8(&bytes).is_ok();
+        proptest::prop_assert_eq!(utf8::validate(&bytes), expected);
+        proptest::prop_assert_eq!(validate_with::<8>(&bytes), expected);
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn prev_across_vectors() {
+    let previous = SimdU8::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    let input = SimdU8::from_array([8, 9, 10, 11, 12, 13, 14, 15]);
+    assert_eq!(utf8::prev(previous, input, 0), input);
+    assert_eq!(
+        utf8::prev(previous, input, 2).to_array(),
+        [6, 7, 8, 9, 10, 11, 12, 13]
+    );
+    assert_eq!(utf8::prev(previous, input, 8), previous);
+}
@@ -0,0 +1,72 @@
+#![cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#![feature(stdsimd)]
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use core::convert::TryFrom;
+use core_simd::*;
+
+#[test]
+fn vector_round_trip() {
+    let v = f32x8::from_array([1., 2., 3., 4., 5., 6., 7., 8.]);
+    let intel: __m256 = v.into();
+    assert_eq!(f32x8::from(intel).to_bits(), v.to_bits());
+
+    let v = u16x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    let intel: __m128i = v.into();
+    assert_eq!(u16x8::from(intel), v);
+
+    let v = u8x64::splat(7);
+    let intel: __m512i = v.into();
+    assert_eq!(u8x64::from(intel), v);
+    assert_eq!(
+        f64x8::from(__m512d::from(f64x8::splat(0.5))).to_array(),
+        [0.5; 8]
+    );
+}
+
+#[test]
+fn mask_round_trip() {
+    let mask = mask32x8::from_array([true, false, false, true, true, true, false, true]);
+    let intel: __m256i = mask.into();
+    assert_eq!(i32x8::from(intel), mask.to_int());
+    assert_eq!(mask32x8::try_from(intel), Ok(mask));
+
+    let mask = mask8x16::from_array([
+        true, false, true, false, false, true, true, true, false, false, false, true, true, false,
+        true, false,
+    ]);
+    let intel: __m128i = mask.into();
+    assert_eq!(mask8x16::try_from(intel), Ok(mask));
+
+    let mask = mask64x8::from_array([true, false, false, true, true, false, true, false]);
+    let intel: __m512i = mask.into();
+    assert_eq!(i64x8::from(intel), mask.to_int());
+    assert_eq!(mask64x8::try_from(intel), Ok(mask));
+}
+
+#[test]
+fn invalid_mask() {
+    let intel: __m128i = i64x2::from_array([0, 1]).into();
+    assert!(mask64x2::try_from(intel).is_err());
+}
+
+#[test]
+#[cfg(target_feature = "avx512f")]
+fn mmask_round_trip() {
+    let mask = mask32x8::from_array([true, false, false, true, true, true, false, true]);
+    let mmask: u8 = mask.into();
+    assert_eq!(mmask, 0b1011_1001);
+    assert_eq!(mask32x8::from(mmask), mask);
+
+    let mmask: u16 = 0b1000_0000_0000_0011;
+    let mask = mask8x16::from(mmask);
+    assert!(mask.test(0) && mask.test(1) && mask.test(15) && !mask.test(2));
+    assert_eq!(u16::from(mask), mmask);
+
+    let mmask = 0x8000_0001u32;
+    assert_eq!(u32::from(Mask16::<32>::from(mmask)), mmask);
+}
@@ -0,0 +1,245 @@
+//! Derive macros for `core_simd`.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, Type};
+
+/// Returns the vector type with lanes of type `ty`, if `ty` is a supported scalar.
+fn vector_type(ty: &Type) -> Option<Ident> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+    let name = match path.get_ident()?.to_string().as_str() {
+        "u8" => "SimdU8",
+        "u16" => "SimdU16",
+        "u32" => "SimdU32",
+        "u64" => "SimdU64",
+        "usize" => "SimdUsize",
+        "i8" => "SimdI8",
+        "i16" => "SimdI16",
+        "i32" => "SimdI32",
+        "i64" => "SimdI64",
+        "isize" => "SimdIsize",
+        "f32" => "SimdF32",
+        "f64" => "SimdF64",
+        _ => return None,
+    };
+    Some(Ident::new(name, Span::call_site()))
+}
+
+/// Derives a structure-of-arrays form of a struct of scalars.
+///
+/// For a struct `Name`, this generates a struct `NameSoa<const LANES: usize>` with the same
+/// fields, where each field is a vector of `LANES` values.  The struct must have named fields of
+/// primitive integer or float types, and must be `Copy`.
+///
+/// The generated struct has methods to convert to and from arrays and slices of `Name`:
+/// * `splat`, which copies one value to every lane
+/// * `from_array` and `to_array`, which transpose between `[Name; LANES]` and vectors
+/// * `from_slice` and `copy_to_slice`, which load and store the first `LANES` values of a slice
+/// * `gather` and `scatter`, which load and store the values of a slice at the given indices
+/// * `extract` and `replace`, which read and write the value in one lane
+///
+/// ```
+/// use core_simd::*;
+/// use core_simd_derive::Soa;
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, Soa)]
+/// struct Particle {
+///     x: f32,
+///     y: f32,
+///     id: u32,
+/// }
+///
+/// let particles = [
+///     Particle { x: 1., y: 2., id: 0 },
+///     Particle { x: 3., y: 4., id: 1 },
+///     Particle { x: 5., y: 6., id: 2 },
+///     Particle { x: 7., y: 8., id: 3 },
+/// ];
+/// let mut soa = ParticleSoa::<4>::from_array(particles);
+/// assert_eq!(soa.x.to_array(), [1., 3., 5., 7.]);
+///
+/// soa.y += SimdF32::splat(0.5);
+/// assert_eq!(soa.extract(2), Particle { x: 5., y: 6.5, id: 2 });
+/// ```
+#[proc_macro_derive(Soa)]
+pub fn derive_soa(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match soa(input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn soa(input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`Soa` can't be derived for generic structs",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "`Soa` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`Soa` can only be derived for structs",
+            ))
+        }
+    };
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let soa_name = format_ident!("{}Soa", name);
+
+    let mut field_names = Vec::new();
+    let mut field_vis = Vec::new();
+    let mut vectors = Vec::new();
+    for field in fields {
+        let vector = vector_type(&field.ty).ok_or_else(|| {
+            Error::new_spanned(
+                &field.ty,
+                "`Soa` fields must be primitive integer or float types",
+            )
+        })?;
+        field_names.push(field.ident.as_ref().unwrap());
+        field_vis.push(&field.vis);
+        vectors.push(vector);
+    }
+
+    let doc = format!(
+        "Structure-of-arrays form of [`{}`], with `LANES` values of each field.",
+        name
+    );
+    let bounds = quote! {
+        ::core_simd::LaneCount<LANES>: ::core_simd::SupportedLaneCount,
+    };
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Copy, Clone, Debug, Default, PartialEq)]
+        #vis struct #soa_name<const LANES: usize>
+        where
+            #bounds
+        {
+            #(#field_vis #field_names: ::core_simd::#vectors<LANES>,)*
+        }
+
+        impl<const LANES: usize> #soa_name<LANES>
+        where
+            #bounds
+        {
+            /// Constructs a value with `value` in every lane.
+            #[inline]
+            pub fn splat(value: #name) -> Self {
+                Self {
+                    #(#field_names: ::core_simd::#vectors::splat(value.#field_names),)*
+                }
+            }
+
+            /// Transposes an array of values into vectors.
+            #[inline]
+            pub fn from_array(values: [#name; LANES]) -> Self {
+                Self::from_slice(&values)
+            }
+
+            /// Transposes the vectors into an array of values.
+            #[inline]
+            pub fn to_array(self) -> [#name; LANES] {
+                let mut values = [self.extract(0); LANES];
+                self.copy_to_slice(&mut values);
+                values
+            }
+
+            /// Loads the first `LANES` values of a slice.
+            ///
+            /// # Panics
+            /// Panics if the slice's length is less than the number of lanes.
+            #[inline]
+            #[track_caller]
+            pub fn from_slice(values: &[#name]) -> Self {
+                assert!(values.len() >= LANES, "slice length must be at least the number of lanes");
+                let mut soa = Self::default();
+                for (lane, value) in values[..LANES].iter().enumerate() {
+                    soa.replace(lane, *value);
+                }
+                soa
+            }
+
+            /// Stores the values to the first `LANES` elements of a slice.
+            ///
+            /// # Panics
+            /// Panics if the slice's length is less than the number of lanes.
+            #[inline]
+            #[track_caller]
+            pub fn copy_to_slice(self, values: &mut [#name]) {
+                assert!(values.len() >= LANES, "slice length must be at least the number of lanes");
+                for (lane, value) in values[..LANES].iter_mut().enumerate() {
+                    *value = self.extract(lane);
+                }
+            }
+
+            /// Loads the values of a slice at the given indices.
+            ///
+            /// # Panics
+            /// Panics if any index is out of bounds.
+            #[inline]
+            #[track_caller]
+            pub fn gather(values: &[#name], idxs: ::core_simd::SimdUsize<LANES>) -> Self {
+                let mut soa = Self::default();
+                for (lane, idx) in idxs.to_array().iter().enumerate() {
+                    soa.replace(lane, values[*idx]);
+                }
+                soa
+            }
+
+            /// Stores the values to a slice at the given indices.
+            ///
+            /// If an index appears more than once, the value in the last lane with that index is
+            /// stored.
+            ///
+            /// # Panics
+            /// Panics if any index is out of bounds.
+            #[inline]
+            #[track_caller]
+            pub fn scatter(self, values: &mut [#name], idxs: ::core_simd::SimdUsize<LANES>) {
+                for (lane, idx) in idxs.to_array().iter().enumerate() {
+                    values[*idx] = self.extract(lane);
+                }
+            }
+
+            /// Returns the value in one lane.
+            ///
+            /// # Panics
+            /// Panics if `lane` is out of bounds.
+            #[inline]
+            #[track_caller]
+            pub fn extract(&self, lane: usize) -> #name {
+                #name {
+                    #(#field_names: self.#field_names[lane],)*
+                }
+            }
+
+            /// Replaces the value in one lane.
+            ///
+            /// # Panics
+            /// Panics if `lane` is out of bounds.
+            #[inline]
+            #[track_caller]
+            pub fn replace(&mut self, lane: usize, value: #name) {
+                #(self.#field_names[lane] = value.#field_names;)*
+            }
+        }
+    })
+}
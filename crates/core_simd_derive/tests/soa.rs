@@ -0,0 +1,87 @@
+use core_simd::{SimdF64, SimdI16, SimdU8, SimdUsize};
+use core_simd_derive::Soa;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Copy, Clone, Debug, PartialEq, Soa)]
+struct Point {
+    x: f64,
+    y: f64,
+    tag: u8,
+    pub weight: i16,
+}
+
+fn point(i: usize) -> Point {
+    Point {
+        x: i as f64,
+        y: -(i as f64),
+        tag: i as u8,
+        weight: 100 * i as i16,
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn array_round_trip() {
+    let points = [point(0), point(1), point(2), point(3)];
+    let soa = PointSoa::<4>::from_array(points);
+    assert_eq!(soa.x, SimdF64::from_array([0., 1., 2., 3.]));
+    assert_eq!(soa.y, SimdF64::from_array([0., -1., -2., -3.]));
+    assert_eq!(soa.tag, SimdU8::from_array([0, 1, 2, 3]));
+    assert_eq!(soa.weight, SimdI16::from_array([0, 100, 200, 300]));
+    assert_eq!(soa.to_array(), points);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn slices() {
+    let points: Vec<Point> = (0..10).map(point).collect();
+    let soa = PointSoa::<8>::from_slice(&points[2..]);
+    assert_eq!(soa.extract(0), point(2));
+    assert_eq!(soa.extract(7), point(9));
+
+    let mut out = vec![point(0); 9];
+    soa.copy_to_slice(&mut out[1..]);
+    assert_eq!(out[0], point(0));
+    assert_eq!(&out[1..], &points[2..]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn gather_scatter() {
+    let points: Vec<Point> = (0..10).map(point).collect();
+    let idxs = SimdUsize::from_array([9, 0, 4, 4]);
+    let soa = PointSoa::<4>::gather(&points, idxs);
+    assert_eq!(soa.to_array(), [point(9), point(0), point(4), point(4)]);
+
+    let mut out = vec![point(0); 4];
+    let soa = PointSoa::<4>::from_array([point(5), point(6), point(7), point(8)]);
+    soa.scatter(&mut out, SimdUsize::from_array([3, 1, 1, 2]));
+    assert_eq!(out, [point(0), point(7), point(8), point(5)]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn lanes() {
+    let mut soa = PointSoa::<2>::splat(point(3));
+    assert_eq!(soa.to_array(), [point(3), point(3)]);
+    soa.replace(1, point(5));
+    assert_eq!(soa.to_array(), [point(3), point(5)]);
+    assert_eq!(PointSoa::<2>::default().extract(0), point(0));
+}
+
+#[test]
+#[should_panic]
+fn from_slice_too_short() {
+    PointSoa::<4>::from_slice(&[point(0); 3]);
+}
+
+#[test]
+#[should_panic]
+fn gather_out_of_bounds() {
+    PointSoa::<2>::gather(&[point(0); 3], SimdUsize::from_array([0, 3]));
+}